@@ -0,0 +1,314 @@
+//! Incremental sync of program accounts using `changedSinceSlot`
+//!
+//! `ProgramAccountSync` downloads a full snapshot of a program's accounts (or of a wallet's token accounts) once, then
+//! only fetches the accounts that changed since the last cycle. Every account fetched is upserted into a user-supplied
+//! `AccountStore`, page by page, so an indexer stays in sync without re-downloading the whole program every cycle
+//!
+//! # Example
+//! ```rust,no_run
+//! use std::collections::HashMap;
+//! use std::time::Duration;
+//!
+//! use helius::account_sync::ProgramAccountSync;
+//! use helius::error::Result;
+//! use helius::types::{AccountInfo, Cluster, GetProgramAccountsV2Config};
+//! use helius::Helius;
+//!
+//! # async fn run() -> Result<()> {
+//! let helius: Helius = Helius::new("YOUR_API_KEY", Cluster::MainnetBeta)?;
+//! let mut sync = ProgramAccountSync::new(
+//!     helius.rpc().as_ref(),
+//!     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+//!     GetProgramAccountsV2Config::default(),
+//!     HashMap::<String, AccountInfo>::new(),
+//! );
+//!
+//! // The first cycle takes a full snapshot, and every cycle after it only fetches changed accounts
+//! sync.run(Duration::from_secs(10)).await
+//! # }
+//! ```
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::error::{HeliusError, Result};
+use crate::pagination::{AccountPage, PaginationOptions};
+use crate::rpc_client::RpcClient;
+use crate::types::{
+    AccountInfo, GetProgramAccountsV2Config, GetTokenAccountsByOwnerV2Config, GpaAccount, TokenAccountRecord,
+    TokenAccountsOwnerFilter,
+};
+
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+use tokio::time::sleep;
+
+/// Where a `ProgramAccountSync` applies the accounts it fetches
+///
+/// Upserts are idempotent from the sync's point of view: an account may be upserted more than once with the same data,
+/// for example when a cycle fails partway through and is retried
+pub trait AccountStore: Send {
+    /// Inserts or replaces the given accounts, keyed by their pubkey
+    ///
+    /// # Arguments
+    /// * `accounts` - A page of accounts that were created or changed
+    /// * `slot` - The slot the page was read at
+    fn upsert(&mut self, accounts: Vec<GpaAccount>, slot: u64) -> impl Future<Output = Result<()>> + Send;
+}
+
+impl AccountStore for HashMap<String, AccountInfo> {
+    async fn upsert(&mut self, accounts: Vec<GpaAccount>, _slot: u64) -> Result<()> {
+        self.extend(accounts.into_iter().map(|account| (account.pubkey, account.account)));
+        Ok(())
+    }
+}
+
+/// The accounts a `ProgramAccountSync` keeps in sync
+#[derive(Debug, Clone)]
+enum SyncSource {
+    Program {
+        program_id: String,
+        config: GetProgramAccountsV2Config,
+    },
+    TokenAccountsByOwner {
+        owner: String,
+        filter: TokenAccountsOwnerFilter,
+        config: GetTokenAccountsByOwnerV2Config,
+    },
+}
+
+/// The outcome of a single sync cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncReport {
+    /// The slot of the oldest page the cycle read, which the next cycle fetches changes since
+    pub slot: u64,
+    /// The number of accounts upserted into the store
+    pub upserted: usize,
+    /// Whether the cycle was a full snapshot rather than an incremental update
+    pub full_snapshot: bool,
+}
+
+/// Keeps an `AccountStore` in sync with a program's accounts, or with a wallet's token accounts
+///
+/// Each cycle requests the context slot of every page and records the slot of the oldest one, so every change made
+/// after any page was read is fetched again by the next cycle. The recorded slot only advances once a cycle has been
+/// fully applied to the store, which makes a failed cycle safe to retry
+///
+/// Note that `changedSinceSlot` only returns accounts that still exist, so accounts that are closed or change owner are
+/// not removed from the store. Call `snapshot` periodically to rebuild the store if that matters to you
+pub struct ProgramAccountSync<S> {
+    rpc: RpcClient,
+    source: SyncSource,
+    store: S,
+    options: PaginationOptions,
+    slot: Option<u64>,
+}
+
+impl<S: AccountStore> ProgramAccountSync<S> {
+    /// Creates a sync for the accounts owned by a program
+    ///
+    /// # Arguments
+    /// * `rpc` - The client used to fetch the accounts
+    /// * `program_id` - The program ID to query
+    /// * `config` - The encoding, filters, and page size used for every cycle. Its `with_context`, `pagination_key`, and `changed_since_slot` are managed by the sync
+    /// * `store` - Where fetched accounts are upserted
+    pub fn new(rpc: &RpcClient, program_id: impl Into<String>, config: GetProgramAccountsV2Config, store: S) -> Self {
+        Self::with_source(
+            rpc,
+            SyncSource::Program {
+                program_id: program_id.into(),
+                config,
+            },
+            store,
+        )
+    }
+
+    /// Creates a sync for the token accounts owned by a wallet
+    ///
+    /// # Arguments
+    /// * `rpc` - The client used to fetch the accounts
+    /// * `owner` - The Base58 wallet address whose token accounts are kept in sync
+    /// * `filter` - Filter by mint or programId
+    /// * `config` - The encoding and page size used for every cycle. Its `pagination_key` and `changed_since_slot` are managed by the sync
+    /// * `store` - Where fetched accounts are upserted
+    pub fn for_token_accounts_by_owner(
+        rpc: &RpcClient,
+        owner: impl Into<String>,
+        filter: TokenAccountsOwnerFilter,
+        config: GetTokenAccountsByOwnerV2Config,
+        store: S,
+    ) -> Self {
+        Self::with_source(
+            rpc,
+            SyncSource::TokenAccountsByOwner {
+                owner: owner.into(),
+                filter,
+                config,
+            },
+            store,
+        )
+    }
+
+    fn with_source(rpc: &RpcClient, source: SyncSource, store: S) -> Self {
+        Self {
+            rpc: rpc.clone(),
+            source,
+            store,
+            options: PaginationOptions::default(),
+            slot: None,
+        }
+    }
+
+    /// Sets the page size and prefetching used to fetch accounts. `max_items` is ignored, since every cycle must fetch
+    /// every changed account
+    pub fn with_pagination(mut self, options: PaginationOptions) -> Self {
+        self.options = PaginationOptions {
+            max_items: None,
+            ..options
+        };
+        self
+    }
+
+    /// Resumes from a slot recorded by a previous sync (e.g., before a restart), so the next cycle is incremental
+    /// instead of a full snapshot. The store must already hold the state as of that slot
+    pub fn with_slot(mut self, slot: u64) -> Self {
+        self.slot = Some(slot);
+        self
+    }
+
+    /// Returns the slot recorded by the last completed cycle, or `None` if no cycle has completed yet
+    pub fn slot(&self) -> Option<u64> {
+        self.slot
+    }
+
+    /// Returns the store
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Returns the store mutably
+    pub fn store_mut(&mut self) -> &mut S {
+        &mut self.store
+    }
+
+    /// Consumes the sync, returning its store
+    pub fn into_store(self) -> S {
+        self.store
+    }
+
+    /// Fetches every account and upserts it into the store, regardless of any previously recorded slot
+    ///
+    /// # Returns
+    /// A `SyncReport` with the slot of the snapshot's oldest page and the number of accounts upserted
+    ///
+    /// # Errors
+    /// Returns an error if any page cannot be fetched or has no context slot, or if the store fails to apply a page. The
+    /// recorded slot is left unchanged in that case
+    pub async fn snapshot(&mut self) -> Result<SyncReport> {
+        self.cycle(None).await
+    }
+
+    /// Fetches the accounts changed since the last completed cycle and upserts them into the store. Takes a full snapshot
+    /// instead if no cycle has completed yet
+    ///
+    /// # Returns
+    /// A `SyncReport` with the slot of the cycle's oldest page and the number of accounts upserted
+    ///
+    /// # Errors
+    /// Returns an error if any page cannot be fetched or has no context slot, or if the store fails to apply a page. The
+    /// recorded slot is left unchanged in that case, so the next call fetches the same changes again
+    pub async fn poll(&mut self) -> Result<SyncReport> {
+        self.cycle(self.slot).await
+    }
+
+    /// Polls for changes every `interval` until a cycle fails
+    ///
+    /// # Errors
+    /// Returns the error of the first cycle that fails. The sync can be resumed by calling `run` or `poll` again
+    pub async fn run(&mut self, interval: Duration) -> Result<()> {
+        loop {
+            let _report: SyncReport = self.poll().await?;
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                slot = _report.slot,
+                upserted = _report.upserted,
+                full_snapshot = _report.full_snapshot,
+                "synced accounts"
+            );
+
+            sleep(interval).await;
+        }
+    }
+
+    async fn cycle(&mut self, changed_since_slot: Option<u64>) -> Result<SyncReport> {
+        let mut pages: BoxStream<'static, Result<AccountPage<GpaAccount>>> = self.pages(changed_since_slot);
+        let mut slot: Option<u64> = None;
+        let mut upserted: usize = 0;
+        while let Some(page) = pages.next().await {
+            let page: AccountPage<GpaAccount> = page?;
+            let page_slot: u64 = page
+                .context_slot
+                .ok_or_else(|| HeliusError::InvalidInput("The response has no context slot".to_string()))?;
+            // Accounts on a page can change after it's read, so the cycle is only complete up to its oldest page
+            slot = Some(slot.map_or(page_slot, |slot| slot.min(page_slot)));
+            if page.accounts.is_empty() {
+                continue;
+            }
+
+            upserted += page.accounts.len();
+            self.store.upsert(page.accounts, page_slot).await?;
+        }
+
+        let slot: u64 =
+            slot.ok_or_else(|| HeliusError::InvalidInput("The response has no context slot".to_string()))?;
+        self.slot = Some(slot);
+        Ok(SyncReport {
+            slot,
+            upserted,
+            full_snapshot: changed_since_slot.is_none(),
+        })
+    }
+
+    fn pages(&self, changed_since_slot: Option<u64>) -> BoxStream<'static, Result<AccountPage<GpaAccount>>> {
+        match &self.source {
+            SyncSource::Program { program_id, config } => {
+                let config: GetProgramAccountsV2Config = GetProgramAccountsV2Config {
+                    with_context: Some(true),
+                    pagination_key: None,
+                    changed_since_slot,
+                    ..config.clone()
+                };
+                self.rpc
+                    .get_program_accounts_v2_stream(program_id.clone(), config, self.options)
+            }
+            SyncSource::TokenAccountsByOwner { owner, filter, config } => {
+                let config: GetTokenAccountsByOwnerV2Config = GetTokenAccountsByOwnerV2Config {
+                    pagination_key: None,
+                    changed_since_slot,
+                    ..config.clone()
+                };
+                self.rpc
+                    .get_token_accounts_by_owner_v2_stream(owner.clone(), filter.clone(), config, self.options)
+                    .map(|page| page.map(into_gpa_page))
+                    .boxed()
+            }
+        }
+    }
+}
+
+fn into_gpa_page(page: AccountPage<TokenAccountRecord>) -> AccountPage<GpaAccount> {
+    AccountPage {
+        accounts: page
+            .accounts
+            .into_iter()
+            .map(|record| GpaAccount {
+                pubkey: record.pubkey,
+                account: record.account,
+            })
+            .collect(),
+        pagination_key: page.pagination_key,
+        total_results: page.total_results,
+        context_slot: page.context_slot,
+    }
+}
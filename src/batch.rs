@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::error::{HeliusError, Result};
+use crate::request_handler::{EndpointFamily, RequestContext};
+use crate::rpc_client::RpcClient;
+use crate::types::inner::{RpcPayload, RpcRequest, RpcResponse};
+#[cfg(feature = "das")]
+use crate::types::{
+    Asset, AssetList, AssetProof, GetAsset, GetAssetBatch, GetAssetProof, GetAssetsByGroup, GetAssetsByOwner,
+    GetTokenAccounts, SearchAssets, TokenAccountsList,
+};
+use crate::types::{GetPriorityFeeEstimateRequest, GetPriorityFeeEstimateResponse};
+
+use reqwest::{Method, Url};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The largest number of IDs the DAS API accepts in a single `getAssetBatch` or `getAssetProofBatch` request
+#[cfg(feature = "das")]
+pub const DAS_MAX_BATCH_SIZE: usize = 1000;
+
+/// A handle to a call added to an `RpcBatch`, used to retrieve its typed result from the `BatchResponse`
+#[derive(Debug)]
+pub struct BatchCall<T> {
+    id: String,
+    _result: PhantomData<fn() -> T>,
+}
+
+impl<T> BatchCall<T> {
+    /// Returns the JSON-RPC ID assigned to this call
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl<T> Clone for BatchCall<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            _result: PhantomData,
+        }
+    }
+}
+
+/// Collects JSON-RPC calls to be sent to Helius as a single batch request
+///
+/// Each call is assigned a unique ID, and the returned `BatchCall` handle is used to retrieve its typed result once the
+/// batch is sent. Calls can be for different methods with different result types
+///
+/// # Example
+/// ```rust,no_run
+/// use helius::error::Result;
+/// use helius::types::*;
+/// use helius::Helius;
+///
+/// async fn fetch(helius: &Helius) -> Result<()> {
+///     let mut batch = helius.rpc().batch();
+///     let asset = batch.get_asset(GetAsset {
+///         id: "F9Lw3ki3hJ7PF9HQXsBzoY8GyE6sPoEZZdXJBsTTD2rk".to_string(),
+///         display_options: None,
+///     })?;
+///     let fees = batch.get_priority_fee_estimate(GetPriorityFeeEstimateRequest::default())?;
+///
+///     let response = batch.send().await?;
+///     let asset: Option<Asset> = response.get(&asset)?;
+///     let fees: GetPriorityFeeEstimateResponse = response.get(&fees)?;
+///     Ok(())
+/// }
+/// ```
+pub struct RpcBatch {
+    client: RpcClient,
+    requests: Vec<RpcRequest<Value>>,
+}
+
+impl RpcBatch {
+    pub(crate) fn new(client: RpcClient) -> Self {
+        Self {
+            client,
+            requests: Vec::new(),
+        }
+    }
+
+    /// Returns the number of calls in the batch
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Returns `true` if no calls have been added to the batch
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Adds a call to the batch
+    ///
+    /// # Arguments
+    /// * `method` - RPC method name as a string reference (e.g., "getAsset")
+    /// * `request` - Request data for the given method
+    ///
+    /// # Returns
+    /// A `BatchCall` handle used to retrieve the result of type `T` from the `BatchResponse`
+    ///
+    /// # Errors
+    /// Returns `HeliusError::SerdeJson` if the request cannot be serialized
+    pub fn add<R, T>(&mut self, method: &str, request: R) -> Result<BatchCall<T>>
+    where
+        R: Serialize,
+        T: DeserializeOwned,
+    {
+        let id: String = self.requests.len().to_string();
+        let parameters: Value = serde_json::to_value(request)?;
+        self.requests
+            .push(RpcRequest::new(method.to_string(), parameters).with_id(id.clone()));
+
+        Ok(BatchCall {
+            id,
+            _result: PhantomData,
+        })
+    }
+
+    /// Adds a `getAsset` call to the batch
+    #[cfg(feature = "das")]
+    pub fn get_asset(&mut self, request: GetAsset) -> Result<BatchCall<Option<Asset>>> {
+        self.add("getAsset", request)
+    }
+
+    /// Adds a `getAssetBatch` call to the batch
+    #[cfg(feature = "das")]
+    pub fn get_asset_batch(&mut self, request: GetAssetBatch) -> Result<BatchCall<Vec<Option<Asset>>>> {
+        self.add("getAssetBatch", request)
+    }
+
+    /// Adds a `getAssetProof` call to the batch
+    #[cfg(feature = "das")]
+    pub fn get_asset_proof(&mut self, request: GetAssetProof) -> Result<BatchCall<Option<AssetProof>>> {
+        self.add("getAssetProof", request)
+    }
+
+    /// Adds a `getAssetsByOwner` call to the batch
+    #[cfg(feature = "das")]
+    pub fn get_assets_by_owner(&mut self, request: GetAssetsByOwner) -> Result<BatchCall<AssetList>> {
+        self.add("getAssetsByOwner", request)
+    }
+
+    /// Adds a `getAssetsByGroup` call to the batch
+    #[cfg(feature = "das")]
+    pub fn get_assets_by_group(&mut self, request: GetAssetsByGroup) -> Result<BatchCall<AssetList>> {
+        self.add("getAssetsByGroup", request)
+    }
+
+    /// Adds a `searchAssets` call to the batch
+    #[cfg(feature = "das")]
+    pub fn search_assets(&mut self, request: SearchAssets) -> Result<BatchCall<AssetList>> {
+        self.add("searchAssets", request)
+    }
+
+    /// Adds a `getTokenAccounts` call to the batch
+    #[cfg(feature = "das")]
+    pub fn get_token_accounts(&mut self, request: GetTokenAccounts) -> Result<BatchCall<TokenAccountsList>> {
+        self.add("getTokenAccounts", request)
+    }
+
+    /// Adds a `getPriorityFeeEstimate` call to the batch
+    pub fn get_priority_fee_estimate(
+        &mut self,
+        request: GetPriorityFeeEstimateRequest,
+    ) -> Result<BatchCall<GetPriorityFeeEstimateResponse>> {
+        self.add("getPriorityFeeEstimate", vec![request])
+    }
+
+    /// Sends every call in the batch as a single JSON-RPC batch request
+    ///
+    /// The request counts once against the DAS rate limit if it contains any DAS method, otherwise once against the RPC
+    /// rate limit. An empty batch is not sent
+    ///
+    /// # Returns
+    /// A `BatchResponse` holding the result or error of each call
+    ///
+    /// # Errors
+    /// Returns `HeliusError::Rpc` if the server rejects the batch as a whole, or another `HeliusError` if the URL cannot be
+    /// parsed or the HTTP request fails. Errors for individual calls are returned by `BatchResponse::get`
+    pub async fn send(self) -> Result<BatchResponse> {
+        if self.requests.is_empty() {
+            return Ok(BatchResponse::default());
+        }
+
+        let family: EndpointFamily = match self
+            .requests
+            .iter()
+            .any(|request| EndpointFamily::for_rpc_method(&request.method) == EndpointFamily::Das)
+        {
+            true => EndpointFamily::Das,
+            false => EndpointFamily::Rpc,
+        };
+        let idempotent: bool = self
+            .requests
+            .iter()
+            .all(|request| RequestContext::is_read_only_rpc_method(&request.method));
+        let context: RequestContext = RequestContext::new("batch", family).idempotent(idempotent);
+
+        let url: Url = self
+            .client
+            .config
+            .authenticated_url(&format!("{}/", self.client.config.endpoints.rpc))?;
+        let response: Value = self
+            .client
+            .handler
+            .send_with_context(&context, Method::POST, url, Some(&self.requests))
+            .await?;
+
+        // A server that cannot process the batch at all responds with a single error object instead of an array
+        let responses: Vec<RpcResponse<Value>> = match response {
+            Value::Array(_) => serde_json::from_value(response)?,
+            _ => {
+                let response: RpcResponse<Value> = serde_json::from_value(response)?;
+                response.into_result()?;
+                return Err(HeliusError::InvalidInput(
+                    "Expected an array of responses to the batch request".to_string(),
+                ));
+            }
+        };
+
+        Ok(BatchResponse {
+            results: responses
+                .into_iter()
+                .map(|response| (response.id, response.payload))
+                .collect(),
+        })
+    }
+}
+
+/// The results of an `RpcBatch`, keyed by the ID of each call
+#[derive(Debug, Default)]
+pub struct BatchResponse {
+    results: HashMap<String, RpcPayload<Value>>,
+}
+
+impl BatchResponse {
+    /// Returns the number of responses received
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Returns `true` if no responses were received
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Returns the typed result of the given call
+    ///
+    /// # Errors
+    /// Returns `HeliusError::Rpc` if the server responded to the call with a JSON-RPC error, `HeliusError::SerdeJson` if
+    /// the result cannot be deserialized, or `HeliusError::InvalidInput` if there is no response for the call
+    pub fn get<T: DeserializeOwned>(&self, call: &BatchCall<T>) -> Result<T> {
+        match self.results.get(&call.id) {
+            Some(RpcPayload::Result(result)) => T::deserialize(result).map_err(HeliusError::from),
+            Some(RpcPayload::Error(error)) => Err(error.clone().into()),
+            None => Err(HeliusError::InvalidInput(format!(
+                "No response for batch call with id {}",
+                call.id
+            ))),
+        }
+    }
+}
+
+impl Debug for RpcBatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RpcBatch").field("requests", &self.requests).finish()
+    }
+}
+
+/// Controls how `get_asset_batch_chunked` and `get_asset_proof_batch_chunked` split their IDs into requests
+///
+/// # Example
+/// ```rust
+/// use helius::batch::ChunkOptions;
+///
+/// let options: ChunkOptions = ChunkOptions::default().with_chunk_size(250).with_concurrency(8);
+/// assert_eq!(options.chunk_size, 250);
+/// ```
+#[cfg(feature = "das")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkOptions {
+    /// The number of IDs sent per request. Defaults to `DAS_MAX_BATCH_SIZE`
+    pub chunk_size: usize,
+    /// The number of requests in flight at once. Defaults to `4`
+    pub concurrency: usize,
+}
+
+#[cfg(feature = "das")]
+impl ChunkOptions {
+    /// Sends `chunk_size` IDs per request
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Keeps up to `concurrency` requests in flight at once
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Returns the chunk size to use, which is at least one and never above what the server accepts
+    pub(crate) fn effective_chunk_size(&self) -> usize {
+        self.chunk_size.clamp(1, DAS_MAX_BATCH_SIZE)
+    }
+
+    /// Returns the number of requests to keep in flight, which is at least one
+    pub(crate) fn effective_concurrency(&self) -> usize {
+        self.concurrency.max(1)
+    }
+}
+
+#[cfg(feature = "das")]
+impl Default for ChunkOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: DAS_MAX_BATCH_SIZE,
+            concurrency: 4,
+        }
+    }
+}
+
+/// The assets fetched by `get_asset_batch_chunked`
+#[cfg(feature = "das")]
+#[derive(Debug, Default)]
+pub struct ChunkedAssetBatch {
+    /// One entry per requested ID, in the order the IDs were given. An entry is `None` if its asset wasn't found
+    pub assets: Vec<Option<Asset>>,
+    /// The requested IDs for which no asset was found, in the order they were given
+    pub missing: Vec<String>,
+}
+
+#[cfg(feature = "das")]
+impl ChunkedAssetBatch {
+    /// Returns `true` if an asset was found for every requested ID
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// The proofs fetched by `get_asset_proof_batch_chunked`
+#[cfg(feature = "das")]
+#[derive(Debug, Default)]
+pub struct ChunkedAssetProofBatch {
+    /// The proof of each asset that has one, keyed by asset ID
+    pub proofs: HashMap<String, AssetProof>,
+    /// The requested IDs for which no proof was returned, in the order they were given
+    pub missing: Vec<String>,
+}
+
+#[cfg(feature = "das")]
+impl ChunkedAssetProofBatch {
+    /// Returns `true` if a proof was returned for every requested ID
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
@@ -0,0 +1,350 @@
+//! A blocking facade over the asynchronous `Helius` client
+//!
+//! The types in this module mirror the DAS, webhook, enhanced transaction, and smart transaction methods of
+//! [`crate::Helius`] and [`crate::rpc_client::RpcClient`], blocking the current thread until each request completes.
+//! Each `Helius` owns a Tokio runtime that drives the underlying async client, so no runtime is needed by the caller.
+//!
+//! Like `reqwest::blocking`, these types must not be created, used, or dropped from within an async runtime. Doing so
+//! will panic. Use the async client directly in async code
+//!
+//! This module is only available with the `blocking` feature enabled, and each method is only available when the feature
+//! for its subsystem (e.g., `das` or `webhooks`) is also enabled
+#[cfg(feature = "das")]
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::builder::HeliusBuilder;
+use crate::error::{HeliusError, Result};
+use crate::rpc_client::RpcClient as AsyncRpcClient;
+#[cfg(feature = "sender")]
+use crate::types::SenderSendOptions;
+#[cfg(feature = "das")]
+use crate::types::{
+    Asset, AssetList, AssetProof, EditionsList, GetAsset, GetAssetBatch, GetAssetProof, GetAssetProofBatch,
+    GetAssetSignatures, GetAssetsByAuthority, GetAssetsByCreator, GetAssetsByGroup, GetAssetsByOwner, GetNftEditions,
+    GetTokenAccounts, SearchAssets, TokenAccountsList, TransactionSignatureList,
+};
+use crate::types::{Cluster, GetPriorityFeeEstimateRequest, GetPriorityFeeEstimateResponse};
+#[cfg(feature = "smart-transactions")]
+use crate::types::{
+    CreateSmartTransactionConfig, CreateSmartTransactionSeedConfig, SmartTransaction, SmartTransactionConfig, Timeout,
+};
+#[cfg(feature = "webhooks")]
+use crate::types::{CreateWebhookRequest, EditWebhookRequest, Webhook};
+#[cfg(feature = "enhanced-transactions")]
+use crate::types::{EnhancedTransaction, ParseTransactionsRequest, ParsedTransactionHistoryRequest};
+
+use solana_client::rpc_client::RpcClient as SolanaRpcClient;
+#[cfg(feature = "smart-transactions")]
+use solana_client::rpc_config::RpcSendTransactionConfig;
+#[cfg(feature = "smart-transactions")]
+use solana_sdk::{
+    instruction::Instruction,
+    message::AddressLookupTableAccount,
+    pubkey::Pubkey,
+    signature::{Signature, Signer},
+};
+use std::future::Future;
+use tokio::runtime::{Builder, Runtime};
+
+/// A blocking client for the Helius API
+///
+/// Wraps an asynchronous [`crate::Helius`] along with the runtime used to drive it. Cloning is cheap, and clones share
+/// the same client and runtime
+///
+/// # Example
+/// ```rust,no_run
+/// use helius::blocking::Helius;
+/// use helius::types::{Cluster, GetAsset};
+///
+/// let helius = Helius::new("your_api_key", Cluster::MainnetBeta).expect("Failed to create a Helius client");
+/// let asset = helius
+///     .rpc()
+///     .get_asset(GetAsset {
+///         id: "F9Lw3ki3hJ7PF9HQXsBzoY8GyE6sPoEZZdXJBsTTD2rk".to_string(),
+///         display_options: None,
+///     })
+///     .expect("Failed to fetch the asset");
+/// ```
+#[derive(Clone)]
+pub struct Helius {
+    inner: crate::Helius,
+    runtime: Arc<Runtime>,
+}
+
+impl Helius {
+    /// Creates a new blocking instance of `Helius` configured with a specific API key and a target cluster
+    ///
+    /// # Arguments
+    /// * `api_key` - The API key required for authenticating the requests made
+    /// * `cluster` - The Solana cluster that defines the given network environment
+    ///
+    /// # Errors
+    /// Returns a `HeliusError` if the runtime or the HTTP client fail to initialize
+    pub fn new(api_key: &str, cluster: Cluster) -> Result<Self> {
+        Self::from_builder(HeliusBuilder::new().api_key(api_key).cluster(cluster))
+    }
+
+    /// Creates a new blocking instance of `Helius` from a `HeliusBuilder`
+    ///
+    /// The builder is connected on the internal runtime, so an enhanced websocket can be requested. Its tasks keep
+    /// running in the background for as long as the client is alive
+    ///
+    /// # Errors
+    /// Returns a `HeliusError` if the runtime fails to initialize, or if the builder fails to build or connect
+    pub fn from_builder(builder: HeliusBuilder) -> Result<Self> {
+        let runtime: Runtime = Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("helius-blocking")
+            .enable_all()
+            .build()
+            .map_err(|e| HeliusError::ClientNotInitialized {
+                text: format!("Failed to build the blocking runtime: {}", e),
+            })?;
+        let inner: crate::Helius = runtime.block_on(builder.connect())?;
+
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the asynchronous `Helius` client wrapped by this instance
+    pub fn inner(&self) -> &crate::Helius {
+        &self.inner
+    }
+
+    /// Provides access to the blocking DAS and RPC methods
+    pub fn rpc(&self) -> RpcClient {
+        RpcClient {
+            inner: self.inner.rpc(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Provides access to the synchronous Solana client
+    pub fn connection(&self) -> Arc<SolanaRpcClient> {
+        self.inner.connection()
+    }
+
+    #[cfg_attr(
+        not(any(
+            feature = "webhooks",
+            feature = "enhanced-transactions",
+            feature = "smart-transactions"
+        )),
+        allow(dead_code)
+    )]
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Blocking version of [`crate::Helius::create_webhook`]
+    #[cfg(feature = "webhooks")]
+    pub fn create_webhook(&self, request: CreateWebhookRequest) -> Result<Webhook> {
+        self.block_on(self.inner.create_webhook(request))
+    }
+
+    /// Blocking version of [`crate::Helius::edit_webhook`]
+    #[cfg(feature = "webhooks")]
+    pub fn edit_webhook(&self, request: EditWebhookRequest) -> Result<Webhook> {
+        self.block_on(self.inner.edit_webhook(request))
+    }
+
+    /// Blocking version of [`crate::Helius::append_addresses_to_webhook`]
+    #[cfg(feature = "webhooks")]
+    pub fn append_addresses_to_webhook(&self, webhook_id: &str, new_addresses: &[String]) -> Result<Webhook> {
+        self.block_on(self.inner.append_addresses_to_webhook(webhook_id, new_addresses))
+    }
+
+    /// Blocking version of [`crate::Helius::remove_addresses_from_webhook`]
+    #[cfg(feature = "webhooks")]
+    pub fn remove_addresses_from_webhook(&self, webhook_id: &str, addresses_to_remove: &[String]) -> Result<Webhook> {
+        self.block_on(
+            self.inner
+                .remove_addresses_from_webhook(webhook_id, addresses_to_remove),
+        )
+    }
+
+    /// Blocking version of [`crate::Helius::get_webhook_by_id`]
+    #[cfg(feature = "webhooks")]
+    pub fn get_webhook_by_id(&self, webhook_id: &str) -> Result<Webhook> {
+        self.block_on(self.inner.get_webhook_by_id(webhook_id))
+    }
+
+    /// Blocking version of [`crate::Helius::get_all_webhooks`]
+    #[cfg(feature = "webhooks")]
+    pub fn get_all_webhooks(&self) -> Result<Vec<Webhook>> {
+        self.block_on(self.inner.get_all_webhooks())
+    }
+
+    /// Blocking version of [`crate::Helius::delete_webhook`]
+    #[cfg(feature = "webhooks")]
+    pub fn delete_webhook(&self, webhook_id: &str) -> Result<()> {
+        self.block_on(self.inner.delete_webhook(webhook_id))
+    }
+
+    /// Blocking version of [`crate::Helius::parse_transactions`]
+    #[cfg(feature = "enhanced-transactions")]
+    pub fn parse_transactions(&self, request: ParseTransactionsRequest) -> Result<Vec<EnhancedTransaction>> {
+        self.block_on(self.inner.parse_transactions(request))
+    }
+
+    /// Blocking version of [`crate::Helius::parsed_transaction_history`]
+    #[cfg(feature = "enhanced-transactions")]
+    pub fn parsed_transaction_history(
+        &self,
+        request: ParsedTransactionHistoryRequest,
+    ) -> Result<Vec<EnhancedTransaction>> {
+        self.block_on(self.inner.parsed_transaction_history(request))
+    }
+
+    /// Blocking version of [`crate::Helius::get_compute_units`]
+    #[cfg(feature = "smart-transactions")]
+    pub fn get_compute_units(
+        &self,
+        instructions: Vec<Instruction>,
+        payer: Pubkey,
+        lookup_tables: Vec<AddressLookupTableAccount>,
+        signers: Option<&[Arc<dyn Signer>]>,
+    ) -> Result<Option<u64>> {
+        self.block_on(
+            self.inner
+                .get_compute_units(instructions, payer, lookup_tables, signers),
+        )
+    }
+
+    /// Blocking version of [`crate::Helius::create_smart_transaction`]
+    #[cfg(feature = "smart-transactions")]
+    pub fn create_smart_transaction(&self, config: &CreateSmartTransactionConfig) -> Result<(SmartTransaction, u64)> {
+        self.block_on(self.inner.create_smart_transaction(config))
+    }
+
+    /// Blocking version of [`crate::Helius::send_smart_transaction`]
+    #[cfg(feature = "smart-transactions")]
+    pub fn send_smart_transaction(&self, config: SmartTransactionConfig) -> Result<Signature> {
+        self.block_on(self.inner.send_smart_transaction(config))
+    }
+
+    /// Blocking version of [`crate::Helius::send_smart_transaction_with_seeds`]
+    #[cfg(feature = "smart-transactions")]
+    pub fn send_smart_transaction_with_seeds(
+        &self,
+        create_config: CreateSmartTransactionSeedConfig,
+        send_options: Option<RpcSendTransactionConfig>,
+        timeout: Option<Timeout>,
+    ) -> Result<Signature> {
+        self.block_on(
+            self.inner
+                .send_smart_transaction_with_seeds(create_config, send_options, timeout),
+        )
+    }
+
+    /// Blocking version of [`crate::Helius::send_smart_transaction_with_sender`]
+    #[cfg(feature = "sender")]
+    pub fn send_smart_transaction_with_sender(
+        &self,
+        config: SmartTransactionConfig,
+        sender_opts: SenderSendOptions,
+    ) -> Result<Signature> {
+        self.block_on(self.inner.send_smart_transaction_with_sender(config, sender_opts))
+    }
+}
+
+/// A blocking client for the DAS API and Helius RPC methods, obtained from [`Helius::rpc`]
+#[derive(Clone)]
+pub struct RpcClient {
+    inner: Arc<AsyncRpcClient>,
+    runtime: Arc<Runtime>,
+}
+
+impl RpcClient {
+    /// Returns the asynchronous `RpcClient` wrapped by this instance
+    pub fn inner(&self) -> &AsyncRpcClient {
+        &self.inner
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Blocking version of [`AsyncRpcClient::get_asset`]
+    #[cfg(feature = "das")]
+    pub fn get_asset(&self, request: GetAsset) -> Result<Option<Asset>> {
+        self.block_on(self.inner.get_asset(request))
+    }
+
+    /// Blocking version of [`AsyncRpcClient::get_asset_batch`]
+    #[cfg(feature = "das")]
+    pub fn get_asset_batch(&self, request: GetAssetBatch) -> Result<Vec<Option<Asset>>> {
+        self.block_on(self.inner.get_asset_batch(request))
+    }
+
+    /// Blocking version of [`AsyncRpcClient::get_asset_proof`]
+    #[cfg(feature = "das")]
+    pub fn get_asset_proof(&self, request: GetAssetProof) -> Result<Option<AssetProof>> {
+        self.block_on(self.inner.get_asset_proof(request))
+    }
+
+    /// Blocking version of [`AsyncRpcClient::get_asset_proof_batch`]
+    #[cfg(feature = "das")]
+    pub fn get_asset_proof_batch(&self, request: GetAssetProofBatch) -> Result<HashMap<String, Option<AssetProof>>> {
+        self.block_on(self.inner.get_asset_proof_batch(request))
+    }
+
+    /// Blocking version of [`AsyncRpcClient::get_assets_by_authority`]
+    #[cfg(feature = "das")]
+    pub fn get_assets_by_authority(&self, request: GetAssetsByAuthority) -> Result<AssetList> {
+        self.block_on(self.inner.get_assets_by_authority(request))
+    }
+
+    /// Blocking version of [`AsyncRpcClient::get_assets_by_creator`]
+    #[cfg(feature = "das")]
+    pub fn get_assets_by_creator(&self, request: GetAssetsByCreator) -> Result<AssetList> {
+        self.block_on(self.inner.get_assets_by_creator(request))
+    }
+
+    /// Blocking version of [`AsyncRpcClient::get_assets_by_group`]
+    #[cfg(feature = "das")]
+    pub fn get_assets_by_group(&self, request: GetAssetsByGroup) -> Result<AssetList> {
+        self.block_on(self.inner.get_assets_by_group(request))
+    }
+
+    /// Blocking version of [`AsyncRpcClient::get_assets_by_owner`]
+    #[cfg(feature = "das")]
+    pub fn get_assets_by_owner(&self, request: GetAssetsByOwner) -> Result<AssetList> {
+        self.block_on(self.inner.get_assets_by_owner(request))
+    }
+
+    /// Blocking version of [`AsyncRpcClient::search_assets`]
+    #[cfg(feature = "das")]
+    pub fn search_assets(&self, request: SearchAssets) -> Result<AssetList> {
+        self.block_on(self.inner.search_assets(request))
+    }
+
+    /// Blocking version of [`AsyncRpcClient::get_signatures_for_asset`]
+    #[cfg(feature = "das")]
+    pub fn get_signatures_for_asset(&self, request: GetAssetSignatures) -> Result<TransactionSignatureList> {
+        self.block_on(self.inner.get_signatures_for_asset(request))
+    }
+
+    /// Blocking version of [`AsyncRpcClient::get_token_accounts`]
+    #[cfg(feature = "das")]
+    pub fn get_token_accounts(&self, request: GetTokenAccounts) -> Result<TokenAccountsList> {
+        self.block_on(self.inner.get_token_accounts(request))
+    }
+
+    /// Blocking version of [`AsyncRpcClient::get_nft_editions`]
+    #[cfg(feature = "das")]
+    pub fn get_nft_editions(&self, request: GetNftEditions) -> Result<EditionsList> {
+        self.block_on(self.inner.get_nft_editions(request))
+    }
+
+    /// Blocking version of [`AsyncRpcClient::get_priority_fee_estimate`]
+    pub fn get_priority_fee_estimate(
+        &self,
+        request: GetPriorityFeeEstimateRequest,
+    ) -> Result<GetPriorityFeeEstimateResponse> {
+        self.block_on(self.inner.get_priority_fee_estimate(request))
+    }
+}
@@ -0,0 +1,814 @@
+//! Instruction builders for Bubblegum compressed NFTs
+//!
+//! A `BubblegumBuilder` is created from an asset, its proof, and the `TreeInfo` of the asset's tree, and produces the
+//! Bubblegum `transfer`, `burn`, `delegate`, `redeem`, `decompress_v1`, `verify_creator`, and
+//! `set_and_verify_collection` instructions for it. The proof nodes cached in the tree's canopy are trimmed from the
+//! instruction's accounts, which keeps the transaction small enough for deep trees
+//!
+//! `Helius::bubblegum_builder` fetches the asset's tree and tree config accounts to build the `TreeInfo`, and checks the
+//! proof against the asset before building anything. The resulting instructions can be passed straight to
+//! `create_smart_transaction`
+//!
+//! Only Bubblegum V1 leaves are supported
+//!
+//! # Example
+//! ```rust,no_run
+//! use helius::bubblegum::BubblegumBuilder;
+//! use helius::error::Result;
+//! use helius::types::{Asset, AssetProof, GetAsset, GetAssetProof};
+//! use helius::Helius;
+//! use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+//!
+//! async fn transfer_instruction(helius: &Helius, id: String, new_owner: Pubkey) -> Result<Option<Instruction>> {
+//!     let asset: Option<Asset> = helius
+//!         .rpc()
+//!         .get_asset(GetAsset {
+//!             id: id.clone(),
+//!             display_options: None,
+//!         })
+//!         .await?;
+//!     let proof: Option<AssetProof> = helius.rpc().get_asset_proof(GetAssetProof { id }).await?;
+//!
+//!     let (Some(asset), Some(proof)) = (asset, proof) else {
+//!         return Ok(None);
+//!     };
+//!     let builder: BubblegumBuilder = helius.bubblegum_builder(&asset, &proof).await?;
+//!     Ok(Some(builder.transfer(&builder.leaf_owner(), &new_owner)?))
+//! }
+//! ```
+use crate::error::{HeliusError, Result};
+use crate::merkle::{decode_hash, decode_pubkey, leaf_index, verify_asset_proof};
+use crate::mint_api::{metadata_address, SYSTEM_PROGRAM_ID};
+use crate::types::{Asset, AssetProof, Compression, TokenStandard, UseMethod};
+use crate::Helius;
+
+use solana_keccak_hasher::hashv;
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+/// The Bubblegum program ID
+pub const BUBBLEGUM_PROGRAM_ID: Pubkey = Pubkey::from_str_const("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+
+/// The SPL Account Compression program ID
+pub const SPL_ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+/// The SPL Noop program ID, which Bubblegum logs leaf changes through
+pub const SPL_NOOP_PROGRAM_ID: Pubkey = Pubkey::from_str_const("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+pub use crate::mint_api::TOKEN_METADATA_PROGRAM_ID;
+
+const TOKEN_PROGRAM_ID: Pubkey = Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = Pubkey::from_str_const("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+const SYSVAR_RENT_ID: Pubkey = Pubkey::from_str_const("SysvarRent111111111111111111111111111111111");
+
+const TRANSFER_DISCRIMINATOR: [u8; 8] = [163, 52, 200, 231, 140, 3, 69, 186];
+const BURN_DISCRIMINATOR: [u8; 8] = [116, 110, 29, 56, 107, 219, 42, 93];
+const DELEGATE_DISCRIMINATOR: [u8; 8] = [90, 147, 75, 178, 85, 88, 4, 137];
+const REDEEM_DISCRIMINATOR: [u8; 8] = [184, 12, 86, 149, 70, 196, 97, 225];
+const DECOMPRESS_V1_DISCRIMINATOR: [u8; 8] = [54, 85, 76, 70, 228, 250, 164, 81];
+const VERIFY_CREATOR_DISCRIMINATOR: [u8; 8] = [52, 17, 96, 132, 71, 4, 85, 194];
+const SET_AND_VERIFY_COLLECTION_DISCRIMINATOR: [u8; 8] = [235, 242, 121, 216, 158, 234, 180, 234];
+const TREE_CONFIG_DISCRIMINATOR: [u8; 8] = [122, 245, 175, 248, 171, 34, 0, 207];
+
+/// The account type byte of an SPL Account Compression concurrent Merkle tree
+const CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE: u8 = 1;
+/// The size of the account type, version, and header that precede a concurrent Merkle tree
+const MERKLE_TREE_HEADER_SIZE: usize = 56;
+/// The size of a tree config account up to and including its tree delegate
+const TREE_CONFIG_MIN_SIZE: usize = 72;
+
+/// Returns the address of a Merkle tree's Bubblegum tree config account
+pub fn tree_config_address(merkle_tree: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[merkle_tree.as_ref()], &BUBBLEGUM_PROGRAM_ID).0
+}
+
+/// Returns the address of the voucher that `redeem` creates for a leaf
+pub fn voucher_address(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"voucher", merkle_tree.as_ref(), &nonce.to_le_bytes()],
+        &BUBBLEGUM_PROGRAM_ID,
+    )
+    .0
+}
+
+/// The on-chain layout of a compressed asset's tree
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeInfo {
+    /// The Merkle tree account
+    pub merkle_tree: Pubkey,
+    /// The depth of the tree, which is the length of a full proof
+    pub max_depth: u32,
+    /// The number of concurrent changes the tree can absorb
+    pub max_buffer_size: u32,
+    /// The number of upper levels of the tree cached on-chain, which don't need to be passed as proof accounts
+    pub canopy_depth: u32,
+    /// The creator of the tree, from its tree config
+    pub tree_creator: Pubkey,
+    /// The delegate of the tree, from its tree config
+    pub tree_delegate: Pubkey,
+}
+
+impl TreeInfo {
+    /// Parses a tree's layout from its Merkle tree and tree config accounts
+    ///
+    /// # Arguments
+    /// * `merkle_tree` - The address of the Merkle tree account
+    /// * `tree_data` - The data of the Merkle tree account
+    /// * `tree_config_data` - The data of the tree's Bubblegum tree config account
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if either account doesn't have the expected layout
+    pub fn from_accounts(merkle_tree: Pubkey, tree_data: &[u8], tree_config_data: &[u8]) -> Result<Self> {
+        let header: &[u8] = tree_data
+            .get(..MERKLE_TREE_HEADER_SIZE)
+            .filter(|header| header[0] == CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE)
+            .ok_or_else(|| HeliusError::InvalidInput(format!("{merkle_tree} is not a concurrent Merkle tree")))?;
+        let max_buffer_size: u32 = read_u32(header, 2, &merkle_tree)?;
+        let max_depth: u32 = read_u32(header, 6, &merkle_tree)?;
+
+        // A concurrent Merkle tree is three u64 counters, a change log per buffer slot, and the rightmost proof, each
+        // of which is a path of `max_depth` nodes with a 32-byte node and two u32s
+        let path_size: usize = 40 + 32 * max_depth as usize;
+        let tree_size: usize = 24 + (max_buffer_size as usize + 1) * path_size;
+        let canopy_depth: u32 = tree_data
+            .len()
+            .checked_sub(MERKLE_TREE_HEADER_SIZE + tree_size)
+            .and_then(|canopy_size| canopy_depth(canopy_size, max_depth))
+            .ok_or_else(|| {
+                HeliusError::InvalidInput(format!(
+                    "{merkle_tree} has an invalid size {} for a tree of depth {max_depth} and buffer size {max_buffer_size}",
+                    tree_data.len()
+                ))
+            })?;
+
+        let config: &[u8] = tree_config_data
+            .get(..TREE_CONFIG_MIN_SIZE)
+            .filter(|config| config[..8] == TREE_CONFIG_DISCRIMINATOR)
+            .ok_or_else(|| HeliusError::InvalidInput(format!("Invalid tree config for {merkle_tree}")))?;
+
+        Ok(TreeInfo {
+            merkle_tree,
+            max_depth,
+            max_buffer_size,
+            canopy_depth,
+            tree_creator: read_pubkey(config, 8, &merkle_tree)?,
+            tree_delegate: read_pubkey(config, 40, &merkle_tree)?,
+        })
+    }
+}
+
+/// Reads the little-endian `u32` at `offset` in the data of one of `merkle_tree`'s accounts
+fn read_u32(data: &[u8], offset: usize, merkle_tree: &Pubkey) -> Result<u32> {
+    data.get(offset..offset + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u32::from_le_bytes)
+        .ok_or_else(|| HeliusError::InvalidInput(format!("Truncated account data for {merkle_tree}")))
+}
+
+/// Reads the pubkey at `offset` in the data of one of `merkle_tree`'s accounts
+fn read_pubkey(data: &[u8], offset: usize, merkle_tree: &Pubkey) -> Result<Pubkey> {
+    data.get(offset..offset + 32)
+        .and_then(|bytes| Pubkey::try_from(bytes).ok())
+        .ok_or_else(|| HeliusError::InvalidInput(format!("Truncated account data for {merkle_tree}")))
+}
+
+/// A canopy of depth `d` caches the `2^(d + 1) - 2` nodes below the root
+fn canopy_depth(canopy_size: usize, max_depth: u32) -> Option<u32> {
+    if canopy_size % 32 != 0 {
+        return None;
+    }
+    let levels: usize = canopy_size / 32 + 2;
+    levels
+        .is_power_of_two()
+        .then(|| levels.trailing_zeros() - 1)
+        .filter(|depth| *depth <= max_depth)
+}
+
+/// The creator of a compressed asset, as Bubblegum hashes it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MetadataCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    /// The creator's share of royalties, in percent
+    pub share: u8,
+}
+
+/// The collection of a compressed asset, as Bubblegum hashes it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MetadataCollection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+/// The uses of a compressed asset, as Bubblegum hashes them
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataUses {
+    pub use_method: UseMethod,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+/// The token program a compressed asset decompresses to
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TokenProgramVersion {
+    #[default]
+    Original,
+    Token2022,
+}
+
+/// The metadata of a compressed asset, which Bubblegum hashes into the leaf's data hash
+///
+/// `decompress`, `verify_creator`, and `set_and_verify_collection` need the full metadata, since the program rehashes
+/// it on-chain. DAS doesn't return every field verbatim, so `from_asset` is a best effort and the builders check the
+/// result against the asset's data and creator hashes
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<TokenStandard>,
+    pub collection: Option<MetadataCollection>,
+    pub uses: Option<MetadataUses>,
+    pub token_program_version: TokenProgramVersion,
+    pub creators: Vec<MetadataCreator>,
+}
+
+impl MetadataArgs {
+    /// Rebuilds an asset's metadata from a DAS response
+    ///
+    /// DAS doesn't return the token standard of compressed assets, so it's assumed to be `NonFungible`, which is the
+    /// only standard Bubblegum mints. DAS only returns unverified collections with `show_unverified_collections`, which
+    /// marks them as unverified, so a collection without a `verified` flag is verified
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if the asset has no content or royalty, or one of its fields is out of range
+    pub fn from_asset(asset: &Asset) -> Result<Self> {
+        let content = asset
+            .content
+            .as_ref()
+            .ok_or_else(|| HeliusError::InvalidInput(format!("Asset {} has no content", asset.id)))?;
+        let royalty = asset
+            .royalty
+            .as_ref()
+            .ok_or_else(|| HeliusError::InvalidInput(format!("Asset {} has no royalty", asset.id)))?;
+
+        let collection: Option<MetadataCollection> = asset
+            .grouping
+            .iter()
+            .flatten()
+            .find(|group| group.group_key == "collection")
+            .and_then(|group| group.group_value.as_ref().map(|key| (key, group.verified)))
+            .map(|(key, verified)| {
+                Ok::<_, HeliusError>(MetadataCollection {
+                    verified: verified.unwrap_or(true),
+                    key: decode_pubkey("collection", key)?,
+                })
+            })
+            .transpose()?;
+        let creators: Vec<MetadataCreator> = asset
+            .creators
+            .iter()
+            .flatten()
+            .map(|creator| {
+                Ok(MetadataCreator {
+                    address: decode_pubkey("creator", &creator.address)?,
+                    verified: creator.verified,
+                    share: u8::try_from(creator.share)
+                        .map_err(|_| HeliusError::InvalidInput(format!("Invalid creator share {}", creator.share)))?,
+                })
+            })
+            .collect::<Result<_>>()?;
+        let edition_nonce: Option<u8> = asset
+            .supply
+            .as_ref()
+            .and_then(|supply| supply.edition_nonce)
+            .map(|nonce| {
+                u8::try_from(nonce).map_err(|_| HeliusError::InvalidInput(format!("Invalid edition nonce {nonce}")))
+            })
+            .transpose()?;
+
+        Ok(MetadataArgs {
+            name: content.metadata.name.clone().unwrap_or_default(),
+            symbol: content.metadata.symbol.clone().unwrap_or_default(),
+            uri: content.json_uri.clone(),
+            seller_fee_basis_points: u16::try_from(royalty.basis_points).map_err(|_| {
+                HeliusError::InvalidInput(format!("Invalid seller fee basis points {}", royalty.basis_points))
+            })?,
+            primary_sale_happened: royalty.primary_sale_happened,
+            is_mutable: asset.mutable,
+            edition_nonce,
+            token_standard: Some(TokenStandard::NonFungible),
+            collection,
+            uses: asset.uses.as_ref().map(|uses| MetadataUses {
+                use_method: uses.use_method.clone(),
+                remaining: uses.remaining,
+                total: uses.total,
+            }),
+            token_program_version: TokenProgramVersion::Original,
+            creators,
+        })
+    }
+
+    /// Serializes the metadata the way Bubblegum does
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if the token standard isn't one Token Metadata's `MetadataArgs` supports
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        let mut data: Vec<u8> = Vec::new();
+        for field in [&self.name, &self.symbol, &self.uri] {
+            data.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            data.extend_from_slice(field.as_bytes());
+        }
+        data.extend_from_slice(&self.seller_fee_basis_points.to_le_bytes());
+        data.push(self.primary_sale_happened as u8);
+        data.push(self.is_mutable as u8);
+
+        match self.edition_nonce {
+            Some(nonce) => data.extend_from_slice(&[1, nonce]),
+            None => data.push(0),
+        }
+        match &self.token_standard {
+            Some(token_standard) => data.extend_from_slice(&[1, token_standard_index(token_standard)?]),
+            None => data.push(0),
+        }
+        match &self.collection {
+            Some(collection) => {
+                data.extend_from_slice(&[1, collection.verified as u8]);
+                data.extend_from_slice(collection.key.as_ref());
+            }
+            None => data.push(0),
+        }
+        match &self.uses {
+            Some(uses) => {
+                let use_method: u8 = match uses.use_method {
+                    UseMethod::Burn => 0,
+                    UseMethod::Multiple => 1,
+                    UseMethod::Single => 2,
+                };
+                data.extend_from_slice(&[1, use_method]);
+                data.extend_from_slice(&uses.remaining.to_le_bytes());
+                data.extend_from_slice(&uses.total.to_le_bytes());
+            }
+            None => data.push(0),
+        }
+        data.push(match self.token_program_version {
+            TokenProgramVersion::Original => 0,
+            TokenProgramVersion::Token2022 => 1,
+        });
+
+        data.extend_from_slice(&(self.creators.len() as u32).to_le_bytes());
+        for creator in &self.creators {
+            data.extend_from_slice(creator.address.as_ref());
+            data.extend_from_slice(&[creator.verified as u8, creator.share]);
+        }
+
+        Ok(data)
+    }
+
+    /// Computes the data hash Bubblegum stores in the leaf for this metadata
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if the metadata can't be serialized
+    pub fn data_hash(&self) -> Result<[u8; 32]> {
+        let metadata_hash: [u8; 32] = hashv(&[&self.serialize()?]).to_bytes();
+        Ok(hashv(&[&metadata_hash, &self.seller_fee_basis_points.to_le_bytes()]).to_bytes())
+    }
+
+    /// Computes the creator hash Bubblegum stores in the leaf for this metadata
+    pub fn creator_hash(&self) -> [u8; 32] {
+        let creators: Vec<u8> = self
+            .creators
+            .iter()
+            .flat_map(|creator| {
+                creator
+                    .address
+                    .to_bytes()
+                    .into_iter()
+                    .chain([creator.verified as u8, creator.share])
+            })
+            .collect();
+        hashv(&[&creators]).to_bytes()
+    }
+}
+
+fn token_standard_index(token_standard: &TokenStandard) -> Result<u8> {
+    match token_standard {
+        TokenStandard::NonFungible => Ok(0),
+        TokenStandard::FungibleAsset => Ok(1),
+        TokenStandard::Fungible => Ok(2),
+        TokenStandard::NonFungibleEdition => Ok(3),
+        other => Err(HeliusError::InvalidInput(format!(
+            "Token standard {other} is not supported by Bubblegum"
+        ))),
+    }
+}
+
+/// Builds Bubblegum instructions for a compressed asset
+///
+/// The builder captures the asset's leaf as of its proof. Once an instruction built from it lands, the leaf changes and
+/// a new asset and proof are needed for the next one
+#[derive(Clone, Debug)]
+pub struct BubblegumBuilder {
+    tree: TreeInfo,
+    asset_id: Pubkey,
+    owner: Pubkey,
+    delegate: Pubkey,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    proof: Vec<Pubkey>,
+}
+
+impl BubblegumBuilder {
+    /// Creates a builder for an asset from its proof and the layout of its tree
+    ///
+    /// # Arguments
+    /// * `asset` - The compressed asset returned by `get_asset`
+    /// * `proof` - The proof returned by `get_asset_proof` for the same asset
+    /// * `tree` - The layout of the asset's tree
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidProof` if the proof doesn't belong to the asset or doesn't hash to its root, and
+    /// `HeliusError::InvalidInput` if the tree doesn't match the proof or the asset is malformed
+    pub fn new(asset: &Asset, proof: &AssetProof, tree: TreeInfo) -> Result<Self> {
+        verify_asset_proof(asset, proof)?;
+        let compression: &Compression = asset
+            .compression
+            .as_ref()
+            .ok_or_else(|| HeliusError::InvalidInput(format!("Asset {} is not compressed", asset.id)))?;
+
+        if tree.merkle_tree.to_string() != proof.tree_id {
+            return Err(HeliusError::InvalidInput(format!(
+                "The proof is for tree {}, but the tree info is for {}",
+                proof.tree_id, tree.merkle_tree
+            )));
+        }
+        if proof.proof.len() != tree.max_depth as usize {
+            return Err(HeliusError::InvalidInput(format!(
+                "The proof has {} nodes, but tree {} has depth {}",
+                proof.proof.len(),
+                tree.merkle_tree,
+                tree.max_depth
+            )));
+        }
+
+        let owner: Pubkey = decode_pubkey("owner", &asset.ownership.owner)?;
+        let delegate: Pubkey = match &asset.ownership.delegate {
+            Some(delegate) => decode_pubkey("delegate", delegate)?,
+            None => owner,
+        };
+        let index: u32 = u32::try_from(leaf_index(proof)?)
+            .map_err(|_| HeliusError::InvalidInput(format!("Invalid node index {}", proof.node_index)))?;
+        let proof_len: usize = (tree.max_depth - tree.canopy_depth) as usize;
+
+        Ok(BubblegumBuilder {
+            asset_id: decode_pubkey("asset ID", &asset.id)?,
+            owner,
+            delegate,
+            root: decode_hash("root", &proof.root)?,
+            data_hash: decode_hash("data hash", &compression.data_hash)?,
+            creator_hash: decode_hash("creator hash", &compression.creator_hash)?,
+            nonce: compression.leaf_id as u64,
+            index,
+            proof: proof.proof[..proof_len]
+                .iter()
+                .map(|node| decode_pubkey("proof node", node))
+                .collect::<Result<_>>()?,
+            tree,
+        })
+    }
+
+    /// The asset's ID, which is also its mint once decompressed
+    pub fn asset_id(&self) -> Pubkey {
+        self.asset_id
+    }
+
+    /// The asset's owner
+    pub fn leaf_owner(&self) -> Pubkey {
+        self.owner
+    }
+
+    /// The asset's delegate, which is the owner if the asset isn't delegated
+    pub fn leaf_delegate(&self) -> Pubkey {
+        self.delegate
+    }
+
+    /// The layout of the asset's tree
+    pub fn tree(&self) -> &TreeInfo {
+        &self.tree
+    }
+
+    /// The proof nodes passed as accounts, with the nodes cached in the canopy trimmed off
+    pub fn proof(&self) -> &[Pubkey] {
+        &self.proof
+    }
+
+    /// Builds a `transfer` instruction
+    ///
+    /// # Arguments
+    /// * `authority` - The signer of the transfer, which must be the asset's owner or delegate
+    /// * `new_owner` - The owner to transfer the asset to
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if `authority` is neither the owner nor the delegate
+    pub fn transfer(&self, authority: &Pubkey, new_owner: &Pubkey) -> Result<Instruction> {
+        self.check_authority(authority)?;
+        let accounts: Vec<AccountMeta> = vec![
+            AccountMeta::new_readonly(tree_config_address(&self.tree.merkle_tree), false),
+            AccountMeta::new_readonly(self.owner, *authority == self.owner),
+            AccountMeta::new_readonly(self.delegate, *authority == self.delegate),
+            AccountMeta::new_readonly(*new_owner, false),
+            AccountMeta::new(self.tree.merkle_tree, false),
+            AccountMeta::new_readonly(SPL_NOOP_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ];
+        Ok(self.instruction(TRANSFER_DISCRIMINATOR, accounts, true))
+    }
+
+    /// Builds a `burn` instruction
+    ///
+    /// # Arguments
+    /// * `authority` - The signer of the burn, which must be the asset's owner or delegate
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if `authority` is neither the owner nor the delegate
+    pub fn burn(&self, authority: &Pubkey) -> Result<Instruction> {
+        self.check_authority(authority)?;
+        let accounts: Vec<AccountMeta> = vec![
+            AccountMeta::new_readonly(tree_config_address(&self.tree.merkle_tree), false),
+            AccountMeta::new_readonly(self.owner, *authority == self.owner),
+            AccountMeta::new_readonly(self.delegate, *authority == self.delegate),
+            AccountMeta::new(self.tree.merkle_tree, false),
+            AccountMeta::new_readonly(SPL_NOOP_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ];
+        Ok(self.instruction(BURN_DISCRIMINATOR, accounts, true))
+    }
+
+    /// Builds a `delegate` instruction, signed by the asset's owner
+    ///
+    /// # Arguments
+    /// * `new_delegate` - The new delegate, or the owner to revoke the current delegate
+    pub fn delegate(&self, new_delegate: &Pubkey) -> Instruction {
+        let accounts: Vec<AccountMeta> = vec![
+            AccountMeta::new_readonly(tree_config_address(&self.tree.merkle_tree), false),
+            AccountMeta::new_readonly(self.owner, true),
+            AccountMeta::new_readonly(self.delegate, false),
+            AccountMeta::new_readonly(*new_delegate, false),
+            AccountMeta::new(self.tree.merkle_tree, false),
+            AccountMeta::new_readonly(SPL_NOOP_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ];
+        self.instruction(DELEGATE_DISCRIMINATOR, accounts, true)
+    }
+
+    /// Builds a `redeem` instruction, signed by the asset's owner
+    ///
+    /// Redeeming removes the leaf from the tree and creates a voucher that `decompress` turns into a regular NFT
+    pub fn redeem(&self) -> Instruction {
+        let accounts: Vec<AccountMeta> = vec![
+            AccountMeta::new_readonly(tree_config_address(&self.tree.merkle_tree), false),
+            AccountMeta::new(self.owner, true),
+            AccountMeta::new_readonly(self.delegate, false),
+            AccountMeta::new(self.tree.merkle_tree, false),
+            AccountMeta::new(voucher_address(&self.tree.merkle_tree, self.nonce), false),
+            AccountMeta::new_readonly(SPL_NOOP_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ];
+        self.instruction(REDEEM_DISCRIMINATOR, accounts, true)
+    }
+
+    /// Builds a `decompress_v1` instruction, signed by the asset's owner, which mints the asset as a regular NFT into
+    /// the owner's associated token account
+    ///
+    /// The leaf must have been redeemed first, since decompression consumes the voucher `redeem` creates
+    ///
+    /// # Arguments
+    /// * `metadata` - The asset's metadata
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if the metadata doesn't hash to the asset's data and creator hashes
+    pub fn decompress(&self, metadata: &MetadataArgs) -> Result<Instruction> {
+        let mut data: Vec<u8> = DECOMPRESS_V1_DISCRIMINATOR.to_vec();
+        data.extend(self.checked_metadata(metadata)?);
+        let mint: Pubkey = self.asset_id;
+        let token_account: Pubkey = Pubkey::find_program_address(
+            &[self.owner.as_ref(), TOKEN_PROGRAM_ID.as_ref(), mint.as_ref()],
+            &ASSOCIATED_TOKEN_PROGRAM_ID,
+        )
+        .0;
+        let mint_authority: Pubkey = Pubkey::find_program_address(&[mint.as_ref()], &BUBBLEGUM_PROGRAM_ID).0;
+
+        Ok(Instruction {
+            program_id: BUBBLEGUM_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(voucher_address(&self.tree.merkle_tree, self.nonce), false),
+                AccountMeta::new(self.owner, true),
+                AccountMeta::new(token_account, false),
+                AccountMeta::new(mint, false),
+                AccountMeta::new(mint_authority, false),
+                AccountMeta::new(metadata_address(&mint), false),
+                AccountMeta::new(master_edition_address(&mint), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSVAR_RENT_ID, false),
+                AccountMeta::new_readonly(TOKEN_METADATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SPL_NOOP_PROGRAM_ID, false),
+            ],
+            data,
+        })
+    }
+
+    /// Builds a `verify_creator` instruction, which marks one of the asset's creators as verified
+    ///
+    /// # Arguments
+    /// * `payer` - The fee payer
+    /// * `creator` - The creator to verify, who must sign
+    /// * `metadata` - The asset's current metadata
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if the metadata doesn't hash to the asset's data and creator hashes
+    pub fn verify_creator(&self, payer: &Pubkey, creator: &Pubkey, metadata: &MetadataArgs) -> Result<Instruction> {
+        let metadata: Vec<u8> = self.checked_metadata(metadata)?;
+        let mut instruction: Instruction = self.instruction(
+            VERIFY_CREATOR_DISCRIMINATOR,
+            vec![
+                AccountMeta::new_readonly(tree_config_address(&self.tree.merkle_tree), false),
+                AccountMeta::new_readonly(self.owner, false),
+                AccountMeta::new_readonly(self.delegate, false),
+                AccountMeta::new(self.tree.merkle_tree, false),
+                AccountMeta::new_readonly(*payer, true),
+                AccountMeta::new_readonly(*creator, true),
+                AccountMeta::new_readonly(SPL_NOOP_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            true,
+        );
+        instruction.data.extend(metadata);
+        Ok(instruction)
+    }
+
+    /// Builds a `set_and_verify_collection` instruction, which sets the asset's collection and verifies it
+    ///
+    /// The tree's delegate is passed as the tree creator or delegate account, and must sign
+    ///
+    /// # Arguments
+    /// * `payer` - The fee payer
+    /// * `collection_authority` - The collection's update authority or a delegated collection authority, who must sign
+    /// * `collection_authority_record` - The collection authority record, if `collection_authority` is a delegate
+    /// * `collection_mint` - The mint of the collection NFT
+    /// * `metadata` - The asset's current metadata
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if the metadata doesn't hash to the asset's data and creator hashes
+    pub fn set_and_verify_collection(
+        &self,
+        payer: &Pubkey,
+        collection_authority: &Pubkey,
+        collection_authority_record: Option<&Pubkey>,
+        collection_mint: &Pubkey,
+        metadata: &MetadataArgs,
+    ) -> Result<Instruction> {
+        let metadata: Vec<u8> = self.checked_metadata(metadata)?;
+        let bubblegum_signer: Pubkey = Pubkey::find_program_address(&[b"collection_cpi"], &BUBBLEGUM_PROGRAM_ID).0;
+        let mut instruction: Instruction = self.instruction(
+            SET_AND_VERIFY_COLLECTION_DISCRIMINATOR,
+            vec![
+                AccountMeta::new_readonly(tree_config_address(&self.tree.merkle_tree), false),
+                AccountMeta::new_readonly(self.owner, false),
+                AccountMeta::new_readonly(self.delegate, false),
+                AccountMeta::new(self.tree.merkle_tree, false),
+                AccountMeta::new_readonly(*payer, true),
+                AccountMeta::new_readonly(self.tree.tree_delegate, true),
+                AccountMeta::new_readonly(*collection_authority, true),
+                AccountMeta::new_readonly(*collection_authority_record.unwrap_or(&BUBBLEGUM_PROGRAM_ID), false),
+                AccountMeta::new_readonly(*collection_mint, false),
+                AccountMeta::new(metadata_address(collection_mint), false),
+                AccountMeta::new_readonly(master_edition_address(collection_mint), false),
+                AccountMeta::new_readonly(bubblegum_signer, false),
+                AccountMeta::new_readonly(SPL_NOOP_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_METADATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            true,
+        );
+        instruction.data.extend(metadata);
+        instruction.data.extend_from_slice(collection_mint.as_ref());
+        Ok(instruction)
+    }
+
+    fn check_authority(&self, authority: &Pubkey) -> Result<()> {
+        if *authority != self.owner && *authority != self.delegate {
+            return Err(HeliusError::InvalidInput(format!(
+                "{authority} is neither the owner nor the delegate of asset {}",
+                self.asset_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Builds an instruction whose data is the discriminator followed by the leaf's root, hashes, nonce, and index,
+    /// optionally followed by the trimmed proof as accounts
+    fn instruction(&self, discriminator: [u8; 8], mut accounts: Vec<AccountMeta>, with_proof: bool) -> Instruction {
+        let mut data: Vec<u8> = Vec::with_capacity(116);
+        data.extend_from_slice(&discriminator);
+        data.extend_from_slice(&self.root);
+        data.extend_from_slice(&self.data_hash);
+        data.extend_from_slice(&self.creator_hash);
+        data.extend_from_slice(&self.nonce.to_le_bytes());
+        data.extend_from_slice(&self.index.to_le_bytes());
+
+        if with_proof {
+            accounts.extend(self.proof.iter().map(|node| AccountMeta::new_readonly(*node, false)));
+        }
+        Instruction {
+            program_id: BUBBLEGUM_PROGRAM_ID,
+            accounts,
+            data,
+        }
+    }
+
+    /// Checks the metadata against the leaf and returns it serialized
+    fn checked_metadata(&self, metadata: &MetadataArgs) -> Result<Vec<u8>> {
+        if metadata.data_hash()? != self.data_hash || metadata.creator_hash() != self.creator_hash {
+            return Err(HeliusError::InvalidInput(format!(
+                "The metadata doesn't match the data and creator hashes of asset {}",
+                self.asset_id
+            )));
+        }
+        metadata.serialize()
+    }
+}
+
+fn master_edition_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+            b"edition",
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+impl Helius {
+    /// Fetches the layout of a compressed asset's tree
+    ///
+    /// # Arguments
+    /// * `merkle_tree` - The address of the Merkle tree account
+    ///
+    /// # Returns
+    /// The tree's depth, buffer size, canopy depth, creator, and delegate
+    ///
+    /// # Errors
+    /// Returns `HeliusError::NotFound` if the tree or its tree config doesn't exist, and `HeliusError::InvalidInput` if
+    /// either account doesn't have the expected layout
+    pub async fn get_tree_info(&self, merkle_tree: &Pubkey) -> Result<TreeInfo> {
+        let tree_config: Pubkey = tree_config_address(merkle_tree);
+        let accounts: Vec<Option<Account>> = self
+            .async_connection()?
+            .get_multiple_accounts(&[*merkle_tree, tree_config])
+            .await?;
+
+        match accounts.as_slice() {
+            [Some(tree), Some(config)] => TreeInfo::from_accounts(*merkle_tree, &tree.data, &config.data),
+            _ => Err(HeliusError::NotFound {
+                text: format!("Merkle tree {merkle_tree} or its tree config was not found"),
+            }),
+        }
+    }
+
+    /// Creates a `BubblegumBuilder` for a compressed asset, fetching its tree's layout
+    ///
+    /// # Arguments
+    /// * `asset` - The compressed asset returned by `get_asset`
+    /// * `proof` - The proof returned by `get_asset_proof` for the same asset
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidProof` if the proof doesn't belong to the asset or doesn't hash to its root, and
+    /// any error `get_tree_info` returns
+    pub async fn bubblegum_builder(&self, asset: &Asset, proof: &AssetProof) -> Result<BubblegumBuilder> {
+        let merkle_tree: Pubkey = decode_pubkey("tree", &proof.tree_id)?;
+        let tree: TreeInfo = self.get_tree_info(&merkle_tree).await?;
+        BubblegumBuilder::new(asset, proof, tree)
+    }
+}
@@ -0,0 +1,436 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(feature = "cassette")]
+use crate::cassette::Cassette;
+use crate::client::Helius;
+use crate::config::{AuthMethod, Config};
+use crate::error::{HeliusError, Result};
+use crate::metrics::MetricsSink;
+use crate::middleware::Middleware;
+use crate::rate_limit::{RateLimiter, RateLimits};
+use crate::request_handler::RequestHandler;
+use crate::retry::RetryPolicy;
+use crate::rpc_client::RpcClient;
+use crate::secret::Secret;
+use crate::types::{Cluster, HeliusEndpoints};
+#[cfg(feature = "enhanced-ws")]
+use crate::websocket::EnhancedWebsocket;
+
+use reqwest::Client;
+use solana_client::nonblocking::rpc_client::RpcClient as AsyncSolanaRpcClient;
+use solana_commitment_config::CommitmentConfig;
+use url::Url;
+
+/// Ping/pong settings for the enhanced websocket connection
+#[cfg(feature = "enhanced-ws")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WebsocketOptions {
+    /// Duration in seconds between ping messages. Defaults to 10 seconds if `None`
+    pub ping_interval_secs: Option<u64>,
+    /// Duration in seconds to wait for a pong response before considering the connection dead. Defaults to 3 failed pings if `None`
+    pub pong_timeout_secs: Option<u64>,
+}
+
+/// A builder for configuring and creating instances of `Helius`
+///
+/// Every setting is optional except for the API key. The cluster defaults to `Cluster::MainnetBeta`, and all settings are
+/// validated before any client is created
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use helius::Helius;
+/// use helius::types::Cluster;
+/// use solana_commitment_config::CommitmentConfig;
+///
+/// let helius = Helius::builder()
+///     .api_key("your_api_key")
+///     .cluster(Cluster::Devnet)
+///     .commitment(CommitmentConfig::confirmed())
+///     .with_async_solana()
+///     .timeout(Duration::from_secs(30))
+///     .build()
+///     .expect("Failed to create a Helius client");
+/// ```
+#[derive(Default)]
+pub struct HeliusBuilder {
+    api_key: Option<Secret>,
+    auth: AuthMethod,
+    cluster: Option<Cluster>,
+    commitment: Option<CommitmentConfig>,
+    http_client: Option<Client>,
+    async_solana: bool,
+    #[cfg(feature = "enhanced-ws")]
+    websocket: Option<WebsocketOptions>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiterSource>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    metrics: Option<Arc<dyn MetricsSink>>,
+    #[cfg(feature = "cassette")]
+    cassette: Option<Arc<Cassette>>,
+    api_url: Option<String>,
+    rpc_url: Option<String>,
+    websocket_url: Option<String>,
+    sender_url: Option<String>,
+}
+
+impl HeliusBuilder {
+    /// Creates a new `HeliusBuilder` with no settings applied
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the API key used for authenticating requests
+    pub fn api_key(mut self, api_key: impl Into<Secret>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Sets how the API key is sent with each request. Defaults to `AuthMethod::QueryParam`
+    pub fn auth_method(mut self, auth: AuthMethod) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Sets the Solana cluster the client will interact with
+    pub fn cluster(mut self, cluster: Cluster) -> Self {
+        self.cluster = Some(cluster);
+        self
+    }
+
+    /// Sets the commitment level used by the embedded Solana clients
+    pub fn commitment(mut self, commitment: CommitmentConfig) -> Self {
+        self.commitment = Some(commitment);
+        self
+    }
+
+    /// Uses the provided `reqwest::Client` for all HTTP requests instead of creating a new one
+    ///
+    /// Note that the timeouts set on this builder cannot be applied to a provided client, so they must be configured on the client itself
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Creates an asynchronous Solana client alongside the default synchronous one
+    pub fn with_async_solana(mut self) -> Self {
+        self.async_solana = true;
+        self
+    }
+
+    /// Connects to the enhanced websocket using the default ping/pong settings
+    #[cfg(feature = "enhanced-ws")]
+    pub fn with_websocket(self) -> Self {
+        self.websocket(WebsocketOptions::default())
+    }
+
+    /// Connects to the enhanced websocket using the given ping/pong settings
+    #[cfg(feature = "enhanced-ws")]
+    pub fn websocket(mut self, options: WebsocketOptions) -> Self {
+        self.websocket = Some(options);
+        self
+    }
+
+    /// Sets the total timeout for each HTTP request, including the embedded Solana clients
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing HTTP connections
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Retries failed idempotent requests to Helius according to the given `RetryPolicy`. By default, requests are not retried
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Throttles outgoing requests to Helius so they stay within the given limits. Requests wait for capacity instead of
+    /// failing with `HeliusError::RateLimitExceeded`
+    pub fn rate_limits(mut self, limits: RateLimits) -> Self {
+        self.rate_limiter = Some(RateLimiterSource::Limits(limits));
+        self
+    }
+
+    /// Throttles outgoing requests to Helius using an existing `RateLimiter`, which lets multiple clients share the same
+    /// limits
+    pub fn rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(RateLimiterSource::Shared(rate_limiter));
+        self
+    }
+
+    /// Adds a `Middleware` that every HTTP request sent by the client passes through. Middleware run in the order they
+    /// are added
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Reports latency, errors, and retries to the given `MetricsSink`. The sink is shared by the client's HTTP requests,
+    /// its enhanced websocket, and the smart transaction methods, as well as by any clones of the client
+    pub fn metrics(mut self, metrics: Arc<dyn MetricsSink>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Records the client's HTTP requests to the given `Cassette`, or replays them from it, depending on its mode. Requests
+    /// made by the embedded Solana clients and the enhanced websocket bypass the cassette
+    #[cfg(feature = "cassette")]
+    pub fn cassette(mut self, cassette: Arc<Cassette>) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Overrides the API endpoint (e.g., webhooks and enhanced transactions) determined by the cluster
+    pub fn api_url(mut self, url: impl Into<String>) -> Self {
+        self.api_url = Some(url.into());
+        self
+    }
+
+    /// Overrides the RPC endpoint determined by the cluster
+    pub fn rpc_url(mut self, url: impl Into<String>) -> Self {
+        self.rpc_url = Some(url.into());
+        self
+    }
+
+    /// Overrides the enhanced websocket endpoint determined by the cluster. The API key is appended to this URL
+    pub fn websocket_url(mut self, url: impl Into<String>) -> Self {
+        self.websocket_url = Some(url.into());
+        self
+    }
+
+    /// Overrides the Sender endpoint used for `send_smart_transaction_with_sender` and related methods. When set, the
+    /// region in `SenderSendOptions` is ignored
+    pub fn sender_url(mut self, url: impl Into<String>) -> Self {
+        self.sender_url = Some(url.into());
+        self
+    }
+
+    /// Checks that the settings can be used to build a client without creating one
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if:
+    /// - The API key is missing or empty
+    /// - An endpoint, including those of a `Cluster::Custom`, is not a valid URL
+    /// - A timeout, ping interval, or rate limit of zero is provided
+    /// - Timeouts are combined with a user-provided `reqwest::Client`
+    /// - A websocket is requested for a cluster without an enhanced websocket endpoint
+    pub fn validate(&self) -> Result<()> {
+        if self.api_key.as_ref().is_none_or(Secret::is_empty) {
+            return Err(HeliusError::InvalidInput("API key cannot be empty".to_string()));
+        }
+
+        let endpoints: HeliusEndpoints = self.endpoints();
+        for (name, url) in [
+            ("API", Some(&endpoints.api)),
+            ("RPC", Some(&endpoints.rpc)),
+            ("websocket", endpoints.websocket.as_ref()),
+            ("Sender", endpoints.sender.as_ref()),
+        ] {
+            if let Some(url) = url {
+                Url::parse(url).map_err(|e| HeliusError::InvalidInput(format!("Invalid {} URL: {}", name, e)))?;
+            }
+        }
+
+        if self.timeout.is_some_and(|timeout| timeout.is_zero())
+            || self.connect_timeout.is_some_and(|timeout| timeout.is_zero())
+        {
+            return Err(HeliusError::InvalidInput(
+                "Timeouts must be greater than zero".to_string(),
+            ));
+        }
+
+        if let Some(RateLimiterSource::Limits(limits)) = &self.rate_limiter {
+            limits.validate()?;
+        }
+
+        if self.http_client.is_some() && (self.timeout.is_some() || self.connect_timeout.is_some()) {
+            return Err(HeliusError::InvalidInput(
+                "Timeouts cannot be applied to a provided reqwest client; configure them on the client instead"
+                    .to_string(),
+            ));
+        }
+
+        #[cfg(feature = "enhanced-ws")]
+        if let Some(options) = &self.websocket {
+            if options.ping_interval_secs == Some(0) {
+                return Err(HeliusError::InvalidInput(
+                    "Websocket ping interval must be greater than zero".to_string(),
+                ));
+            }
+
+            EnhancedWebsocket::get_url_for_endpoints(&endpoints, "")?;
+        }
+
+        Ok(())
+    }
+
+    /// Builds a `Helius` client from the current settings
+    ///
+    /// # Errors
+    /// Returns a `HeliusError` if the settings fail validation, if a websocket was requested (use `connect` instead), or if the
+    /// HTTP client fails to initialize
+    pub fn build(self) -> Result<Helius> {
+        #[cfg(feature = "enhanced-ws")]
+        if self.websocket.is_some() {
+            return Err(HeliusError::InvalidInput(
+                "A websocket connection was requested; use `connect` to build the client instead".to_string(),
+            ));
+        }
+
+        self.validate()?;
+        self.build_without_ws()
+    }
+
+    /// Builds a `Helius` client from the current settings and connects to the enhanced websocket, if requested
+    ///
+    /// # Errors
+    /// Returns a `HeliusError` if the settings fail validation, or if the HTTP client or websocket fail to initialize
+    pub async fn connect(self) -> Result<Helius> {
+        self.validate()?;
+
+        #[cfg(feature = "enhanced-ws")]
+        let websocket: Option<WebsocketOptions> = self.websocket;
+        let helius: Helius = self.build_without_ws()?;
+
+        #[cfg(feature = "enhanced-ws")]
+        let helius: Helius = match websocket {
+            Some(options) => {
+                let ws_client: EnhancedWebsocket = EnhancedWebsocket::new_with_metrics(
+                    helius.config.websocket_request()?,
+                    options.ping_interval_secs,
+                    options.pong_timeout_secs,
+                    helius.rpc_client.handler.metrics().cloned(),
+                )
+                .await?;
+                Helius {
+                    ws_client: Some(Arc::new(ws_client)),
+                    ..helius
+                }
+            }
+            None => helius,
+        };
+
+        Ok(helius)
+    }
+
+    fn target_cluster(&self) -> Cluster {
+        self.cluster.clone().unwrap_or(Cluster::MainnetBeta)
+    }
+
+    fn endpoints(&self) -> HeliusEndpoints {
+        let mut endpoints: HeliusEndpoints = HeliusEndpoints::for_cluster(&self.target_cluster());
+
+        if let Some(api_url) = &self.api_url {
+            endpoints.api = api_url.clone();
+        }
+        if let Some(rpc_url) = &self.rpc_url {
+            endpoints.rpc = rpc_url.clone();
+        }
+        if let Some(websocket_url) = &self.websocket_url {
+            endpoints.websocket = Some(websocket_url.clone());
+        }
+        if let Some(sender_url) = &self.sender_url {
+            endpoints.sender = Some(sender_url.clone());
+        }
+
+        endpoints
+    }
+
+    fn build_without_ws(self) -> Result<Helius> {
+        let mut config: Config = Config::new(
+            self.api_key.as_ref().map(Secret::expose).unwrap_or_default(),
+            self.target_cluster(),
+        )?;
+        config.auth = self.auth;
+        config.endpoints = self.endpoints();
+
+        let config: Arc<Config> = Arc::new(config);
+        let client: Client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut client_builder = Client::builder();
+                if let Some(timeout) = self.timeout {
+                    client_builder = client_builder.timeout(timeout);
+                }
+                if let Some(connect_timeout) = self.connect_timeout {
+                    client_builder = client_builder.connect_timeout(connect_timeout);
+                }
+                client_builder.build().map_err(HeliusError::ReqwestError)?
+            }
+        };
+
+        let commitment: CommitmentConfig = self.commitment.unwrap_or_default();
+
+        let mut handler: RequestHandler = config.request_handler(Arc::new(client.clone()))?;
+        if let Some(policy) = self.retry_policy {
+            handler = handler.with_retry_policy(policy);
+        }
+        match self.rate_limiter {
+            Some(RateLimiterSource::Limits(limits)) => {
+                handler = handler.with_rate_limiter(Arc::new(RateLimiter::new(limits)?));
+            }
+            Some(RateLimiterSource::Shared(rate_limiter)) => handler = handler.with_rate_limiter(rate_limiter),
+            None => {}
+        }
+        for middleware in self.middleware {
+            handler = handler.with_middleware(middleware);
+        }
+        if let Some(metrics) = self.metrics {
+            handler = handler.with_metrics(metrics);
+        }
+        #[cfg(feature = "cassette")]
+        if let Some(cassette) = self.cassette {
+            handler = handler.with_cassette(cassette);
+        }
+
+        let rpc_client: Arc<RpcClient> = Arc::new(RpcClient {
+            handler,
+            config: config.clone(),
+            solana_client: Arc::new(config.solana_rpc_client(commitment, self.timeout)?),
+            async_solana_client: Arc::new(config.async_solana_rpc_client(commitment, self.timeout)?),
+        });
+        let async_rpc_client: Option<Arc<AsyncSolanaRpcClient>> = match self.async_solana {
+            true => Some(rpc_client.async_solana_client.clone()),
+            false => None,
+        };
+
+        Ok(Helius {
+            config,
+            client,
+            rpc_client,
+            async_rpc_client,
+            #[cfg(feature = "enhanced-ws")]
+            ws_client: None,
+        })
+    }
+}
+
+/// The rate limiter requested for a builder, either created from limits or shared with other clients
+enum RateLimiterSource {
+    Limits(RateLimits),
+    Shared(Arc<RateLimiter>),
+}
+
+impl From<Config> for HeliusBuilder {
+    /// Creates a `HeliusBuilder` that keeps the API key, cluster, and endpoints of an existing `Config`
+    fn from(config: Config) -> Self {
+        HeliusBuilder {
+            api_key: Some(config.api_key),
+            auth: config.auth,
+            cluster: Some(config.cluster),
+            api_url: Some(config.endpoints.api),
+            rpc_url: Some(config.endpoints.rpc),
+            websocket_url: config.endpoints.websocket,
+            sender_url: config.endpoints.sender,
+            ..Default::default()
+        }
+    }
+}
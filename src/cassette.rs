@@ -0,0 +1,315 @@
+//! Record and replay of HTTP interactions for regression tests
+//!
+//! A `Cassette` attached to the `RequestHandler` either records every request sent through it, along with its response,
+//! to a JSON file, or replays a previously recorded file without touching the network. Recording a real session once
+//! captures realistic payloads (e.g., DAS assets or enhanced transactions) that can then be checked in as fixtures.
+//!
+//! The API key is never written to a cassette: the `api-key` query parameter is replaced with `REDACTED` and request
+//! headers are not recorded. Only requests sent through the `RequestHandler` are recorded, so calls made by the embedded
+//! Solana clients and the enhanced websocket are not
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::error::{HeliusError, Result};
+use crate::secret::redact_url;
+
+use reqwest::header::{HeaderName, CONTENT_TYPE, RETRY_AFTER};
+use reqwest::{Client, Request, Response, ResponseBuilderExt, Url};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The response headers kept in a cassette. Others, such as cookies and request IDs, only add noise to fixtures
+const RECORDED_HEADERS: [HeaderName; 2] = [CONTENT_TYPE, RETRY_AFTER];
+
+/// Whether a `Cassette` records new interactions or replays recorded ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Sends requests over the network and appends each interaction to the cassette file
+    Record,
+    /// Answers requests from the cassette file without sending them
+    Replay,
+}
+
+/// A request as stored in a cassette
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// The request URL, with the API key redacted
+    pub url: String,
+    /// The JSON request body, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// A response as stored in a cassette
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// The response body, if it is JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+    /// The response body, if it is not JSON (e.g., an empty body or plain text)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub text: String,
+}
+
+/// A request and the response it received
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Default)]
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    /// Whether each interaction has been replayed already
+    replayed: Vec<bool>,
+}
+
+/// A file of recorded HTTP interactions that the `RequestHandler` records to or replays from
+///
+/// When replaying, each request is answered by the first interaction that has not been replayed yet with the same
+/// method, URL, and body. The `id` of JSON-RPC request bodies is ignored when matching, since it may vary between runs.
+/// Interactions are replayed at most once, so repeated requests (e.g., polling or retries) receive their responses in
+/// the order they were recorded
+///
+/// # Example
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use helius::cassette::Cassette;
+/// use helius::types::Cluster;
+/// use helius::Helius;
+///
+/// # fn main() -> helius::error::Result<()> {
+/// let cassette: Arc<Cassette> = Arc::new(Cassette::replay("tests/cassettes/get_asset.json")?);
+/// let helius: Helius = Helius::builder()
+///     .api_key("any_api_key")
+///     .cluster(Cluster::MainnetBeta)
+///     .cassette(cassette)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Mutex<CassetteState>,
+}
+
+impl Cassette {
+    /// Creates a cassette that records to the file at `path`, replacing any existing file once the first interaction is
+    /// recorded
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            mode: CassetteMode::Record,
+            state: Mutex::new(CassetteState::default()),
+        }
+    }
+
+    /// Loads a cassette from the file at `path` to replay its interactions
+    ///
+    /// # Errors
+    /// Returns `HeliusError::Cassette` if the file cannot be read, or `HeliusError::SerdeJson` if it is not a valid
+    /// cassette
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path: PathBuf = path.into();
+        let contents: String = fs::read_to_string(&path)
+            .map_err(|err| HeliusError::Cassette(format!("Failed to read {}: {err}", path.display())))?;
+        let file: CassetteFile = serde_json::from_str(&contents)?;
+
+        Ok(Self {
+            path,
+            mode: CassetteMode::Replay,
+            state: Mutex::new(CassetteState {
+                replayed: vec![false; file.interactions.len()],
+                interactions: file.interactions,
+            }),
+        })
+    }
+
+    /// Records if the file at `path` does not exist yet, and replays it otherwise
+    ///
+    /// # Errors
+    /// Returns an error if an existing file cannot be loaded for replay
+    pub fn record_or_replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path: PathBuf = path.into();
+        match path.exists() {
+            true => Self::replay(path),
+            false => Ok(Self::record(path)),
+        }
+    }
+
+    /// Returns the path of the cassette file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns whether the cassette records or replays
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Returns the interactions recorded so far, or loaded for replay
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.lock().interactions.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CassetteState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Sends the request over the network and records it, or answers it from the cassette, depending on the mode
+    ///
+    /// # Errors
+    /// Returns `HeliusError::Network` if a request being recorded fails to send, or `HeliusError::Cassette` if the
+    /// cassette cannot be written or has no interaction left for a replayed request
+    pub(crate) async fn execute(&self, client: &Client, request: Request) -> Result<Response> {
+        let url: Url = request.url().clone();
+        let recorded_request: RecordedRequest = RecordedRequest {
+            method: request.method().to_string(),
+            url: redacted(&url),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .and_then(|body| serde_json::from_slice(body).ok()),
+        };
+
+        match self.mode {
+            CassetteMode::Record => self.record_interaction(client, request, recorded_request).await,
+            CassetteMode::Replay => self.replay_interaction(url, &recorded_request),
+        }
+    }
+
+    async fn record_interaction(
+        &self,
+        client: &Client,
+        request: Request,
+        recorded_request: RecordedRequest,
+    ) -> Result<Response> {
+        let url: Url = request.url().clone();
+        let response: Response = client.execute(request).await.map_err(HeliusError::network)?;
+
+        let status: u16 = response.status().as_u16();
+        let headers: BTreeMap<String, String> = RECORDED_HEADERS
+            .iter()
+            .filter_map(|name| {
+                let value: &str = response.headers().get(name)?.to_str().ok()?;
+                Some((name.to_string(), value.to_string()))
+            })
+            .collect();
+        let bytes: Vec<u8> = response.bytes().await.map_err(HeliusError::network)?.to_vec();
+
+        let (body, text) = match serde_json::from_slice::<Value>(&bytes) {
+            Ok(body) => (Some(body), String::new()),
+            Err(_) => (None, String::from_utf8_lossy(&bytes).into_owned()),
+        };
+        let recorded_response: RecordedResponse = RecordedResponse {
+            status,
+            headers,
+            body,
+            text,
+        };
+
+        let mut state = self.lock();
+        state.interactions.push(Interaction {
+            request: recorded_request,
+            response: recorded_response.clone(),
+        });
+        state.replayed.push(true);
+        self.save(&state.interactions)?;
+        drop(state);
+
+        to_response(url, &recorded_response)
+    }
+
+    fn replay_interaction(&self, url: Url, request: &RecordedRequest) -> Result<Response> {
+        let mut state = self.lock();
+        let CassetteState { interactions, replayed } = &mut *state;
+
+        let index: usize = interactions
+            .iter()
+            .zip(replayed.iter())
+            .position(|(interaction, replayed)| !replayed && matches(&interaction.request, request))
+            .ok_or_else(|| {
+                HeliusError::Cassette(format!(
+                    "No unplayed interaction in {} matches {} {}",
+                    self.path.display(),
+                    request.method,
+                    request.url
+                ))
+            })?;
+
+        replayed[index] = true;
+        to_response(url, &interactions[index].response)
+    }
+
+    fn save(&self, interactions: &[Interaction]) -> Result<()> {
+        let file: CassetteFile = CassetteFile {
+            interactions: interactions.to_vec(),
+        };
+        let contents: String = serde_json::to_string_pretty(&file)?;
+        fs::write(&self.path, contents)
+            .map_err(|err| HeliusError::Cassette(format!("Failed to write {}: {err}", self.path.display())))
+    }
+}
+
+/// Returns the URL as a string with the API key redacted
+fn redacted(url: &Url) -> String {
+    let mut url: Url = url.clone();
+    redact_url(&mut url);
+    url.to_string()
+}
+
+/// Compares two requests, ignoring the `id` of JSON-RPC bodies
+fn matches(recorded: &RecordedRequest, request: &RecordedRequest) -> bool {
+    recorded.method == request.method
+        && recorded.url == request.url
+        && without_rpc_id(&recorded.body) == without_rpc_id(&request.body)
+}
+
+fn without_rpc_id(body: &Option<Value>) -> Option<Value> {
+    let strip = |value: &Value| match value {
+        Value::Object(object) if object.contains_key("jsonrpc") => {
+            let mut object = object.clone();
+            object.remove("id");
+            Value::Object(object)
+        }
+        value => value.clone(),
+    };
+
+    body.as_ref().map(|body| match body {
+        // Batches are matched call by call
+        Value::Array(calls) => Value::Array(calls.iter().map(strip).collect()),
+        body => strip(body),
+    })
+}
+
+/// Rebuilds a `reqwest::Response` from a recorded response, attributing it to the given URL
+fn to_response(url: Url, recorded: &RecordedResponse) -> Result<Response> {
+    let body: Vec<u8> = match &recorded.body {
+        Some(body) => serde_json::to_vec(body)?,
+        None => recorded.text.clone().into_bytes(),
+    };
+
+    let mut builder = http::Response::builder().status(recorded.status).url(url);
+    for (name, value) in &recorded.headers {
+        builder = builder.header(name, value);
+    }
+
+    let response = builder
+        .body(body)
+        .map_err(|err| HeliusError::Cassette(format!("Invalid recorded response: {err}")))?;
+    Ok(Response::from(response))
+}
@@ -1,9 +1,13 @@
 use std::{ops::Deref, sync::Arc};
 
+use crate::builder::HeliusBuilder;
+#[cfg(feature = "enhanced-ws")]
+use crate::builder::WebsocketOptions;
 use crate::config::Config;
-use crate::error::{HeliusError, Result};
+use crate::error::Result;
 use crate::rpc_client::RpcClient;
 use crate::types::Cluster;
+#[cfg(feature = "enhanced-ws")]
 use crate::websocket::EnhancedWebsocket;
 
 use reqwest::Client;
@@ -15,6 +19,9 @@ use solana_commitment_config::CommitmentConfig;
 ///
 /// This client is responsible for setting up the network and configuration settins used to interact with the various provided methods.
 /// It also provides methods to access RPC client functionalities. The client ensures thread-safe access to the underlying RPC client
+///
+/// Cloning a `Helius` client is cheap, and clones share the same underlying clients, rate limiter, and websocket connection
+#[derive(Clone)]
 pub struct Helius {
     /// The configuration which specifies an `api_key`, `cluster`, and the requisite `endpoints`
     pub config: Arc<Config>,
@@ -25,10 +32,31 @@ pub struct Helius {
     /// An optional asynchronous Solana client for async operations
     pub async_rpc_client: Option<Arc<AsyncSolanaRpcClient>>,
     /// A reference-counted enhanced (geyser) websocket client
+    #[cfg(feature = "enhanced-ws")]
     pub ws_client: Option<Arc<EnhancedWebsocket>>,
 }
 
 impl Helius {
+    /// Creates a `HeliusBuilder` for configuring a new instance of `Helius`
+    ///
+    /// The builder can set the cluster, commitment, `reqwest` client, asynchronous Solana client, enhanced websocket, timeouts, and endpoint
+    /// overrides in any combination
+    ///
+    /// # Example
+    /// ```rust
+    /// use helius::Helius;
+    /// use helius::types::Cluster;
+    ///
+    /// let helius = Helius::builder()
+    ///     .api_key("your_api_key")
+    ///     .cluster(Cluster::Devnet)
+    ///     .build()
+    ///     .expect("Failed to create a Helius client");
+    /// ```
+    pub fn builder() -> HeliusBuilder {
+        HeliusBuilder::new()
+    }
+
     /// Creates a new instance of `Helius` configured with a specific API key and a target cluster
     ///
     /// # Arguments
@@ -46,17 +74,7 @@ impl Helius {
     /// let helius = Helius::new("your_api_key", Cluster::Devnet).expect("Failed to create a Helius client");
     /// ```
     pub fn new(api_key: &str, cluster: Cluster) -> Result<Self> {
-        let config: Arc<Config> = Arc::new(Config::new(api_key, cluster)?);
-        let client: Client = Client::builder().build().map_err(HeliusError::ReqwestError)?;
-        let rpc_client: Arc<RpcClient> = Arc::new(RpcClient::new(Arc::new(client.clone()), config.clone())?);
-
-        Ok(Helius {
-            config,
-            client,
-            rpc_client,
-            async_rpc_client: None,
-            ws_client: None,
-        })
+        Self::builder().api_key(api_key).cluster(cluster).build()
     }
 
     /// Creates a new instance of `Helius` configured with a specific API key, target cluster, and a commitment config
@@ -78,21 +96,11 @@ impl Helius {
     /// let helius = Helius::new_with_commitment("your_api_key", Cluster::Devnet, CommitmentConfig::confirmed()).expect("Failed to create a Helius client");
     /// ```
     pub fn new_with_commitment(api_key: &str, cluster: Cluster, commitment: CommitmentConfig) -> Result<Self> {
-        let config: Arc<Config> = Arc::new(Config::new(api_key, cluster)?);
-        let client: Client = Client::builder().build().map_err(HeliusError::ReqwestError)?;
-        let rpc_client: Arc<RpcClient> = Arc::new(RpcClient::new_with_commitment(
-            Arc::new(client.clone()),
-            config.clone(),
-            commitment,
-        )?);
-
-        Ok(Helius {
-            config,
-            client,
-            rpc_client,
-            async_rpc_client: None,
-            ws_client: None,
-        })
+        Self::builder()
+            .api_key(api_key)
+            .cluster(cluster)
+            .commitment(commitment)
+            .build()
     }
 
     /// Creates a new instance of `Helius` with an asynchronous Solana client
@@ -112,18 +120,11 @@ impl Helius {
     /// let helius = Helius::new_with_async_solana("your_api_key", Cluster::Devnet).expect("Failed to create a Helius client");
     /// ```
     pub fn new_with_async_solana(api_key: &str, cluster: Cluster) -> Result<Self> {
-        let config: Arc<Config> = Arc::new(Config::new(api_key, cluster)?);
-        let client: Client = Client::builder().build().map_err(HeliusError::ReqwestError)?;
-        let url: String = format!("{}/?api-key={}", config.endpoints.rpc, config.api_key);
-        let async_solana_client: Arc<AsyncSolanaRpcClient> = Arc::new(AsyncSolanaRpcClient::new(url));
-
-        Ok(Helius {
-            config: config.clone(),
-            client: client.clone(),
-            rpc_client: Arc::new(RpcClient::new(Arc::new(client), config.clone())?),
-            async_rpc_client: Some(async_solana_client),
-            ws_client: None,
-        })
+        Self::builder()
+            .api_key(api_key)
+            .cluster(cluster)
+            .with_async_solana()
+            .build()
     }
 
     /// Creates a new instance of `Helius` with an asynchronous Solana client
@@ -151,23 +152,12 @@ impl Helius {
         cluster: Cluster,
         commitment: CommitmentConfig,
     ) -> Result<Self> {
-        let config: Arc<Config> = Arc::new(Config::new(api_key, cluster)?);
-        let client: Client = Client::builder().build().map_err(HeliusError::ReqwestError)?;
-        let url: String = format!("{}/?api-key={}", config.endpoints.rpc, config.api_key);
-        let async_solana_client: Arc<AsyncSolanaRpcClient> =
-            Arc::new(AsyncSolanaRpcClient::new_with_commitment(url, commitment));
-
-        Ok(Helius {
-            config: config.clone(),
-            client: client.clone(),
-            rpc_client: Arc::new(RpcClient::new_with_commitment(
-                Arc::new(client),
-                config.clone(),
-                commitment,
-            )?),
-            async_rpc_client: Some(async_solana_client),
-            ws_client: None,
-        })
+        Self::builder()
+            .api_key(api_key)
+            .cluster(cluster)
+            .commitment(commitment)
+            .with_async_solana()
+            .build()
     }
 
     /// The enhanced websocket is optional, and this method is used to create a new instance of `Helius` with an enhanced websocket client.
@@ -181,27 +171,22 @@ impl Helius {
     ///
     /// # Returns
     /// An instance of `Helius` if successful. A `HeliusError` is returned if an error occurs during configuration or initialization of the HTTP, RPC, or WS client
+    #[cfg(feature = "enhanced-ws")]
     pub async fn new_with_ws_with_timeouts(
         api_key: &str,
         cluster: Cluster,
         ping_interval_secs: Option<u64>,
         pong_timeout_secs: Option<u64>,
     ) -> Result<Self> {
-        let config: Arc<Config> = Arc::new(Config::new(api_key, cluster.clone())?);
-        let client: Client = Client::builder().build().map_err(HeliusError::ReqwestError)?;
-        let rpc_client: Arc<RpcClient> = Arc::new(RpcClient::new(Arc::new(client.clone()), config.clone())?);
-
-        let wss: String = EnhancedWebsocket::get_url(&cluster, api_key)?;
-        let ws_client: Arc<EnhancedWebsocket> =
-            Arc::new(EnhancedWebsocket::new(&wss, ping_interval_secs, pong_timeout_secs).await?);
-
-        Ok(Helius {
-            config,
-            client,
-            rpc_client,
-            async_rpc_client: None,
-            ws_client: Some(ws_client),
-        })
+        Self::builder()
+            .api_key(api_key)
+            .cluster(cluster)
+            .websocket(WebsocketOptions {
+                ping_interval_secs,
+                pong_timeout_secs,
+            })
+            .connect()
+            .await
     }
 
     /// Creates a new instance of `Helius` with an enhanced websocket client using default timeout settings.
@@ -214,6 +199,7 @@ impl Helius {
     ///
     /// # Returns
     /// An instance of `Helius` if successful. A `HeliusError` is returned if an error occurs during configuration or initialization of the HTTP, RPC, or WS client
+    #[cfg(feature = "enhanced-ws")]
     pub async fn new_with_ws(api_key: &str, cluster: Cluster) -> Result<Self> {
         Self::new_with_ws_with_timeouts(api_key, cluster, None, None).await
     }
@@ -228,26 +214,30 @@ impl Helius {
 
     /// Provides a thread-safe way to access asynchronous Solana client functionalities
     ///
+    /// This is the client used by the SDK's own async methods. If no `async_rpc_client` was set, the embedded
+    /// asynchronous client of the `RpcClient` is used
+    ///
     /// # Returns
-    /// A `Result` containing a `HeliusAsyncSolanaClient` if an `async_rpc_client` exists, otherwise a `HeliusError`
+    /// A `Result` containing a `HeliusAsyncSolanaClient`
     pub fn async_connection(&self) -> Result<HeliusAsyncSolanaClient> {
-        match &self.async_rpc_client {
-            Some(client) => Ok(HeliusAsyncSolanaClient::new(client.clone())),
-            None => Err(HeliusError::ClientNotInitialized {
-                text: "An asynchronous Solana RPC client must be initialized before trying to access async_connection"
-                    .to_string(),
-            }),
-        }
+        let client: Arc<AsyncSolanaRpcClient> = self
+            .async_rpc_client
+            .clone()
+            .unwrap_or_else(|| self.rpc_client.async_solana_client.clone());
+        Ok(HeliusAsyncSolanaClient::new(client))
     }
 
     /// Provides a thread-safe way to access synchronous Solana client functionalities
     ///
+    /// The synchronous client blocks the current thread, so use `async_connection` inside async code
+    ///
     /// # Returns
     /// A cloned `Arc<SolanaRpcClient>` that can be safely shared across threads
     pub fn connection(&self) -> Arc<SolanaRpcClient> {
         self.rpc_client.solana_client.clone()
     }
 
+    #[cfg(feature = "enhanced-ws")]
     pub fn ws(&self) -> Option<Arc<EnhancedWebsocket>> {
         self.ws_client.clone()
     }
@@ -1,20 +1,53 @@
+use crate::builder::HeliusBuilder;
+#[cfg(feature = "enhanced-ws")]
+use crate::builder::WebsocketOptions;
 use crate::error::{HeliusError, Result};
+use crate::request_handler::RequestHandler;
 use crate::rpc_client::RpcClient;
+use crate::secret::Secret;
 use crate::types::{Cluster, HeliusEndpoints, MintApiAuthority};
+#[cfg(feature = "enhanced-ws")]
 use crate::websocket::EnhancedWebsocket;
 use crate::Helius;
-use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Client, Url};
 use solana_client::nonblocking::rpc_client::RpcClient as AsyncSolanaRpcClient;
+use solana_client::rpc_client::RpcClient as SolanaRpcClient;
+use solana_commitment_config::CommitmentConfig;
+use solana_rpc_client::http_sender::HttpSender;
+use solana_rpc_client::rpc_client::RpcClientConfig;
 use std::sync::Arc;
-use url::{ParseError, Url};
+use std::time::Duration;
+#[cfg(feature = "enhanced-ws")]
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+#[cfg(feature = "enhanced-ws")]
+use tokio_tungstenite::tungstenite::handshake::client::Request;
+
+/// The header used to send the API key when authenticating with `AuthMethod::Header`
+pub const API_KEY_HEADER: &str = "X-Api-Key";
+
+/// The default timeout used by the embedded Solana clients, matching `solana_client`'s own default
+const DEFAULT_SOLANA_CLIENT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Determines how the API key is sent to Helius
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AuthMethod {
+    /// Appends the API key to each URL as an `api-key` query parameter
+    #[default]
+    QueryParam,
+    /// Sends the API key in the `X-Api-Key` header so it never appears in a URL
+    Header,
+}
 
 /// Configuration settings for the Helius client
 ///
 /// `Config` contains all the necessary parameters needed to configure and authenticate the `Helius` client to interact with a specific Solana cluster
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct Config {
-    /// The API key used for authenticating requests
-    pub api_key: String,
+    /// The API key used for authenticating requests. It is redacted from `Debug` output
+    pub api_key: Secret,
+    /// How the API key is sent with each request
+    pub auth: AuthMethod,
     /// The target Solana cluster the client will interact with
     pub cluster: Cluster,
     /// The endpoints associated with the specified `cluster`. Note these endpoints are automatically determined based on the cluster to ensure requests
@@ -25,6 +58,8 @@ pub struct Config {
 impl Config {
     /// Creates a new configuration for the `Helius` client
     ///
+    /// The API key is sent as a query parameter by default. Set `auth` to `AuthMethod::Header` to send it as a header instead
+    ///
     /// # Arguments
     /// * `api_key` - A string slice that holds the API key necessary for authenticating the client
     /// * `cluster` - The Solana cluster to interact with
@@ -42,12 +77,122 @@ impl Config {
         let endpoints: HeliusEndpoints = HeliusEndpoints::for_cluster(&cluster);
 
         Ok(Config {
-            api_key: api_key.to_string(),
+            api_key: Secret::from(api_key),
+            auth: AuthMethod::default(),
             cluster,
             endpoints,
         })
     }
 
+    /// Parses `url` and appends the API key as an `api-key` query parameter when using `AuthMethod::QueryParam`
+    ///
+    /// # Errors
+    /// Returns `HeliusError::UrlParseError` if `url` is not a valid URL
+    pub fn authenticated_url(&self, url: &str) -> Result<Url> {
+        let mut url: Url = Url::parse(url)?;
+        if self.auth == AuthMethod::QueryParam {
+            url.query_pairs_mut().append_pair("api-key", self.api_key.expose());
+        }
+
+        Ok(url)
+    }
+
+    /// Returns the API key as a sensitive header value
+    fn api_key_header_value(&self) -> Result<HeaderValue> {
+        let mut value: HeaderValue = HeaderValue::from_str(self.api_key.expose())
+            .map_err(|_| HeliusError::InvalidInput("API key is not a valid header value".to_string()))?;
+        value.set_sensitive(true);
+
+        Ok(value)
+    }
+
+    /// Creates a `RequestHandler` that authenticates requests using this configuration
+    pub(crate) fn request_handler(&self, client: Arc<Client>) -> Result<RequestHandler> {
+        let handler: RequestHandler = RequestHandler::new(client)?;
+        match self.auth {
+            AuthMethod::QueryParam => Ok(handler),
+            AuthMethod::Header => Ok(handler.with_api_key_header(self.api_key_header_value()?)),
+        }
+    }
+
+    /// Creates the HTTP sender used by the embedded Solana clients
+    fn solana_http_sender(&self, timeout: Option<Duration>) -> Result<HttpSender> {
+        let timeout: Duration = timeout.unwrap_or(DEFAULT_SOLANA_CLIENT_TIMEOUT);
+        let url: Url = self.authenticated_url(&format!("{}/", self.endpoints.rpc))?;
+
+        match self.auth {
+            AuthMethod::QueryParam => Ok(HttpSender::new_with_timeout(url, timeout)),
+            AuthMethod::Header => {
+                let mut headers: HeaderMap = HttpSender::default_headers();
+                headers.insert(API_KEY_HEADER, self.api_key_header_value()?);
+
+                let client: Client = Client::builder()
+                    .default_headers(headers)
+                    .timeout(timeout)
+                    .pool_idle_timeout(timeout)
+                    .build()
+                    .map_err(HeliusError::from)?;
+                Ok(HttpSender::new_with_client(url, client))
+            }
+        }
+    }
+
+    /// Creates an embedded synchronous Solana client for this configuration
+    pub(crate) fn solana_rpc_client(
+        &self,
+        commitment: CommitmentConfig,
+        timeout: Option<Duration>,
+    ) -> Result<SolanaRpcClient> {
+        Ok(SolanaRpcClient::new_sender(
+            self.solana_http_sender(timeout)?,
+            RpcClientConfig::with_commitment(commitment),
+        ))
+    }
+
+    /// Creates an embedded asynchronous Solana client for this configuration
+    pub(crate) fn async_solana_rpc_client(
+        &self,
+        commitment: CommitmentConfig,
+        timeout: Option<Duration>,
+    ) -> Result<AsyncSolanaRpcClient> {
+        Ok(AsyncSolanaRpcClient::new_sender(
+            self.solana_http_sender(timeout)?,
+            RpcClientConfig::with_commitment(commitment),
+        ))
+    }
+
+    /// Creates the handshake request for the enhanced websocket, authenticated using this configuration
+    ///
+    /// # Errors
+    /// Returns `HeliusError::EnhancedWebsocket` if the endpoints do not include a websocket endpoint
+    #[cfg(feature = "enhanced-ws")]
+    pub(crate) fn websocket_request(&self) -> Result<Request> {
+        match self.auth {
+            AuthMethod::QueryParam => Ok(EnhancedWebsocket::get_url_for_endpoints(
+                &self.endpoints,
+                self.api_key.expose(),
+            )?
+            .into_client_request()?),
+            AuthMethod::Header => {
+                let url: &str = self
+                    .endpoints
+                    .websocket
+                    .as_deref()
+                    .ok_or_else(|| HeliusError::EnhancedWebsocket {
+                        reason: "Unsupported cluster".into(),
+                        message: "no enhanced websocket endpoint is configured for this cluster".into(),
+                    })?;
+
+                let mut request: Request = Url::parse(url)?.as_str().into_client_request()?;
+                request
+                    .headers_mut()
+                    .insert(API_KEY_HEADER, self.api_key_header_value()?);
+                Ok(request)
+            }
+        }
+    }
+
+    /// Creates an `RpcClient` from this configuration that uses the given `reqwest::Client`
     pub fn rpc_client_with_reqwest_client(&self, client: Client) -> Result<RpcClient> {
         RpcClient::new(Arc::new(client), Arc::new(self.clone()))
     }
@@ -57,16 +202,7 @@ impl Config {
     /// # Returns
     /// A `Result` containing a Helius client with basic RPC capabilities
     pub fn create_client(self) -> Result<Helius> {
-        let client: Client = Client::builder().build().map_err(HeliusError::ReqwestError)?;
-        let rpc_client: Arc<RpcClient> = Arc::new(self.rpc_client_with_reqwest_client(client.clone())?);
-
-        Ok(Helius {
-            config: Arc::new(self),
-            client,
-            rpc_client,
-            async_rpc_client: None,
-            ws_client: None,
-        })
+        HeliusBuilder::from(self).build()
     }
 
     /// Creates a Helius client with async Solana capabilities
@@ -74,22 +210,7 @@ impl Config {
     /// # Returns
     /// A `Result` containing a Helius client with both RPC and async Solana capabilities
     pub fn create_client_with_async(self) -> Result<Helius> {
-        let client: Client = Client::builder().build().map_err(HeliusError::ReqwestError)?;
-        let mut rpc_url: Url = Url::parse(&self.endpoints.rpc)
-            .map_err(|e: ParseError| HeliusError::InvalidInput(format!("Invalid RPC URL: {}", e)))?;
-
-        rpc_url.query_pairs_mut().append_pair("api-key", &self.api_key);
-
-        let async_solana_client: Arc<AsyncSolanaRpcClient> = Arc::new(AsyncSolanaRpcClient::new(rpc_url.to_string()));
-        let rpc_client: Arc<RpcClient> = Arc::new(self.rpc_client_with_reqwest_client(client.clone())?);
-
-        Ok(Helius {
-            config: Arc::new(self),
-            client,
-            rpc_client,
-            async_rpc_client: Some(async_solana_client),
-            ws_client: None,
-        })
+        HeliusBuilder::from(self).with_async_solana().build()
     }
 
     /// Creates a Helius client with websocket support
@@ -100,25 +221,19 @@ impl Config {
     ///
     /// # Returns
     /// A `Result` containing a Helius client with websocket support
+    #[cfg(feature = "enhanced-ws")]
     pub async fn create_client_with_ws(
         self,
         ping_interval_secs: Option<u64>,
         pong_timeout_secs: Option<u64>,
     ) -> Result<Helius> {
-        let client: Client = Client::builder().build().map_err(HeliusError::ReqwestError)?;
-        let rpc_client: Arc<RpcClient> = Arc::new(self.rpc_client_with_reqwest_client(client.clone())?);
-
-        let wss: String = EnhancedWebsocket::get_url(&self.cluster, &self.api_key)?;
-        let ws_client: Arc<EnhancedWebsocket> =
-            Arc::new(EnhancedWebsocket::new(&wss, ping_interval_secs, pong_timeout_secs).await?);
-
-        Ok(Helius {
-            config: Arc::new(self),
-            client,
-            rpc_client,
-            async_rpc_client: None,
-            ws_client: Some(ws_client),
-        })
+        HeliusBuilder::from(self)
+            .websocket(WebsocketOptions {
+                ping_interval_secs,
+                pong_timeout_secs,
+            })
+            .connect()
+            .await
     }
 
     /// Creates a full-featured Helius client with both async and websocket support
@@ -129,35 +244,27 @@ impl Config {
     ///
     /// # Returns
     /// A `Result` containing a fully-featured Helius client
+    #[cfg(feature = "enhanced-ws")]
     pub async fn create_full_client(
         self,
         ping_interval_secs: Option<u64>,
         pong_timeout_secs: Option<u64>,
     ) -> Result<Helius> {
-        let client: Client = Client::builder().build().map_err(HeliusError::ReqwestError)?;
-        let rpc_client: Arc<RpcClient> = Arc::new(self.rpc_client_with_reqwest_client(client.clone())?);
-
-        // Setup async client
-        let mut rpc_url: Url = Url::parse(&self.endpoints.rpc)
-            .map_err(|e: ParseError| HeliusError::InvalidInput(format!("Invalid RPC URL: {}", e)))?;
-        rpc_url.query_pairs_mut().append_pair("api-key", &self.api_key);
-        let async_solana_client = Arc::new(AsyncSolanaRpcClient::new(rpc_url.to_string()));
-
-        // Setup websocket
-        let wss: String = EnhancedWebsocket::get_url(&self.cluster, &self.api_key)?;
-        let ws_client: Arc<EnhancedWebsocket> =
-            Arc::new(EnhancedWebsocket::new(&wss, ping_interval_secs, pong_timeout_secs).await?);
-
-        Ok(Helius {
-            config: Arc::new(self),
-            client,
-            rpc_client,
-            async_rpc_client: Some(async_solana_client),
-            ws_client: Some(ws_client),
-        })
+        HeliusBuilder::from(self)
+            .with_async_solana()
+            .websocket(WebsocketOptions {
+                ping_interval_secs,
+                pong_timeout_secs,
+            })
+            .connect()
+            .await
     }
 
-    pub fn mint_api_authority(&self) -> MintApiAuthority {
+    /// Returns the Mint API's authority for the configured cluster
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if the cluster is `Localnet` or `Custom`, which the Mint API doesn't serve
+    pub fn mint_api_authority(&self) -> Result<MintApiAuthority> {
         MintApiAuthority::from_cluster(&self.cluster)
     }
 }
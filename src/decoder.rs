@@ -0,0 +1,780 @@
+//! Typed decoding of raw account data
+//!
+//! `AccountInfo.data` holds whatever the RPC returned for the requested `Encoding`, which is usually a
+//! `["<data>", "<encoding>"]` pair. `AccountInfo::decode` turns it back into bytes and decodes them into any type that
+//! implements `AccountDecoder`. Decoders are built in for SPL Token and Token-2022 accounts and mints (including their
+//! extensions), stake accounts, and durable nonce accounts. These also decode the `jsonParsed` form of their accounts,
+//! except for Token-2022 extensions, which are only decoded from binary encodings
+//!
+//! When the owning program isn't known ahead of time, a `DecoderRegistry` picks the decoder from the account's owner.
+//! Custom decoders for other programs can be registered alongside the built-in ones
+//!
+//! # Example
+//! ```rust,no_run
+//! use helius::decoder::{DecodedAccount, DecoderRegistry, SplTokenAccount};
+//! use helius::error::Result;
+//! use helius::types::GpaAccount;
+//!
+//! fn print_balances(accounts: &[GpaAccount]) -> Result<()> {
+//!     for account in accounts {
+//!         let token_account: SplTokenAccount = account.decode()?;
+//!         println!("{}: {}", account.pubkey, token_account.account().amount);
+//!     }
+//!
+//!     let registry: DecoderRegistry = DecoderRegistry::default();
+//!     if let DecodedAccount::Mint(mint) = registry.decode(&accounts[0].account)? {
+//!         println!("supply: {}", mint.mint().supply);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::error::{HeliusError, Result};
+use crate::types::{AccountInfo, GpaAccount, TokenAccountRecord};
+
+use base64::{engine::general_purpose::STANDARD as B64, Engine};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use solana_nonce::{
+    state::{Data as NonceData, DurableNonce, State as NonceState},
+    versions::Versions,
+};
+use solana_sdk::{bs58, hash::Hash, program_option::COption, program_pack::Pack, pubkey::Pubkey};
+use solana_stake_interface::stake_flags::StakeFlags;
+use solana_stake_interface::state::{Authorized, Delegation, Lockup, Meta, Stake, StakeStateV2};
+use spl_token_2022_interface::extension::{
+    AccountType, BaseStateWithExtensions, ExtensionType, StateWithExtensionsOwned,
+};
+use spl_token_2022_interface::state::{Account, AccountState, Mint};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+pub use spl_token_2022_interface;
+
+/// The SPL Token program ID
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token_2022_interface::inline_spl_token::ID;
+
+/// The Token-2022 program ID
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = spl_token_2022_interface::ID;
+
+/// A type that can be decoded from the raw data of an account
+pub trait AccountDecoder: Sized {
+    /// Decodes an account's data
+    ///
+    /// # Arguments
+    /// * `owner` - The program that owns the account
+    /// * `data` - The account's raw data
+    ///
+    /// # Errors
+    /// Returns `HeliusError::AccountDecode` if the account isn't owned by a program this type belongs to, or if its data
+    /// doesn't match the type's layout
+    fn decode_account(owner: &Pubkey, data: &[u8]) -> Result<Self>;
+
+    /// Decodes an account's `jsonParsed` data
+    ///
+    /// The RPC only parses accounts of the programs it knows, so by default this returns an error asking for a binary
+    /// encoding instead
+    ///
+    /// # Arguments
+    /// * `owner` - The program that owns the account
+    /// * `parsed` - The account's `jsonParsed` data, e.g., `{"program": "spl-token", "parsed": {...}, "space": 165}`
+    ///
+    /// # Errors
+    /// Returns `HeliusError::AccountDecode` if the type can't be decoded from `jsonParsed` data, or if the data doesn't
+    /// match the type
+    fn decode_parsed(_owner: &Pubkey, _parsed: &Value) -> Result<Self> {
+        Err(HeliusError::AccountDecode(
+            "jsonParsed account data can't be decoded into this type, request base64 encoding instead".to_string(),
+        ))
+    }
+}
+
+/// An SPL Token or Token-2022 token account, along with any Token-2022 extensions
+///
+/// Extensions are read through `state`, e.g., `token_account.state.get_extension::<ImmutableOwner>()` with the
+/// `BaseStateWithExtensions` trait in scope
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplTokenAccount {
+    /// The token program that owns the account
+    pub program_id: Pubkey,
+    /// The base account state and its extension data
+    pub state: StateWithExtensionsOwned<Account>,
+}
+
+impl SplTokenAccount {
+    /// Returns the base token account state
+    pub fn account(&self) -> &Account {
+        &self.state.base
+    }
+
+    /// Returns the types of the extensions the account has. Always empty for SPL Token accounts
+    pub fn extension_types(&self) -> Result<Vec<ExtensionType>> {
+        self.state.get_extension_types().map_err(decode_error)
+    }
+
+    /// Returns `true` if the account is owned by the Token-2022 program
+    pub fn is_token_2022(&self) -> bool {
+        self.program_id == TOKEN_2022_PROGRAM_ID
+    }
+}
+
+impl AccountDecoder for SplTokenAccount {
+    fn decode_account(owner: &Pubkey, data: &[u8]) -> Result<Self> {
+        check_token_program(owner)?;
+        Ok(Self {
+            program_id: *owner,
+            state: StateWithExtensionsOwned::<Account>::unpack(data.to_vec()).map_err(decode_error)?,
+        })
+    }
+
+    fn decode_parsed(owner: &Pubkey, parsed: &Value) -> Result<Self> {
+        match decode_parsed_token_program(owner, parsed)? {
+            DecodedAccount::TokenAccount(token_account) => Ok(token_account),
+            _ => Err(HeliusError::AccountDecode(
+                "Expected a token account, but the account is a mint".to_string(),
+            )),
+        }
+    }
+}
+
+/// An SPL Token or Token-2022 mint, along with any Token-2022 extensions
+///
+/// Extensions are read through `state`, e.g., `mint.state.get_extension::<TransferFeeConfig>()` with the
+/// `BaseStateWithExtensions` trait in scope
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplMint {
+    /// The token program that owns the mint
+    pub program_id: Pubkey,
+    /// The base mint state and its extension data
+    pub state: StateWithExtensionsOwned<Mint>,
+}
+
+impl SplMint {
+    /// Returns the base mint state
+    pub fn mint(&self) -> &Mint {
+        &self.state.base
+    }
+
+    /// Returns the types of the extensions the mint has. Always empty for SPL Token mints
+    pub fn extension_types(&self) -> Result<Vec<ExtensionType>> {
+        self.state.get_extension_types().map_err(decode_error)
+    }
+
+    /// Returns `true` if the mint is owned by the Token-2022 program
+    pub fn is_token_2022(&self) -> bool {
+        self.program_id == TOKEN_2022_PROGRAM_ID
+    }
+
+    /// Returns the metadata stored in the mint's `TokenMetadata` extension, if it has one
+    ///
+    /// # Errors
+    /// Returns `HeliusError::AccountDecode` if the extension's data is malformed
+    pub fn token_metadata(&self) -> Result<Option<TokenMetadata>> {
+        if !self.extension_types()?.contains(&ExtensionType::TokenMetadata) {
+            return Ok(None);
+        }
+
+        self.state
+            .get_variable_len_extension::<TokenMetadata>()
+            .map(Some)
+            .map_err(decode_error)
+    }
+}
+
+impl AccountDecoder for SplMint {
+    fn decode_account(owner: &Pubkey, data: &[u8]) -> Result<Self> {
+        check_token_program(owner)?;
+        Ok(Self {
+            program_id: *owner,
+            state: StateWithExtensionsOwned::<Mint>::unpack(data.to_vec()).map_err(decode_error)?,
+        })
+    }
+
+    fn decode_parsed(owner: &Pubkey, parsed: &Value) -> Result<Self> {
+        match decode_parsed_token_program(owner, parsed)? {
+            DecodedAccount::Mint(mint) => Ok(mint),
+            _ => Err(HeliusError::AccountDecode(
+                "Expected a mint, but the account is a token account".to_string(),
+            )),
+        }
+    }
+}
+
+impl AccountDecoder for StakeStateV2 {
+    fn decode_account(owner: &Pubkey, data: &[u8]) -> Result<Self> {
+        check_owner(owner, &solana_stake_interface::program::ID, "stake")?;
+        bincode::deserialize(data).map_err(decode_error)
+    }
+
+    fn decode_parsed(owner: &Pubkey, parsed: &Value) -> Result<Self> {
+        check_owner(owner, &solana_stake_interface::program::ID, "stake")?;
+        match parsed_info::<ParsedStakeAccount>(parsed)? {
+            ParsedStakeAccount::Uninitialized => Ok(StakeStateV2::Uninitialized),
+            ParsedStakeAccount::Initialized(account) => Ok(StakeStateV2::Initialized(account.meta.try_into()?)),
+            ParsedStakeAccount::Delegated(account) => {
+                let stake: ParsedStake = account
+                    .stake
+                    .ok_or_else(|| HeliusError::AccountDecode("Delegated stake account has no stake".to_string()))?;
+                Ok(StakeStateV2::Stake(
+                    account.meta.try_into()?,
+                    stake.try_into()?,
+                    StakeFlags::empty(),
+                ))
+            }
+            ParsedStakeAccount::RewardsPool => Ok(StakeStateV2::RewardsPool),
+        }
+    }
+}
+
+impl AccountDecoder for NonceState {
+    fn decode_account(owner: &Pubkey, data: &[u8]) -> Result<Self> {
+        check_owner(owner, &solana_system_interface::program::ID, "nonce")?;
+        let versions: Versions = bincode::deserialize(data).map_err(decode_error)?;
+        Ok(versions.state().clone())
+    }
+
+    fn decode_parsed(owner: &Pubkey, parsed: &Value) -> Result<Self> {
+        check_owner(owner, &solana_system_interface::program::ID, "nonce")?;
+        match parsed_info::<ParsedNonceAccount>(parsed)? {
+            ParsedNonceAccount::Uninitialized => Ok(NonceState::Uninitialized),
+            ParsedNonceAccount::Initialized(data) => {
+                let blockhash: Hash = Hash::from_str(&data.blockhash).map_err(decode_error)?;
+                // The parsed blockhash is the durable nonce itself, not a blockhash to derive it from
+                let durable_nonce: DurableNonce = bincode::deserialize(blockhash.as_ref()).map_err(decode_error)?;
+                Ok(NonceState::Initialized(NonceData::new(
+                    parse_pubkey(&data.authority)?,
+                    durable_nonce,
+                    parse_u64(&data.fee_calculator.lamports_per_signature)?,
+                )))
+            }
+        }
+    }
+}
+
+/// An account decoded by a `DecoderRegistry`
+pub enum DecodedAccount {
+    TokenAccount(SplTokenAccount),
+    Mint(SplMint),
+    Stake(StakeStateV2),
+    Nonce(NonceState),
+    /// An account decoded by a decoder registered with `DecoderRegistry::register`
+    Custom(Box<dyn Any + Send + Sync>),
+}
+
+impl DecodedAccount {
+    /// Returns the account decoded by a custom decoder, if it was decoded as a `T`
+    pub fn as_custom<T: Any>(&self) -> Option<&T> {
+        match self {
+            DecodedAccount::Custom(account) => account.downcast_ref::<T>(),
+            _ => None,
+        }
+    }
+
+    /// Consumes the account, returning it if it was decoded as a `T` by a custom decoder
+    pub fn into_custom<T: Any>(self) -> Option<T> {
+        match self {
+            DecodedAccount::Custom(account) => account.downcast::<T>().ok().map(|account| *account),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for DecodedAccount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodedAccount::TokenAccount(account) => f.debug_tuple("TokenAccount").field(account).finish(),
+            DecodedAccount::Mint(mint) => f.debug_tuple("Mint").field(mint).finish(),
+            DecodedAccount::Stake(stake) => f.debug_tuple("Stake").field(stake).finish(),
+            DecodedAccount::Nonce(nonce) => f.debug_tuple("Nonce").field(nonce).finish(),
+            DecodedAccount::Custom(_) => f.debug_tuple("Custom").finish_non_exhaustive(),
+        }
+    }
+}
+
+type DecodeFn = Arc<dyn Fn(&Pubkey, &[u8]) -> Result<DecodedAccount> + Send + Sync>;
+
+/// Decodes accounts using the decoder registered for their owning program
+///
+/// `DecoderRegistry::default()` knows the SPL Token, Token-2022, stake, and system (nonce) programs. Token accounts and
+/// mints are told apart by their data, so both decode from the same registry
+#[derive(Clone)]
+pub struct DecoderRegistry {
+    decoders: HashMap<Pubkey, DecodeFn>,
+}
+
+impl DecoderRegistry {
+    /// Creates a registry without any decoders
+    pub fn empty() -> Self {
+        Self {
+            decoders: HashMap::new(),
+        }
+    }
+
+    /// Registers `T` as the decoder for accounts owned by `program_id`, replacing any decoder previously registered for
+    /// it (including a built-in one). Accounts decoded by it are returned as `DecodedAccount::Custom`
+    pub fn register<T: AccountDecoder + Send + Sync + 'static>(&mut self, program_id: Pubkey) -> &mut Self {
+        self.register_fn(program_id, |owner, data| {
+            T::decode_account(owner, data).map(|account| DecodedAccount::Custom(Box::new(account)))
+        })
+    }
+
+    /// Registers a function as the decoder for accounts owned by `program_id`, replacing any decoder previously
+    /// registered for it
+    pub fn register_fn<F>(&mut self, program_id: Pubkey, decode: F) -> &mut Self
+    where
+        F: Fn(&Pubkey, &[u8]) -> Result<DecodedAccount> + Send + Sync + 'static,
+    {
+        self.decoders.insert(program_id, Arc::new(decode));
+        self
+    }
+
+    /// Returns `true` if a decoder is registered for `program_id`
+    pub fn contains(&self, program_id: &Pubkey) -> bool {
+        self.decoders.contains_key(program_id)
+    }
+
+    /// Decodes an account returned by the RPC
+    ///
+    /// # Arguments
+    /// * `account` - The account to decode. `jsonParsed` data is decoded with the built-in decoders, since it's only
+    ///   returned for programs the RPC knows
+    ///
+    /// # Returns
+    /// The decoded account
+    ///
+    /// # Errors
+    /// Returns `HeliusError::AccountDecode` if no decoder is registered for the account's owner or the decoder fails
+    pub fn decode(&self, account: &AccountInfo) -> Result<DecodedAccount> {
+        let owner: Pubkey = account.owner_pubkey()?;
+        if !account.data.is_object() {
+            return self.decode_data(&owner, &account.data_bytes()?);
+        }
+
+        if !self.contains(&owner) {
+            return Err(HeliusError::AccountDecode(format!(
+                "No decoder registered for program {owner}"
+            )));
+        }
+        match owner {
+            TOKEN_PROGRAM_ID | TOKEN_2022_PROGRAM_ID => decode_parsed_token_program(&owner, &account.data),
+            solana_stake_interface::program::ID => {
+                StakeStateV2::decode_parsed(&owner, &account.data).map(DecodedAccount::Stake)
+            }
+            solana_system_interface::program::ID => {
+                NonceState::decode_parsed(&owner, &account.data).map(DecodedAccount::Nonce)
+            }
+            _ => Err(HeliusError::AccountDecode(format!(
+                "jsonParsed account data owned by {owner} can't be decoded, request base64 encoding instead"
+            ))),
+        }
+    }
+
+    /// Decodes raw account data owned by `owner`
+    ///
+    /// # Errors
+    /// Returns `HeliusError::AccountDecode` if no decoder is registered for `owner` or the decoder fails
+    pub fn decode_data(&self, owner: &Pubkey, data: &[u8]) -> Result<DecodedAccount> {
+        let decode: &DecodeFn = self
+            .decoders
+            .get(owner)
+            .ok_or_else(|| HeliusError::AccountDecode(format!("No decoder registered for program {owner}")))?;
+        decode(owner, data)
+    }
+}
+
+impl Default for DecoderRegistry {
+    fn default() -> Self {
+        let mut registry: DecoderRegistry = Self::empty();
+        registry
+            .register_fn(TOKEN_PROGRAM_ID, decode_token_program)
+            .register_fn(TOKEN_2022_PROGRAM_ID, decode_token_program)
+            .register_fn(solana_stake_interface::program::ID, |owner, data| {
+                StakeStateV2::decode_account(owner, data).map(DecodedAccount::Stake)
+            })
+            .register_fn(solana_system_interface::program::ID, |owner, data| {
+                NonceState::decode_account(owner, data).map(DecodedAccount::Nonce)
+            });
+        registry
+    }
+}
+
+impl fmt::Debug for DecoderRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecoderRegistry")
+            .field("programs", &self.decoders.keys().collect::<Vec<&Pubkey>>())
+            .finish()
+    }
+}
+
+impl AccountInfo {
+    /// Returns the account's raw data, decoding it from the encoding it was returned in
+    ///
+    /// # Errors
+    /// Returns `HeliusError::AccountDecode` if the data was returned as `jsonParsed`, or can't be decoded from its
+    /// encoding
+    pub fn data_bytes(&self) -> Result<Vec<u8>> {
+        match &self.data {
+            // Binary data without an encoding tag is base58
+            Value::String(data) => decode_base58(data),
+            Value::Array(parts) => match parts.as_slice() {
+                [Value::String(data), Value::String(encoding)] => match encoding.as_str() {
+                    "base58" => decode_base58(data),
+                    "base64" => decode_base64(data),
+                    "base64+zstd" => zstd::decode_all(decode_base64(data)?.as_slice()).map_err(decode_error),
+                    encoding => Err(HeliusError::AccountDecode(format!(
+                        "Unsupported account data encoding: {encoding}"
+                    ))),
+                },
+                _ => Err(HeliusError::AccountDecode(
+                    "Expected account data as [data, encoding]".to_string(),
+                )),
+            },
+            Value::Object(_) => Err(HeliusError::AccountDecode(
+                "jsonParsed account data can't be decoded, request base64 encoding instead".to_string(),
+            )),
+            _ => Err(HeliusError::AccountDecode("Unexpected account data".to_string())),
+        }
+    }
+
+    /// Decodes the account's data into `T`, from either its binary encoding or its `jsonParsed` form
+    ///
+    /// # Errors
+    /// Returns `HeliusError::AccountDecode` if the data can't be decoded from its encoding or into `T`
+    pub fn decode<T: AccountDecoder>(&self) -> Result<T> {
+        let owner: Pubkey = self.owner_pubkey()?;
+        match self.data.is_object() {
+            true => T::decode_parsed(&owner, &self.data),
+            false => T::decode_account(&owner, &self.data_bytes()?),
+        }
+    }
+
+    fn owner_pubkey(&self) -> Result<Pubkey> {
+        Pubkey::from_str(&self.owner)
+            .map_err(|e| HeliusError::AccountDecode(format!("Invalid owner {}: {e}", self.owner)))
+    }
+}
+
+impl GpaAccount {
+    /// Decodes the account's data into `T`
+    ///
+    /// # Errors
+    /// Returns `HeliusError::AccountDecode` if the data can't be decoded from its encoding or into `T`
+    pub fn decode<T: AccountDecoder>(&self) -> Result<T> {
+        self.account.decode()
+    }
+}
+
+impl TokenAccountRecord {
+    /// Decodes the account's data into `T`, usually `SplTokenAccount`
+    ///
+    /// # Errors
+    /// Returns `HeliusError::AccountDecode` if the data can't be decoded from its encoding or into `T`
+    pub fn decode<T: AccountDecoder>(&self) -> Result<T> {
+        self.account.decode()
+    }
+}
+
+/// Decodes an account owned by a token program as a mint or token account, depending on its layout
+fn decode_token_program(owner: &Pubkey, data: &[u8]) -> Result<DecodedAccount> {
+    // Token-2022 mints with extensions are padded to the length of a token account, followed by their account type
+    let is_mint: bool =
+        data.len() == Mint::LEN || (data.len() > Account::LEN && data[Account::LEN] == u8::from(AccountType::Mint));
+
+    if is_mint {
+        SplMint::decode_account(owner, data).map(DecodedAccount::Mint)
+    } else {
+        SplTokenAccount::decode_account(owner, data).map(DecodedAccount::TokenAccount)
+    }
+}
+
+/// Decodes the `jsonParsed` form of an account owned by a token program as a mint or token account
+fn decode_parsed_token_program(owner: &Pubkey, parsed: &Value) -> Result<DecodedAccount> {
+    check_token_program(owner)?;
+    match parsed_info::<ParsedTokenProgramAccount>(parsed)? {
+        ParsedTokenProgramAccount::Account(info) => {
+            check_no_extensions(&info.extensions)?;
+            let account: Account = Account {
+                mint: parse_pubkey(&info.mint)?,
+                owner: parse_pubkey(&info.owner)?,
+                amount: parse_u64(&info.token_amount.amount)?,
+                delegate: parse_optional_pubkey(info.delegate.as_deref())?,
+                state: match info.state {
+                    ParsedAccountState::Uninitialized => AccountState::Uninitialized,
+                    ParsedAccountState::Initialized => AccountState::Initialized,
+                    ParsedAccountState::Frozen => AccountState::Frozen,
+                },
+                is_native: match (info.is_native, info.rent_exempt_reserve) {
+                    (true, Some(reserve)) => COption::Some(parse_u64(&reserve.amount)?),
+                    (true, None) => COption::Some(0),
+                    (false, _) => COption::None,
+                },
+                delegated_amount: match info.delegated_amount {
+                    Some(delegated_amount) => parse_u64(&delegated_amount.amount)?,
+                    None => 0,
+                },
+                close_authority: parse_optional_pubkey(info.close_authority.as_deref())?,
+            };
+            Ok(DecodedAccount::TokenAccount(SplTokenAccount {
+                program_id: *owner,
+                state: StateWithExtensionsOwned::<Account>::unpack(pack(account)?).map_err(decode_error)?,
+            }))
+        }
+        ParsedTokenProgramAccount::Mint(info) => {
+            check_no_extensions(&info.extensions)?;
+            let mint: Mint = Mint {
+                mint_authority: parse_optional_pubkey(info.mint_authority.as_deref())?,
+                supply: parse_u64(&info.supply)?,
+                decimals: info.decimals,
+                is_initialized: info.is_initialized,
+                freeze_authority: parse_optional_pubkey(info.freeze_authority.as_deref())?,
+            };
+            Ok(DecodedAccount::Mint(SplMint {
+                program_id: *owner,
+                state: StateWithExtensionsOwned::<Mint>::unpack(pack(mint)?).map_err(decode_error)?,
+            }))
+        }
+    }
+}
+
+fn check_token_program(owner: &Pubkey) -> Result<()> {
+    if *owner == TOKEN_PROGRAM_ID || *owner == TOKEN_2022_PROGRAM_ID {
+        Ok(())
+    } else {
+        Err(HeliusError::AccountDecode(format!(
+            "Expected a token account, but the account is owned by {owner}"
+        )))
+    }
+}
+
+fn check_owner(owner: &Pubkey, expected: &Pubkey, kind: &str) -> Result<()> {
+    if owner == expected {
+        Ok(())
+    } else {
+        Err(HeliusError::AccountDecode(format!(
+            "Expected a {kind} account owned by {expected}, but the account is owned by {owner}"
+        )))
+    }
+}
+
+fn check_no_extensions(extensions: &[Value]) -> Result<()> {
+    if extensions.is_empty() {
+        Ok(())
+    } else {
+        Err(HeliusError::AccountDecode(
+            "jsonParsed Token-2022 extensions can't be decoded, request base64 encoding instead".to_string(),
+        ))
+    }
+}
+
+/// Deserializes the `parsed` object of `jsonParsed` account data
+fn parsed_info<T: DeserializeOwned>(parsed: &Value) -> Result<T> {
+    let parsed: &Value = parsed
+        .get("parsed")
+        .ok_or_else(|| HeliusError::AccountDecode("Expected jsonParsed account data".to_string()))?;
+    T::deserialize(parsed).map_err(decode_error)
+}
+
+fn pack<T: Pack>(state: T) -> Result<Vec<u8>> {
+    let mut data: Vec<u8> = vec![0; T::LEN];
+    T::pack(state, &mut data).map_err(decode_error)?;
+    Ok(data)
+}
+
+fn parse_pubkey(pubkey: &str) -> Result<Pubkey> {
+    Pubkey::from_str(pubkey).map_err(|e| HeliusError::AccountDecode(format!("Invalid pubkey {pubkey}: {e}")))
+}
+
+fn parse_optional_pubkey(pubkey: Option<&str>) -> Result<COption<Pubkey>> {
+    match pubkey {
+        Some(pubkey) => parse_pubkey(pubkey).map(COption::Some),
+        None => Ok(COption::None),
+    }
+}
+
+fn parse_u64(amount: &str) -> Result<u64> {
+    amount
+        .parse()
+        .map_err(|e| HeliusError::AccountDecode(format!("Invalid amount {amount}: {e}")))
+}
+
+fn decode_base58(data: &str) -> Result<Vec<u8>> {
+    bs58::decode(data).into_vec().map_err(decode_error)
+}
+
+fn decode_base64(data: &str) -> Result<Vec<u8>> {
+    B64.decode(data).map_err(decode_error)
+}
+
+fn decode_error(err: impl fmt::Display) -> HeliusError {
+    HeliusError::AccountDecode(err.to_string())
+}
+
+/// The `parsed` object of a token program account's `jsonParsed` data. Multisigs aren't decoded
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type", content = "info")]
+enum ParsedTokenProgramAccount {
+    Account(ParsedTokenAccount),
+    Mint(ParsedMint),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParsedTokenAccount {
+    mint: String,
+    owner: String,
+    token_amount: ParsedTokenAmount,
+    #[serde(default)]
+    delegate: Option<String>,
+    state: ParsedAccountState,
+    is_native: bool,
+    #[serde(default)]
+    rent_exempt_reserve: Option<ParsedTokenAmount>,
+    #[serde(default)]
+    delegated_amount: Option<ParsedTokenAmount>,
+    #[serde(default)]
+    close_authority: Option<String>,
+    #[serde(default)]
+    extensions: Vec<Value>,
+}
+
+#[derive(Deserialize)]
+struct ParsedTokenAmount {
+    amount: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum ParsedAccountState {
+    Uninitialized,
+    Initialized,
+    Frozen,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParsedMint {
+    mint_authority: Option<String>,
+    supply: String,
+    decimals: u8,
+    is_initialized: bool,
+    freeze_authority: Option<String>,
+    #[serde(default)]
+    extensions: Vec<Value>,
+}
+
+/// The `parsed` object of a stake account's `jsonParsed` data
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type", content = "info")]
+enum ParsedStakeAccount {
+    Uninitialized,
+    Initialized(ParsedStakeInfo),
+    Delegated(ParsedStakeInfo),
+    RewardsPool,
+}
+
+#[derive(Deserialize)]
+struct ParsedStakeInfo {
+    meta: ParsedStakeMeta,
+    stake: Option<ParsedStake>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParsedStakeMeta {
+    rent_exempt_reserve: String,
+    authorized: ParsedAuthorized,
+    lockup: ParsedLockup,
+}
+
+impl TryFrom<ParsedStakeMeta> for Meta {
+    type Error = HeliusError;
+
+    fn try_from(meta: ParsedStakeMeta) -> Result<Self> {
+        Ok(Meta {
+            rent_exempt_reserve: parse_u64(&meta.rent_exempt_reserve)?,
+            authorized: Authorized {
+                staker: parse_pubkey(&meta.authorized.staker)?,
+                withdrawer: parse_pubkey(&meta.authorized.withdrawer)?,
+            },
+            lockup: Lockup {
+                unix_timestamp: meta.lockup.unix_timestamp,
+                epoch: meta.lockup.epoch,
+                custodian: parse_pubkey(&meta.lockup.custodian)?,
+            },
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct ParsedAuthorized {
+    staker: String,
+    withdrawer: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParsedLockup {
+    unix_timestamp: i64,
+    epoch: u64,
+    custodian: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParsedStake {
+    delegation: ParsedDelegation,
+    credits_observed: u64,
+}
+
+impl TryFrom<ParsedStake> for Stake {
+    type Error = HeliusError;
+
+    fn try_from(stake: ParsedStake) -> Result<Self> {
+        Ok(Stake {
+            delegation: Delegation {
+                voter_pubkey: parse_pubkey(&stake.delegation.voter)?,
+                stake: parse_u64(&stake.delegation.stake)?,
+                activation_epoch: parse_u64(&stake.delegation.activation_epoch)?,
+                deactivation_epoch: parse_u64(&stake.delegation.deactivation_epoch)?,
+                ..Delegation::default()
+            },
+            credits_observed: stake.credits_observed,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParsedDelegation {
+    voter: String,
+    stake: String,
+    activation_epoch: String,
+    deactivation_epoch: String,
+}
+
+/// The `parsed` object of a nonce account's `jsonParsed` data
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type", content = "info")]
+enum ParsedNonceAccount {
+    Uninitialized,
+    Initialized(ParsedNonceData),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParsedNonceData {
+    authority: String,
+    blockhash: String,
+    fee_calculator: ParsedFeeCalculator,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParsedFeeCalculator {
+    lamports_per_signature: String,
+}
@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::request_handler::{EndpointFamily, RequestContext};
 use crate::types::{EnhancedTransaction, ParseTransactionsRequest, ParsedTransactionHistoryRequest};
 use crate::Helius;
 
@@ -14,15 +15,18 @@ impl Helius {
     /// # Returns
     /// A `Result` wrapping a vector of `EnhancedTransaction`s
     pub async fn parse_transactions(&self, request: ParseTransactionsRequest) -> Result<Vec<EnhancedTransaction>> {
-        let url: String = format!(
-            "{}v0/transactions?api-key={}",
-            self.config.endpoints.api, self.config.api_key
-        );
-        let parsed_url: Url = Url::parse(&url).expect("Failed to parse URL");
+        let parsed_url: Url = self
+            .config
+            .authenticated_url(&format!("{}v0/transactions", self.config.endpoints.api))?;
 
         self.rpc_client
             .handler
-            .send(Method::POST, parsed_url, Some(&request))
+            .send_with_context(
+                &RequestContext::new("parseTransactions", EndpointFamily::EnhancedApi).idempotent(true),
+                Method::POST,
+                parsed_url,
+                Some(&request),
+            )
             .await
     }
 
@@ -39,37 +43,47 @@ impl Helius {
         &self,
         request: ParsedTransactionHistoryRequest,
     ) -> Result<Vec<EnhancedTransaction>> {
-        let mut url: String = format!(
-            "{}v0/addresses/{}/transactions?api-key={}",
-            self.config.endpoints.api, request.address, self.config.api_key
-        );
+        let mut parsed_url: Url = self.config.authenticated_url(&format!(
+            "{}v0/addresses/{}/transactions",
+            self.config.endpoints.api, request.address
+        ))?;
 
-        if let Some(before) = request.before {
-            url = format!("{}&before={}", url, before);
-        }
+        {
+            let mut query = parsed_url.query_pairs_mut();
 
-        if let Some(until) = request.until {
-            url = format!("{}&until={}", url, until);
-        }
+            if let Some(before) = request.before {
+                query.append_pair("before", &before);
+            }
 
-        if let Some(commitment) = request.commitment {
-            url = format!("{}&commitment={}", url, commitment);
-        }
+            if let Some(until) = request.until {
+                query.append_pair("until", &until);
+            }
 
-        if let Some(source) = request.source {
-            url = format!("{}&source={}", url, source);
-        }
+            if let Some(commitment) = request.commitment {
+                query.append_pair("commitment", &commitment.to_string());
+            }
 
-        if let Some(transaction_type) = request.transaction_type {
-            url = format!("{}&type={}", url, transaction_type);
-        }
+            if let Some(source) = request.source {
+                query.append_pair("source", &source.to_string());
+            }
 
-        if let Some(limit) = request.limit {
-            url = format!("{}&limit={}", url, limit);
-        }
+            if let Some(transaction_type) = request.transaction_type {
+                query.append_pair("type", &transaction_type.to_string());
+            }
 
-        let parsed_url: Url = Url::parse(&url).expect("Failed to parse URL");
+            if let Some(limit) = request.limit {
+                query.append_pair("limit", &limit.to_string());
+            }
+        }
 
-        self.rpc_client.handler.send(Method::GET, parsed_url, None::<&()>).await
+        self.rpc_client
+            .handler
+            .send_with_context(
+                &RequestContext::new("parsedTransactionHistory", EndpointFamily::EnhancedApi).idempotent(true),
+                Method::GET,
+                parsed_url,
+                None::<&()>,
+            )
+            .await
     }
 }
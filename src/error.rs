@@ -1,6 +1,8 @@
+use crate::secret::redact_url;
+use crate::types::RpcError;
 use reqwest::{Error as ReqwestError, StatusCode};
-use serde_json::Error as SerdeJsonError;
-use solana_client::client_error::ClientError;
+use serde_json::{Error as SerdeJsonError, Value};
+use solana_client::client_error::{ClientError, ClientErrorKind};
 use solana_sdk::{
     message::CompileError, sanitize::SanitizeError, signature::SignerError, transaction::TransactionError,
 };
@@ -22,7 +24,7 @@ pub enum HeliusError {
     ///
     /// This captures errors from the Solana client library
     #[error("Solana client error: {0}")]
-    ClientError(#[from] ClientError),
+    ClientError(ClientError),
 
     /// Indicates if a client is not already initialized
     ///
@@ -69,6 +71,17 @@ pub enum HeliusError {
     #[error("Too many requests made to {path}")]
     RateLimitExceeded { path: String },
 
+    /// Represents a JSON-RPC error returned by the server
+    ///
+    /// The server accepted the request but could not fulfill it, such as when the parameters are invalid or a transaction
+    /// fails simulation. `data` holds any additional details the server provided (e.g., simulation logs)
+    #[error("RPC error {code}: {message}")]
+    Rpc {
+        code: i64,
+        message: String,
+        data: Option<Value>,
+    },
+
     /// Indicates an error from the underlying HTTP client (i.e., reqwest)
     ///
     /// This captures errors from the `reqwest` library specifically
@@ -112,6 +125,7 @@ pub enum HeliusError {
     #[error("Unknown error has occurred: HTTP {code} - {text}")]
     Unknown { code: StatusCode, text: String },
 
+    #[cfg(feature = "enhanced-ws")]
     #[error("Unable to connect to server: {0}")]
     Tungstenite(#[from] tokio_tungstenite::tungstenite::Error),
 
@@ -126,6 +140,22 @@ pub enum HeliusError {
 
     #[error("TLS error: {0}")]
     TlsError(String),
+
+    /// Indicates a cassette could not be read or written, or has no recorded interaction for a replayed request
+    #[cfg(feature = "cassette")]
+    #[error("Cassette error: {0}")]
+    Cassette(String),
+
+    /// Indicates an asset proof doesn't belong to its asset or doesn't hash to its root, which usually means the indexer
+    /// served stale data
+    #[cfg(feature = "das")]
+    #[error("Invalid asset proof: {0}")]
+    InvalidProof(String),
+
+    /// Indicates account data could not be decoded, either from its encoding or into the requested type
+    #[cfg(feature = "account-decoder")]
+    #[error("Account decode error: {0}")]
+    AccountDecode(String),
 }
 
 impl HeliusError {
@@ -142,6 +172,101 @@ impl HeliusError {
             _ => HeliusError::Unknown { code: status, text },
         }
     }
+
+    /// Returns `true` if the error is transient and the request that caused it may succeed if retried
+    ///
+    /// Rate limits, server errors, gateway errors, timeouts, and connection failures are considered retryable
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            HeliusError::RateLimitExceeded { .. } | HeliusError::InternalError { .. } => true,
+            HeliusError::Unknown { code, .. } => matches!(
+                *code,
+                StatusCode::REQUEST_TIMEOUT
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            HeliusError::Network(err) | HeliusError::ReqwestError(err) => err.is_timeout() || err.is_connect(),
+            _ => false,
+        }
+    }
+
+    /// Returns the name of the error's variant (e.g., `"RateLimitExceeded"`), which is useful for labelling metrics
+    pub fn kind(&self) -> &'static str {
+        match self {
+            HeliusError::BadRequest { .. } => "BadRequest",
+            HeliusError::ClientError(_) => "ClientError",
+            HeliusError::ClientNotInitialized { .. } => "ClientNotInitialized",
+            HeliusError::CompileError(_) => "CompileError",
+            HeliusError::InternalError { .. } => "InternalError",
+            HeliusError::InvalidInput(_) => "InvalidInput",
+            HeliusError::Network(_) => "Network",
+            HeliusError::NotFound { .. } => "NotFound",
+            HeliusError::RateLimitExceeded { .. } => "RateLimitExceeded",
+            HeliusError::Rpc { .. } => "Rpc",
+            HeliusError::ReqwestError(_) => "ReqwestError",
+            HeliusError::SerdeJson(_) => "SerdeJson",
+            HeliusError::SignerError(_) => "SignerError",
+            HeliusError::Timeout { .. } => "Timeout",
+            HeliusError::TransactionError(_) => "TransactionError",
+            HeliusError::Unauthorized { .. } => "Unauthorized",
+            HeliusError::Unknown { .. } => "Unknown",
+            #[cfg(feature = "enhanced-ws")]
+            HeliusError::Tungstenite(_) => "Tungstenite",
+            HeliusError::WebsocketClosed(_) => "WebsocketClosed",
+            HeliusError::EnhancedWebsocket { .. } => "EnhancedWebsocket",
+            HeliusError::UrlParseError(_) => "UrlParseError",
+            HeliusError::TlsError(_) => "TlsError",
+            #[cfg(feature = "cassette")]
+            HeliusError::Cassette(_) => "Cassette",
+            #[cfg(feature = "das")]
+            HeliusError::InvalidProof(_) => "InvalidProof",
+            #[cfg(feature = "account-decoder")]
+            HeliusError::AccountDecode(_) => "AccountDecode",
+        }
+    }
+
+    /// Returns the JSON-RPC error code if this is a `HeliusError::Rpc`
+    pub fn rpc_code(&self) -> Option<i64> {
+        match self {
+            HeliusError::Rpc { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// Wraps a `reqwest::Error` raised while sending a request as `HeliusError::Network`, redacting the API key from its URL
+    pub fn network(mut err: ReqwestError) -> Self {
+        redact_reqwest_error(&mut err);
+        HeliusError::Network(err)
+    }
+}
+
+/// Redacts the API key from the URL attached to a `reqwest::Error`, if any
+fn redact_reqwest_error(err: &mut ReqwestError) {
+    if let Some(url) = err.url_mut() {
+        redact_url(url);
+    }
+}
+
+impl From<ClientError> for HeliusError {
+    /// Converts a `ClientError` into a `HeliusError`, redacting the API key from any underlying request URL
+    fn from(mut err: ClientError) -> Self {
+        if let ClientErrorKind::Reqwest(reqwest_err) = err.kind.as_mut() {
+            redact_reqwest_error(reqwest_err);
+        }
+        HeliusError::ClientError(err)
+    }
+}
+
+impl From<RpcError> for HeliusError {
+    /// Converts a JSON-RPC error object into `HeliusError::Rpc`
+    fn from(err: RpcError) -> Self {
+        HeliusError::Rpc {
+            code: err.code,
+            message: err.message,
+            data: err.data,
+        }
+    }
 }
 
 impl From<SerdeJsonError> for HeliusError {
@@ -160,7 +285,8 @@ impl From<SanitizeError> for HeliusError {
 }
 
 impl From<ReqwestError> for HeliusError {
-    fn from(err: reqwest::Error) -> Self {
+    fn from(mut err: reqwest::Error) -> Self {
+        redact_reqwest_error(&mut err);
         if err.is_builder() {
             HeliusError::TlsError(err.to_string())
         } else {
@@ -1,9 +1,7 @@
-use std::sync::Arc;
-
+use crate::builder::HeliusBuilder;
 use crate::client::Helius;
-use crate::config::Config;
 use crate::error::Result;
-use crate::rpc_client::RpcClient;
+use crate::secret::Secret;
 use crate::types::Cluster;
 
 use reqwest::Client;
@@ -13,7 +11,7 @@ use reqwest::Client;
 /// This factory allows for a centralized configuration and creation of `Helius` clients so work can be done across multiple clusters at the same time.
 /// Using a factory simplifies client code and enhances maintainability by ensuring that all `Helius` clients are configured consistently.
 pub struct HeliusFactory {
-    api_key: String,
+    api_key: Secret,
     client: Option<Client>,
 }
 
@@ -30,7 +28,7 @@ impl HeliusFactory {
     /// ```
     pub fn new(api_key: &str) -> Self {
         HeliusFactory {
-            api_key: api_key.to_string(),
+            api_key: Secret::from(api_key),
             client: None,
         }
     }
@@ -81,16 +79,11 @@ impl HeliusFactory {
     /// }
     /// ```
     pub fn create(&self, cluster: Cluster) -> Result<Helius> {
-        let config: Arc<Config> = Arc::new(Config::new(&self.api_key, cluster)?);
-        let client: Client = self.client.clone().unwrap_or_default();
-        let rpc_client: Arc<RpcClient> = Arc::new(RpcClient::new(Arc::new(client.clone()), config.clone())?);
+        let mut builder: HeliusBuilder = Helius::builder().api_key(self.api_key.clone()).cluster(cluster);
+        if let Some(client) = &self.client {
+            builder = builder.http_client(client.clone());
+        }
 
-        Ok(Helius {
-            config,
-            client,
-            rpc_client,
-            async_rpc_client: None,
-            ws_client: None,
-        })
+        builder.build()
     }
 }
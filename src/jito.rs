@@ -268,7 +268,8 @@ impl Helius {
         let interval: Duration = Duration::from_secs(5);
         let start: tokio::time::Instant = tokio::time::Instant::now();
 
-        while start.elapsed() < timeout || self.connection().get_block_height()? <= last_valid_block_height {
+        while start.elapsed() < timeout || self.async_connection()?.get_block_height().await? <= last_valid_block_height
+        {
             #[allow(deprecated)]
             let bundle_statuses: Value = self.get_bundle_statuses(vec![bundle_id.clone()], jito_api_url).await?;
 
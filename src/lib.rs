@@ -1,18 +1,53 @@
+#[cfg(feature = "rpc-v2")]
+pub mod account_sync;
+pub mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "das")]
+pub mod bubblegum;
+pub mod builder;
+#[cfg(feature = "cassette")]
+pub mod cassette;
 pub mod client;
 pub mod config;
+#[cfg(feature = "account-decoder")]
+pub mod decoder;
+#[cfg(feature = "enhanced-transactions")]
 pub mod enhanced_transactions;
 pub mod error;
 pub mod factory;
+#[cfg(feature = "jito")]
 pub mod jito;
+#[cfg(feature = "das")]
+pub mod merkle;
+pub mod metrics;
+pub mod middleware;
+pub mod mint_api;
+#[cfg(feature = "smart-transactions")]
 pub mod optimized_transaction;
+pub mod pagination;
+pub mod rate_limit;
 pub mod request_handler;
+pub mod retry;
 pub mod rpc_client;
+#[cfg(feature = "das")]
+pub mod search;
+pub mod secret;
+#[cfg(feature = "sender")]
+pub mod sender;
+pub mod services;
+#[cfg(feature = "staking")]
 pub mod staking;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod types;
 pub mod utils;
+#[cfg(feature = "webhooks")]
 pub mod webhook;
+#[cfg(feature = "enhanced-ws")]
 pub mod websocket;
 
+pub use builder::HeliusBuilder;
 pub use client::Helius;
 pub use factory::HeliusFactory;
 pub use request_handler::{SDK_USER_AGENT, SDK_VERSION};
@@ -0,0 +1,211 @@
+//! Local verification of compressed NFT Merkle proofs
+//!
+//! `get_asset_proof` returns the proof the indexer holds for a compressed asset, but nothing guarantees the indexer is
+//! up to date. `verify_asset_proof` recomputes the asset's leaf from its ownership and compression hashes, then hashes
+//! it up the concurrent Merkle tree with the proof's nodes and checks that the result is the proof's root. A proof that
+//! fails verification would be rejected on-chain, so checking it first avoids building a transfer that cannot land
+//!
+//! Only Bubblegum V1 leaves are supported
+//!
+//! # Example
+//! ```rust,no_run
+//! use helius::error::Result;
+//! use helius::merkle::verify_asset_proof;
+//! use helius::types::{Asset, AssetProof, GetAsset, GetAssetProof};
+//! use helius::Helius;
+//!
+//! async fn checked_proof(helius: &Helius, id: String) -> Result<Option<AssetProof>> {
+//!     let asset: Option<Asset> = helius
+//!         .rpc()
+//!         .get_asset(GetAsset {
+//!             id: id.clone(),
+//!             display_options: None,
+//!         })
+//!         .await?;
+//!     let proof: Option<AssetProof> = helius.rpc().get_asset_proof(GetAssetProof { id }).await?;
+//!
+//!     match (asset, proof) {
+//!         (Some(asset), Some(proof)) => {
+//!             verify_asset_proof(&asset, &proof)?;
+//!             Ok(Some(proof))
+//!         }
+//!         _ => Ok(None),
+//!     }
+//! }
+//! ```
+use crate::error::{HeliusError, Result};
+use crate::types::{Asset, AssetProof, Compression};
+
+use solana_keccak_hasher::hashv;
+use solana_sdk::{bs58, pubkey::Pubkey};
+use std::str::FromStr;
+
+/// The version byte Bubblegum prefixes V1 leaves with
+const LEAF_SCHEMA_V1: u8 = 1;
+
+/// Computes the hash of a Bubblegum V1 leaf
+///
+/// # Arguments
+/// * `asset_id` - The asset's ID
+/// * `owner` - The asset's owner
+/// * `delegate` - The asset's delegate, which is the owner if the asset isn't delegated
+/// * `nonce` - The asset's leaf ID
+/// * `data_hash` - The hash of the asset's metadata
+/// * `creator_hash` - The hash of the asset's creators
+///
+/// # Returns
+/// The 32-byte keccak hash of the leaf
+pub fn compute_leaf_hash(
+    asset_id: &Pubkey,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    nonce: u64,
+    data_hash: &[u8; 32],
+    creator_hash: &[u8; 32],
+) -> [u8; 32] {
+    hashv(&[
+        &[LEAF_SCHEMA_V1],
+        asset_id.as_ref(),
+        owner.as_ref(),
+        delegate.as_ref(),
+        &nonce.to_le_bytes(),
+        data_hash,
+        creator_hash,
+    ])
+    .to_bytes()
+}
+
+/// Computes the root of a concurrent Merkle tree from a leaf and its proof
+///
+/// # Arguments
+/// * `leaf` - The hash of the leaf
+/// * `proof` - The sibling nodes on the path from the leaf to the root, starting with the leaf's sibling
+/// * `leaf_index` - The position of the leaf in the tree
+///
+/// # Returns
+/// The 32-byte root the leaf and proof hash to
+pub fn compute_root(leaf: [u8; 32], proof: &[[u8; 32]], leaf_index: u64) -> [u8; 32] {
+    proof.iter().enumerate().fold(leaf, |node, (level, sibling)| {
+        if (leaf_index >> level) & 1 == 0 {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    })
+}
+
+/// Checks that a proof's leaf hashes to its root
+///
+/// # Arguments
+/// * `proof` - The proof returned by `get_asset_proof`
+///
+/// # Errors
+/// Returns `HeliusError::InvalidProof` if the proof doesn't hash to its root, or `HeliusError::InvalidInput` if one of its
+/// hashes isn't valid Base58 or its node index doesn't match its depth
+pub fn verify_merkle_proof(proof: &AssetProof) -> Result<()> {
+    let nodes: Vec<[u8; 32]> = proof
+        .proof
+        .iter()
+        .map(|node| decode_hash("proof node", node))
+        .collect::<Result<_>>()?;
+    let leaf: [u8; 32] = decode_hash("leaf", &proof.leaf)?;
+    let root: [u8; 32] = decode_hash("root", &proof.root)?;
+
+    let computed: [u8; 32] = compute_root(leaf, &nodes, leaf_index(proof)?);
+    if computed != root {
+        return Err(HeliusError::InvalidProof(format!(
+            "The proof hashes to {} instead of the root {}",
+            bs58::encode(computed).into_string(),
+            proof.root
+        )));
+    }
+
+    Ok(())
+}
+
+/// Checks that a proof belongs to an asset and hashes to its root
+///
+/// The asset's leaf is recomputed from its ID, owner, delegate, leaf ID, and data and creator hashes, and must match the
+/// proof's leaf. A mismatch usually means the asset was transferred, delegated, or updated after the indexer served one
+/// of the two responses
+///
+/// # Arguments
+/// * `asset` - The compressed asset returned by `get_asset`
+/// * `proof` - The proof returned by `get_asset_proof` for the same asset
+///
+/// # Errors
+/// Returns `HeliusError::InvalidProof` if the proof belongs to another tree or leaf, or doesn't hash to its root, and
+/// `HeliusError::InvalidInput` if the asset isn't compressed or one of its hashes or addresses is malformed
+pub fn verify_asset_proof(asset: &Asset, proof: &AssetProof) -> Result<()> {
+    let compression: &Compression = asset
+        .compression
+        .as_ref()
+        .filter(|compression| compression.compressed)
+        .ok_or_else(|| HeliusError::InvalidInput(format!("Asset {} is not compressed", asset.id)))?;
+
+    if compression.tree != proof.tree_id {
+        return Err(HeliusError::InvalidProof(format!(
+            "The asset is in tree {}, but the proof is for tree {}",
+            compression.tree, proof.tree_id
+        )));
+    }
+
+    let owner: Pubkey = decode_pubkey("owner", &asset.ownership.owner)?;
+    let delegate: Pubkey = match &asset.ownership.delegate {
+        Some(delegate) => decode_pubkey("delegate", delegate)?,
+        None => owner,
+    };
+    let nonce: u64 = u64::try_from(compression.leaf_id)
+        .map_err(|_| HeliusError::InvalidInput(format!("Invalid leaf ID {}", compression.leaf_id)))?;
+
+    let leaf: [u8; 32] = compute_leaf_hash(
+        &decode_pubkey("asset ID", &asset.id)?,
+        &owner,
+        &delegate,
+        nonce,
+        &decode_hash("data hash", &compression.data_hash)?,
+        &decode_hash("creator hash", &compression.creator_hash)?,
+    );
+    if leaf != decode_hash("leaf", &proof.leaf)? {
+        return Err(HeliusError::InvalidProof(format!(
+            "The asset's leaf hashes to {}, but the proof is for leaf {}",
+            bs58::encode(leaf).into_string(),
+            proof.leaf
+        )));
+    }
+
+    verify_merkle_proof(proof)
+}
+
+/// Returns the position of the proof's leaf in its tree. Node indices count every node of the tree from the root, so
+/// the leaves of a tree of depth `d` are numbered from `2^d`
+pub(crate) fn leaf_index(proof: &AssetProof) -> Result<u64> {
+    let depth: u32 = u32::try_from(proof.proof.len())
+        .ok()
+        .filter(|depth| *depth < u64::BITS)
+        .ok_or_else(|| HeliusError::InvalidInput(format!("Invalid proof depth {}", proof.proof.len())))?;
+    let first_leaf: u64 = 1 << depth;
+
+    u64::try_from(proof.node_index)
+        .ok()
+        .filter(|node_index| (first_leaf..first_leaf << 1).contains(node_index))
+        .map(|node_index| node_index - first_leaf)
+        .ok_or_else(|| {
+            HeliusError::InvalidInput(format!(
+                "Node index {} is not a leaf of a tree of depth {depth}",
+                proof.node_index
+            ))
+        })
+}
+
+pub(crate) fn decode_hash(name: &str, hash: &str) -> Result<[u8; 32]> {
+    bs58::decode(hash)
+        .into_vec()
+        .ok()
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .ok_or_else(|| HeliusError::InvalidInput(format!("Invalid {name} {hash}")))
+}
+
+pub(crate) fn decode_pubkey(name: &str, pubkey: &str) -> Result<Pubkey> {
+    Pubkey::from_str(pubkey).map_err(|_| HeliusError::InvalidInput(format!("Invalid {name} {pubkey}")))
+}
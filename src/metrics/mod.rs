@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use crate::error::HeliusError;
+
+#[cfg(feature = "prometheus")]
+pub use self::prometheus::PrometheusMetrics;
+
+#[cfg(feature = "prometheus")]
+mod prometheus;
+
+/// Receives latency, error, and retry measurements from every part of a `Helius` client
+///
+/// A sink attached with `HeliusBuilder::metrics` is shared by the client's `RequestHandler`, its `EnhancedWebsocket`,
+/// and the smart transaction methods. Operations are named after the RPC method (e.g., `getAsset`) or API call (e.g.,
+/// `createWebhook`) they perform. Implementations must be cheap and non-blocking, since they are called on every request
+///
+/// Enable the `prometheus` feature for `PrometheusMetrics`, an implementation that renders the Prometheus text format
+///
+/// # Example
+/// ```rust
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use std::time::Duration;
+/// use helius::error::HeliusError;
+/// use helius::metrics::MetricsSink;
+///
+/// #[derive(Default)]
+/// struct ErrorCounter(AtomicU64);
+///
+/// impl MetricsSink for ErrorCounter {
+///     fn record_operation(&self, _operation: &str, _elapsed: Duration, error: Option<&HeliusError>) {
+///         if error.is_some() {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+/// ```
+pub trait MetricsSink: Send + Sync {
+    /// Records a completed operation, including the time spent on any retries, along with its error if it failed
+    fn record_operation(&self, operation: &str, elapsed: Duration, error: Option<&HeliusError>);
+
+    /// Records that an operation failed and is about to be retried
+    fn record_retry(&self, _operation: &str) {}
+
+    /// Records a notification received on a websocket subscription (e.g., `transaction` or `account`)
+    fn record_notification(&self, _subscription: &str) {}
+}
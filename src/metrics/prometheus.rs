@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::MetricsSink;
+use crate::error::HeliusError;
+
+/// The upper bounds, in seconds, of the default latency histogram buckets
+pub const DEFAULT_LATENCY_BUCKETS: [f64; 12] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+/// A `MetricsSink` that aggregates measurements in memory and renders them in the Prometheus text exposition format
+///
+/// Latency is recorded as a histogram per operation, so percentiles such as p50 and p99 can be computed with
+/// `histogram_quantile`. Serve the output of `render` from a `/metrics` endpoint to have it scraped. The following
+/// metrics are exported:
+/// - `helius_operation_duration_seconds{operation}` - A histogram of operation latency
+/// - `helius_operation_errors_total{operation, error}` - Failed operations, by `HeliusError` variant
+/// - `helius_operation_retries_total{operation}` - Retries
+/// - `helius_websocket_notifications_total{subscription}` - Websocket notifications received
+///
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use helius::metrics::{MetricsSink, PrometheusMetrics};
+///
+/// let metrics: Arc<PrometheusMetrics> = Arc::new(PrometheusMetrics::new());
+/// metrics.record_operation("getAsset", Duration::from_millis(42), None);
+/// assert!(metrics.render().contains(r#"helius_operation_duration_seconds_count{operation="getAsset"} 1"#));
+/// ```
+#[derive(Debug)]
+pub struct PrometheusMetrics {
+    buckets: Vec<f64>,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    latency: BTreeMap<String, Histogram>,
+    errors: BTreeMap<(String, &'static str), u64>,
+    retries: BTreeMap<String, u64>,
+    notifications: BTreeMap<String, u64>,
+}
+
+#[derive(Debug)]
+struct Histogram {
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl PrometheusMetrics {
+    /// Creates a new `PrometheusMetrics` with the default latency buckets
+    pub fn new() -> Self {
+        Self::with_buckets(DEFAULT_LATENCY_BUCKETS.to_vec())
+    }
+
+    /// Creates a new `PrometheusMetrics` with the given latency bucket upper bounds, in seconds
+    pub fn with_buckets(mut buckets: Vec<f64>) -> Self {
+        buckets.retain(|bound| bound.is_finite());
+        buckets.sort_by(f64::total_cmp);
+        buckets.dedup();
+
+        Self {
+            buckets,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Renders every metric recorded so far in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut output: String = String::new();
+
+        output.push_str("# HELP helius_operation_duration_seconds Latency of Helius operations, including retries\n");
+        output.push_str("# TYPE helius_operation_duration_seconds histogram\n");
+        for (operation, histogram) in &state.latency {
+            let operation: String = escape(operation);
+            for (bound, count) in self.buckets.iter().zip(&histogram.counts) {
+                let _ = writeln!(
+                    output,
+                    "helius_operation_duration_seconds_bucket{{operation=\"{operation}\",le=\"{bound}\"}} {count}"
+                );
+            }
+            let _ = writeln!(
+                output,
+                "helius_operation_duration_seconds_bucket{{operation=\"{operation}\",le=\"+Inf\"}} {}",
+                histogram.count
+            );
+            let _ = writeln!(
+                output,
+                "helius_operation_duration_seconds_sum{{operation=\"{operation}\"}} {}",
+                histogram.sum
+            );
+            let _ = writeln!(
+                output,
+                "helius_operation_duration_seconds_count{{operation=\"{operation}\"}} {}",
+                histogram.count
+            );
+        }
+
+        output.push_str("# HELP helius_operation_errors_total Failed Helius operations by error\n");
+        output.push_str("# TYPE helius_operation_errors_total counter\n");
+        for ((operation, error), count) in &state.errors {
+            let _ = writeln!(
+                output,
+                "helius_operation_errors_total{{operation=\"{}\",error=\"{error}\"}} {count}",
+                escape(operation)
+            );
+        }
+
+        output.push_str("# HELP helius_operation_retries_total Retried Helius operations\n");
+        output.push_str("# TYPE helius_operation_retries_total counter\n");
+        for (operation, count) in &state.retries {
+            let _ = writeln!(
+                output,
+                "helius_operation_retries_total{{operation=\"{}\"}} {count}",
+                escape(operation)
+            );
+        }
+
+        output.push_str(
+            "# HELP helius_websocket_notifications_total Notifications received on websocket subscriptions\n",
+        );
+        output.push_str("# TYPE helius_websocket_notifications_total counter\n");
+        for (subscription, count) in &state.notifications {
+            let _ = writeln!(
+                output,
+                "helius_websocket_notifications_total{{subscription=\"{}\"}} {count}",
+                escape(subscription)
+            );
+        }
+
+        output
+    }
+
+    fn with_state(&self, f: impl FnOnce(&mut State)) {
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&mut state);
+    }
+}
+
+impl Default for PrometheusMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetricsSink for PrometheusMetrics {
+    fn record_operation(&self, operation: &str, elapsed: Duration, error: Option<&HeliusError>) {
+        let seconds: f64 = elapsed.as_secs_f64();
+        self.with_state(|state| {
+            let histogram: &mut Histogram = state.latency.entry(operation.to_string()).or_insert_with(|| Histogram {
+                counts: vec![0; self.buckets.len()],
+                sum: 0.0,
+                count: 0,
+            });
+            for (bound, count) in self.buckets.iter().zip(histogram.counts.iter_mut()) {
+                if seconds <= *bound {
+                    *count += 1;
+                }
+            }
+            histogram.sum += seconds;
+            histogram.count += 1;
+
+            if let Some(error) = error {
+                *state.errors.entry((operation.to_string(), error.kind())).or_default() += 1;
+            }
+        });
+    }
+
+    fn record_retry(&self, operation: &str) {
+        self.with_state(|state| *state.retries.entry(operation.to_string()).or_default() += 1);
+    }
+
+    fn record_notification(&self, subscription: &str) {
+        self.with_state(|state| *state.notifications.entry(subscription.to_string()).or_default() += 1);
+    }
+}
+
+/// Escapes a label value for the Prometheus text format
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use reqwest::{Request, Response};
+
+use crate::error::{HeliusError, Result};
+use crate::request_handler::RequestContext;
+
+/// A hook into every HTTP request sent by the `RequestHandler`
+///
+/// Middleware can inspect or modify each outgoing request (e.g., to add tracing headers or rewrite URLs for a proxy) and
+/// observe each response (e.g., to log timing). Both hooks run once per attempt, so a retried request passes through the
+/// middleware again. When several middleware are registered, `before_request` runs in the order they were added, and
+/// `after_response` runs in reverse order
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use helius::error::{HeliusError, Result};
+/// use helius::middleware::Middleware;
+/// use helius::request_handler::RequestContext;
+/// use reqwest::{Request, Response};
+///
+/// struct TimingLogger;
+///
+/// impl Middleware for TimingLogger {
+///     fn after_response(
+///         &self,
+///         context: &RequestContext,
+///         outcome: std::result::Result<&Response, &HeliusError>,
+///         elapsed: Duration,
+///     ) {
+///         let status = outcome.map(|response| response.status().as_u16()).ok();
+///         println!("{} finished with {:?} in {:?}", context.operation, status, elapsed);
+///     }
+/// }
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Called before a request is sent. Returning an error aborts the request with that error
+    fn before_request(&self, _context: &RequestContext, _request: &mut Request) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called after a response is received or the request fails to send. The response body has not been read yet
+    fn after_response(
+        &self,
+        _context: &RequestContext,
+        _outcome: std::result::Result<&Response, &HeliusError>,
+        _elapsed: Duration,
+    ) {
+    }
+}
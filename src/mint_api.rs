@@ -0,0 +1,210 @@
+//! Helpers for minting compressed NFTs into verified collections with the Helius Mint API
+//!
+//! `mintCompressedNft` can only verify a minted NFT in a collection if the collection's update authority has delegated
+//! its collection authority to the Mint API's authority for the cluster (`Config::mint_api_authority`). The delegation
+//! is a Token Metadata `ApproveCollectionAuthority` instruction signed by the update authority, which only needs to land
+//! once per collection
+//!
+//! # Example
+//! ```rust,no_run
+//! use helius::error::Result;
+//! use helius::types::{MintCompressedNftRequest, MintResponse};
+//! use helius::Helius;
+//! use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+//!
+//! async fn mint_into_collection(helius: &Helius, collection: Pubkey, update_authority: Pubkey) -> Result<MintResponse> {
+//!     if !helius.is_collection_delegated_to_mint_api(&collection).await? {
+//!         let delegate: Instruction =
+//!             helius.delegate_collection_to_mint_api(&collection, &update_authority, &update_authority)?;
+//!         // Sign with the update authority and send `delegate`, e.g. with `create_smart_transaction`
+//!     }
+//!
+//!     helius
+//!         .rpc()
+//!         .mint_compressed_nft(MintCompressedNftRequest {
+//!             name: "Exodia the Forbidden One".to_string(),
+//!             symbol: "ETFO".to_string(),
+//!             owner: "DCQnfUH6mHA333mzkU22b4hMvyqcejUBociodq8bB5HF".to_string(),
+//!             description: "Exodia the Forbidden One is a powerful, legendary creature".to_string(),
+//!             collection: Some(collection.to_string()),
+//!             ..Default::default()
+//!         })
+//!         .await
+//! }
+//! ```
+use crate::client::HeliusAsyncSolanaClient;
+use crate::error::Result;
+use crate::Helius;
+
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+/// The Metaplex Token Metadata program ID
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = Pubkey::from_str_const("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// The System program ID
+pub(crate) const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::from_str_const("11111111111111111111111111111111");
+
+/// Token Metadata's `ApproveCollectionAuthority` instruction
+const APPROVE_COLLECTION_AUTHORITY: u8 = 23;
+/// Token Metadata's `RevokeCollectionAuthority` instruction
+const REVOKE_COLLECTION_AUTHORITY: u8 = 24;
+
+/// Returns the address of a mint's Token Metadata metadata account
+pub fn metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Returns the address of the record that delegates a collection's authority to `collection_authority`
+pub fn collection_authority_record_address(collection_mint: &Pubkey, collection_authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            collection_mint.as_ref(),
+            b"collection_authority",
+            collection_authority.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Builds a Token Metadata `ApproveCollectionAuthority` instruction
+///
+/// # Arguments
+/// * `collection_mint` - The mint of the collection NFT
+/// * `update_authority` - The collection's update authority, who must sign
+/// * `new_collection_authority` - The account to delegate the collection authority to
+/// * `payer` - The payer of the collection authority record's rent, who must sign
+pub fn approve_collection_authority(
+    collection_mint: &Pubkey,
+    update_authority: &Pubkey,
+    new_collection_authority: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(
+                collection_authority_record_address(collection_mint, new_collection_authority),
+                false,
+            ),
+            AccountMeta::new_readonly(*new_collection_authority, false),
+            AccountMeta::new(*update_authority, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(metadata_address(collection_mint), false),
+            AccountMeta::new_readonly(*collection_mint, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: vec![APPROVE_COLLECTION_AUTHORITY],
+    }
+}
+
+/// Builds a Token Metadata `RevokeCollectionAuthority` instruction
+///
+/// # Arguments
+/// * `collection_mint` - The mint of the collection NFT
+/// * `revoke_authority` - The collection's update authority or the delegate itself, who must sign
+/// * `delegate_authority` - The account the collection authority was delegated to
+pub fn revoke_collection_authority(
+    collection_mint: &Pubkey,
+    revoke_authority: &Pubkey,
+    delegate_authority: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(
+                collection_authority_record_address(collection_mint, delegate_authority),
+                false,
+            ),
+            AccountMeta::new(*delegate_authority, false),
+            AccountMeta::new(*revoke_authority, true),
+            AccountMeta::new_readonly(metadata_address(collection_mint), false),
+            AccountMeta::new_readonly(*collection_mint, false),
+        ],
+        data: vec![REVOKE_COLLECTION_AUTHORITY],
+    }
+}
+
+impl Helius {
+    /// Builds the instruction that delegates a collection's authority to the Mint API, so `mint_compressed_nft` can
+    /// mint into it as a verified collection
+    ///
+    /// # Arguments
+    /// * `collection_mint` - The mint of the collection NFT
+    /// * `update_authority` - The collection's update authority, who must sign
+    /// * `payer` - The payer of the collection authority record's rent, who must sign
+    ///
+    /// # Returns
+    /// An `ApproveCollectionAuthority` instruction for the Mint API authority of the configured cluster
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if the configured cluster isn't served by the Mint API
+    pub fn delegate_collection_to_mint_api(
+        &self,
+        collection_mint: &Pubkey,
+        update_authority: &Pubkey,
+        payer: &Pubkey,
+    ) -> Result<Instruction> {
+        Ok(approve_collection_authority(
+            collection_mint,
+            update_authority,
+            &self.config.mint_api_authority()?.into(),
+            payer,
+        ))
+    }
+
+    /// Builds the instruction that revokes the Mint API's authority over a collection
+    ///
+    /// # Arguments
+    /// * `collection_mint` - The mint of the collection NFT
+    /// * `update_authority` - The collection's update authority, who must sign
+    ///
+    /// # Returns
+    /// A `RevokeCollectionAuthority` instruction for the Mint API authority of the configured cluster
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if the configured cluster isn't served by the Mint API
+    pub fn revoke_mint_api_collection_delegate(
+        &self,
+        collection_mint: &Pubkey,
+        update_authority: &Pubkey,
+    ) -> Result<Instruction> {
+        Ok(revoke_collection_authority(
+            collection_mint,
+            update_authority,
+            &self.config.mint_api_authority()?.into(),
+        ))
+    }
+
+    /// Checks whether a collection's authority is delegated to the Mint API
+    ///
+    /// # Arguments
+    /// * `collection_mint` - The mint of the collection NFT
+    ///
+    /// # Returns
+    /// Whether the collection authority record for the Mint API authority of the configured cluster exists
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if the configured cluster isn't served by the Mint API, or an error if the
+    /// record's account can't be fetched
+    pub async fn is_collection_delegated_to_mint_api(&self, collection_mint: &Pubkey) -> Result<bool> {
+        let record: Pubkey =
+            collection_authority_record_address(collection_mint, &self.config.mint_api_authority()?.into());
+        let client: HeliusAsyncSolanaClient = self.async_connection()?;
+        let account: Option<Account> = client
+            .get_account_with_commitment(&record, client.commitment())
+            .await?
+            .value;
+        Ok(account.is_some_and(|account| account.owner == TOKEN_METADATA_PROGRAM_ID))
+    }
+}
@@ -1,20 +1,14 @@
 use crate::error::{HeliusError, Result};
-use crate::request_handler::SDK_USER_AGENT;
 use crate::types::{
     CreateSmartTransactionConfig, CreateSmartTransactionSeedConfig, GetPriorityFeeEstimateOptions,
-    GetPriorityFeeEstimateRequest, GetPriorityFeeEstimateResponse, PriorityLevel, SenderSendOptions, SmartTransaction,
+    GetPriorityFeeEstimateRequest, GetPriorityFeeEstimateResponse, PriorityLevel, SmartTransaction,
     SmartTransactionConfig, Timeout,
 };
 use crate::Helius;
-use std::str::FromStr;
 use std::sync::Arc;
 
-use base64::{engine::general_purpose::STANDARD as B64, Engine as _};
 use bincode::{serialize, ErrorKind};
-use phf::phf_map;
-use rand::Rng;
 use reqwest::StatusCode;
-use serde_json::json;
 use solana_client::{
     rpc_client::SerializableTransaction,
     rpc_config::{RpcSendTransactionConfig, RpcSimulateTransactionConfig},
@@ -34,139 +28,31 @@ use solana_sdk::{
     signer::keypair::Keypair,
     transaction::{Transaction, VersionedTransaction},
 };
-use solana_system_interface::instruction as system_instruction;
 use solana_transaction_status::TransactionConfirmationStatus;
 use std::time::{Duration, Instant};
 use tokio::time::sleep;
 
-const CU_BUFFER_MULTIPLIER_DEFAULT: f32 = 1.25;
-
-const MIN_TIP_LAMPORTS_DUAL: u64 = 1_000_000; // 0.001 SOL
-const MIN_TIP_LAMPORTS_SWQOS: u64 = 500_000; // 0.0005 SOL
-
-const TIP_FLOOR_URL: &str = "https://bundles.jito.wtf/api/v1/bundles/tip_floor";
-
-const SENDER_TIP_ACCOUNTS: [&str; 10] = [
-    "4ACfpUFoaSD9bfPdeu6DBt89gB6ENTeHBXCAi87NhDEE",
-    "D2L6yPZ2FmmmTKPgzaMKdhu6EWZcTpLy1Vhx8uvZe7NZ",
-    "9bnz4RShgq1hAnLnZbP8kbgBg1kEmcJBYQq3gQbmnSta",
-    "5VY91ws6B2hMmBFRsXkoAAdsPHBJwRfBht4DXox3xkwn",
-    "2nyhqdwKcJZR2vcqCyrYsaPVdAnFoJjiksCXJ7hfEYgD",
-    "2q5pghRs6arqVjRvT5gfgWfWcHWmw1ZuCzphgd5KfWGJ",
-    "wyvPkWjVZz1M8fHQnMMCDTQDbkManefNNhweYk5WkcF",
-    "3KCKozbAaF75qEU33jtzozcJ29yJuaLJTy2jFdzUY8bT",
-    "4vieeGHPYPG2MmyPRcYjdiDmmhN3ww7hsFNap8pVN3Ey",
-    "4TQLFNWK8AovT1gFvda5jfw2oJeRMKEmw7aH6MGBJ3or",
-];
-
-pub static SENDER_ENDPOINTS: phf::Map<&'static str, &'static str> = phf_map! {
-    "Default"      => "http://sender.helius-rpc.com",
-    "US_SLC"       => "http://slc-sender.helius-rpc.com",
-    "US_EAST"      => "http://ewr-sender.helius-rpc.com",
-    "EU_WEST"      => "http://lon-sender.helius-rpc.com",
-    "EU_CENTRAL"   => "http://fra-sender.helius-rpc.com",
-    "EU_NORTH"     => "http://ams-sender.helius-rpc.com",
-    "AP_SINGAPORE" => "http://sg-sender.helius-rpc.com",
-    "AP_TOKYO"     => "http://tyo-sender.helius-rpc.com",
-};
-
-pub static SENDER_REGION_ALIASES: phf::Map<&'static str, &'static str> = phf_map! {
-    "US-EAST"      => "US_EAST",
-    "US-SLC"       => "US_SLC",
-    "EU-WEST"      => "EU_WEST",
-    "EU-CENTRAL"   => "EU_CENTRAL",
-    "EU-NORTH"     => "EU_NORTH",
-    "AP-SINGAPORE" => "AP_SINGAPORE",
-    "AP-TOKYO"     => "AP_TOKYO",
-};
-
-const SENDER_DEFAULT_BASE: &str = "http://slc-sender.helius-rpc.com";
-
-#[inline]
-fn normalize_region(region: &str) -> &str {
-    SENDER_REGION_ALIASES.get(region).copied().unwrap_or(region)
-}
-
-#[inline]
-fn sender_base_url(region: &str) -> &'static str {
-    let key: &str = normalize_region(region);
-    SENDER_ENDPOINTS.get(key).copied().unwrap_or(SENDER_DEFAULT_BASE)
-}
+// The Sender endpoints moved to `crate::sender`, but stay importable from here
+#[cfg(feature = "sender")]
+pub use crate::sender::{sender_fast_url, sender_ping_url, SENDER_ENDPOINTS, SENDER_REGION_ALIASES};
 
-/// `/fast` endpoint used for sending transactions
-#[inline]
-pub fn sender_fast_url(region: &str) -> String {
-    format!("{}/fast", sender_base_url(region))
-}
-
-/// `/ping` endpoint used for connection warming
-#[inline]
-pub fn sender_ping_url(region: &str) -> String {
-    format!("{}/ping", sender_base_url(region))
-}
+const CU_BUFFER_MULTIPLIER_DEFAULT: f32 = 1.25;
 
-/// POST base64 wire-transaction to Sender via `/fast`.
-async fn post_to_sender(tx64: &str, opts: &SenderSendOptions) -> Result<Signature> {
-    let mut endpoint: String = sender_fast_url(&opts.region);
-    if opts.swqos_only {
-        endpoint.push_str("?swqos_only=true");
+impl Helius {
+    /// Reports a completed smart transaction operation to the `MetricsSink`, if one is set
+    pub(crate) fn record_operation<T>(&self, operation: &str, start: Instant, result: &Result<T>) {
+        if let Some(metrics) = self.rpc_client.handler.metrics() {
+            metrics.record_operation(operation, start.elapsed(), result.as_ref().err());
+        }
     }
 
-    let body = json!({
-        "jsonrpc": "2.0",
-        "id": format!("helius-rust-{}", std::time::SystemTime::now()
-             .duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_millis()),
-        "method": "sendTransaction",
-        "params": [
-            tx64,
-            { "encoding": "base64", "skipPreflight": true, "maxRetries": 0 }
-        ]
-    });
-
-    let res = reqwest::Client::new()
-        .post(&endpoint)
-        .header("User-Agent", SDK_USER_AGENT)
-        .json(&body)
-        .send()
-        .await
-        .map_err(|e| HeliusError::InvalidInput(format!("Sender request error: {e}")))?;
-
-    let status = res.status();
-    if !status.is_success() {
-        // `text()` consumes `res` in this branch, and we return immediately.
-        let text = res.text().await.unwrap_or_default();
-        Err(HeliusError::InvalidInput(format!(
-            "Sender HTTP {}: {}",
-            status,
-            text.chars().take(200).collect::<String>()
-        )))
-    } else {
-        // Success path: `json()` consumes `res` *here*, not above.
-        let val: serde_json::Value = res
-            .json()
-            .await
-            .map_err(|e| HeliusError::InvalidInput(format!("Sender JSON parse error: {e}")))?;
-
-        if let Some(s) = val.as_str() {
-            return Signature::from_str(s)
-                .map_err(|e| HeliusError::InvalidInput(format!("Invalid signature from Sender: {e}")));
-        }
-        if let Some(err) = val.get("error") {
-            return Err(HeliusError::InvalidInput(format!("Sender error: {err}")));
-        }
-        if let Some(result) = val.get("result").and_then(|r| r.as_str()) {
-            return Signature::from_str(result)
-                .map_err(|e| HeliusError::InvalidInput(format!("Invalid signature from Sender: {e}")));
+    /// Reports a retried smart transaction operation to the `MetricsSink`, if one is set
+    pub(crate) fn record_retry(&self, operation: &str) {
+        if let Some(metrics) = self.rpc_client.handler.metrics() {
+            metrics.record_retry(operation);
         }
-
-        Err(HeliusError::InvalidInput(format!(
-            "Unexpected Sender response: {}",
-            val.to_string().chars().take(200).collect::<String>()
-        )))
     }
-}
 
-impl Helius {
     // Builds a minimal, unsigned transaction for fee estimation: v0 if LUTs included, legacy otherwise
     fn build_unsigned_preflight_tx(
         payer: &Pubkey,
@@ -214,7 +100,7 @@ impl Helius {
             .collect::<Vec<_>>();
 
         // Fetch the latest blockhash
-        let recent_blockhash: Hash = self.connection().get_latest_blockhash()?;
+        let recent_blockhash: Hash = self.async_connection()?.get_latest_blockhash().await?;
 
         // Create a v0::Message
         let v0_message: v0::Message =
@@ -238,8 +124,9 @@ impl Helius {
             ..Default::default()
         };
         let result: Response<RpcSimulateTransactionResult> = self
-            .connection()
-            .simulate_transaction_with_config(&transaction, config)?;
+            .async_connection()?
+            .simulate_transaction_with_config(&transaction, config)
+            .await?;
 
         // Return the units consumed or None if not available
         Ok(result.value.units_consumed)
@@ -266,7 +153,7 @@ impl Helius {
                 });
             }
 
-            let status = self.connection().get_signature_statuses(&[txt_sig])?;
+            let status = self.async_connection()?.get_signature_statuses(&[txt_sig]).await?;
 
             match status.value[0].clone() {
                 Some(status) => {
@@ -276,8 +163,8 @@ impl Helius {
                     {
                         return Ok(txt_sig);
                     }
-                    if status.err.is_some() {
-                        return Err(HeliusError::TransactionError(status.err.unwrap()));
+                    if let Some(err) = status.err {
+                        return Err(HeliusError::TransactionError(err));
                     }
                 }
                 None => {
@@ -310,8 +197,9 @@ impl Helius {
             .as_ref()
             .map_or(config.signers[0].pubkey(), |signer| signer.pubkey());
         let (recent_blockhash, last_valid_block_hash) = self
-            .connection()
-            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())?;
+            .async_connection()?
+            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+            .await?;
         let mut final_instructions: Vec<Instruction> = vec![];
 
         // Check if any of the instructions provided set the compute unit price and/or limit, and throw an error if `true`
@@ -498,17 +386,35 @@ impl Helius {
         send_transaction_config: RpcSendTransactionConfig,
         last_valid_block_height: u64,
         timeout: Option<Duration>,
+    ) -> Result<Signature> {
+        let start: Instant = Instant::now();
+        let result: Result<Signature> = self
+            .resend_until_confirmed(transaction, send_transaction_config, last_valid_block_height, timeout)
+            .await;
+
+        self.record_operation("sendAndConfirmTransaction", start, &result);
+        result
+    }
+
+    /// Sends a transaction until it is confirmed, it expires, or the timeout elapses
+    async fn resend_until_confirmed(
+        &self,
+        transaction: &impl SerializableTransaction,
+        send_transaction_config: RpcSendTransactionConfig,
+        last_valid_block_height: u64,
+        timeout: Option<Duration>,
     ) -> Result<Signature> {
         // Retry logic with a timeout
         let timeout: Duration = timeout.unwrap_or(Duration::from_secs(60));
         let start_time: Instant = Instant::now();
 
         while Instant::now().duration_since(start_time) < timeout
-            || self.connection().get_block_height()? <= last_valid_block_height
+            || self.async_connection()?.get_block_height().await? <= last_valid_block_height
         {
             let result = self
-                .connection()
-                .send_transaction_with_config(transaction, send_transaction_config);
+                .async_connection()?
+                .send_transaction_with_config(transaction, send_transaction_config)
+                .await;
 
             match result {
                 Ok(signature) => {
@@ -516,11 +422,21 @@ impl Helius {
                     match self.poll_transaction_confirmation(signature).await {
                         Ok(sig) => return Ok(sig),
                         // Retry on polling failure
-                        Err(_) => continue,
+                        Err(_err) => {
+                            #[cfg(feature = "tracing")]
+                            tracing::debug!(%signature, error = %_err, "transaction not confirmed, resending");
+                            self.record_retry("sendAndConfirmTransaction");
+                            continue;
+                        }
                     }
                 }
                 // Retry on send failure
-                Err(_) => continue,
+                Err(_err) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(error = %_err, "send attempt failed, retrying");
+                    self.record_retry("sendAndConfirmTransaction");
+                    continue;
+                }
             }
         }
 
@@ -552,7 +468,7 @@ impl Helius {
             .chain(instructions)
             .collect::<Vec<_>>();
 
-        let recent_blockhash: Hash = self.connection().get_latest_blockhash()?;
+        let recent_blockhash: Hash = self.async_connection()?.get_latest_blockhash().await?;
         let v0_message: v0::Message =
             v0::Message::try_compile(&payer, &test_instructions, &lookup_tables, recent_blockhash)?;
         let versioned_message: VersionedMessage = VersionedMessage::V0(v0_message);
@@ -581,8 +497,9 @@ impl Helius {
         };
 
         let result: Response<RpcSimulateTransactionResult> = self
-            .connection()
-            .simulate_transaction_with_config(&transaction, config)?;
+            .async_connection()?
+            .simulate_transaction_with_config(&transaction, config)
+            .await?;
 
         Ok(result.value.units_consumed)
     }
@@ -635,8 +552,9 @@ impl Helius {
         };
 
         let (recent_blockhash, last_valid_block_hash) = self
-            .connection()
-            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())?;
+            .async_connection()?
+            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+            .await?;
 
         let mut final_instructions: Vec<Instruction> = vec![];
 
@@ -840,8 +758,9 @@ impl Helius {
         let payer_pubkey: Pubkey = fee_payer.pubkey();
 
         let (recent_blockhash, last_valid_block_hash) = self
-            .connection()
-            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())?;
+            .async_connection()?
+            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+            .await?;
 
         let mut final_instructions: Vec<Instruction> = vec![];
 
@@ -954,180 +873,4 @@ impl Helius {
             Ok((SmartTransaction::Legacy(tx), last_valid_block_hash))
         }
     }
-
-    /// Fetches the 75th percentile landed tip floor from Jito's endpoint (in SOL).
-    /// Returns `None` if the fetch fails or the response is malformed.
-    pub async fn fetch_tip_floor_75th(&self) -> Result<Option<u64>> {
-        let res = reqwest::Client::new()
-            .get(TIP_FLOOR_URL)
-            .header("User-Agent", SDK_USER_AGENT)
-            .send()
-            .await
-            .map_err(|e| HeliusError::InvalidInput(format!("Tip floor fetch error: {e}")))?;
-
-        if !res.status().is_success() {
-            return Ok(None);
-        }
-
-        let json: serde_json::Value = res
-            .json()
-            .await
-            .map_err(|e| HeliusError::InvalidInput(format!("Tip floor JSON parse error: {e}")))?;
-
-        let val_sol = json
-            .get(0)
-            .and_then(|o| o.get("landed_tips_75th_percentile"))
-            .and_then(|v| v.as_f64());
-
-        Ok(val_sol.map(|sol| (sol * 1_000_000_000.0) as u64))
-    }
-
-    /// Determines the tip amount in lamports using the 75th percentile floor or falling back to the minimum.
-    pub async fn determine_tip_lamports(&self, swqos_only: bool) -> Result<u64> {
-        let min_lamports: u64 = if swqos_only {
-            MIN_TIP_LAMPORTS_SWQOS
-        } else {
-            MIN_TIP_LAMPORTS_DUAL
-        };
-        let floor_lamports: u64 = self.fetch_tip_floor_75th().await?.unwrap_or(min_lamports);
-
-        Ok(floor_lamports.max(min_lamports))
-    }
-
-    /// Creates an optimized smart transaction with an appended tip transfer instruction for Sender
-    /// Will rename once Jito functions are removed.
-    pub async fn create_smart_transaction_with_tip_for_sender(
-        &self,
-        mut config: CreateSmartTransactionConfig,
-        tip_amount: u64,
-    ) -> Result<(SmartTransaction, u64)> {
-        if config.signers.is_empty() {
-            return Err(HeliusError::InvalidInput(
-                "The fee payer must sign the transaction".to_string(),
-            ));
-        }
-
-        let payer_pubkey: Pubkey = config
-            .fee_payer
-            .as_ref()
-            .map_or(config.signers[0].pubkey(), |signer| signer.pubkey());
-
-        if tip_amount > 0 {
-            let mut rng = rand::rng();
-            let idx = rng.random_range(0..SENDER_TIP_ACCOUNTS.len());
-            let tip_pubkey = Pubkey::from_str(SENDER_TIP_ACCOUNTS[idx])
-                .map_err(|e| HeliusError::InvalidInput(format!("Invalid tip account: {e}")))?;
-
-            let tip_ix = system_instruction::transfer(&payer_pubkey, &tip_pubkey, tip_amount);
-            config.instructions.push(tip_ix);
-        }
-
-        self.create_smart_transaction(&config).await
-    }
-
-    /// Warms Sender connection by hitting `/ping`.
-    pub async fn warm_sender_connection(&self, region: &str) -> Result<()> {
-        let url = sender_ping_url(region);
-        let res = reqwest::Client::new()
-            .get(&url)
-            .header("User-Agent", SDK_USER_AGENT)
-            .send()
-            .await
-            .map_err(|e| HeliusError::InvalidInput(format!("Sender ping error: {e}")))?;
-        if !res.status().is_success() {
-            return Err(HeliusError::InvalidInput(format!("Sender ping HTTP {}", res.status())));
-        }
-        Ok(())
-    }
-
-    /// Sends a signed tx via Sender `/fast` and polls until confirmed (or until timeout/last valid blockhash expiry).
-    /// NOTE: Uses `skipPreflight = true`, `maxRetries = 0`.
-    pub async fn send_and_confirm_via_sender<T>(
-        &self,
-        transaction: &T,
-        last_valid_block_height: u64,
-        opts: SenderSendOptions,
-    ) -> Result<Signature>
-    where
-        T: SerializableTransaction + serde::Serialize + ?Sized,
-    {
-        let wire: Vec<u8> =
-            bincode::serialize(transaction).map_err(|e: Box<ErrorKind>| HeliusError::InvalidInput(e.to_string()))?;
-
-        // Base64 encode the wire transaction for Sender
-        let tx64: String = B64.encode(&wire);
-
-        // Send to Sender
-        let sig: Signature = post_to_sender(&tx64, &opts).await?;
-
-        // Poll until confirmed (or timeout/last valid blockhash expiry)
-        let start: Instant = Instant::now();
-        let timeout: Duration = Duration::from_millis(opts.poll_timeout_ms);
-        let interval: Duration = Duration::from_millis(opts.poll_interval_ms);
-
-        loop {
-            if start.elapsed() >= timeout {
-                return Err(HeliusError::Timeout {
-                    code: StatusCode::REQUEST_TIMEOUT,
-                    text: format!("Transaction {}'s confirmation timed out", sig),
-                });
-            }
-
-            if self.connection().get_block_height()? > last_valid_block_height {
-                return Err(HeliusError::Timeout {
-                    code: StatusCode::REQUEST_TIMEOUT,
-                    text: format!(
-                        "Transaction {} expired (last_valid_block_height={})",
-                        sig, last_valid_block_height
-                    ),
-                });
-            }
-
-            match self.poll_transaction_confirmation(sig).await {
-                Ok(confirmed) => return Ok(confirmed),
-                Err(_) => sleep(interval).await,
-            }
-        }
-    }
-
-    /// Builds an optimized tx and sends via Sender.
-    /// If you need a tip transfer, prepend it to `config.create_config.instructions` before calling.
-    pub async fn send_smart_transaction_with_sender(
-        &self,
-        config: SmartTransactionConfig,
-        sender_opts: SenderSendOptions,
-    ) -> Result<Signature> {
-        if sender_opts.region.trim().is_empty() {
-            return Err(HeliusError::InvalidInput("Sender region must be specified".to_string()));
-        }
-
-        // Determine tip and enforce floor (all in lamports)
-        let mut tip_lamports = self.determine_tip_lamports(sender_opts.swqos_only).await?;
-        let floor = if sender_opts.swqos_only {
-            MIN_TIP_LAMPORTS_SWQOS
-        } else {
-            MIN_TIP_LAMPORTS_DUAL
-        };
-
-        if tip_lamports < floor {
-            tip_lamports = floor;
-        }
-
-        let create_cfg: CreateSmartTransactionConfig = config.create_config;
-
-        let (transaction, last_valid_block_height) = self
-            .create_smart_transaction_with_tip_for_sender(create_cfg, tip_lamports)
-            .await?;
-
-        match transaction {
-            SmartTransaction::Legacy(tx) => {
-                self.send_and_confirm_via_sender(&tx, last_valid_block_height, sender_opts)
-                    .await
-            }
-            SmartTransaction::Versioned(tx) => {
-                self.send_and_confirm_via_sender(&tx, last_valid_block_height, sender_opts)
-                    .await
-            }
-        }
-    }
 }
@@ -0,0 +1,567 @@
+//! Lazy, auto-paginating streams over paginated Helius methods
+//!
+//! The `*_stream` methods on `RpcClient` fetch one page at a time as the stream is polled, so a whole collection can be
+//! walked without holding it in memory or writing the pagination loop by hand
+#[cfg(feature = "das")]
+use std::fmt::Debug;
+#[cfg(feature = "rpc-v2")]
+use std::future::Future;
+
+#[cfg(any(feature = "das", feature = "rpc-v2"))]
+use crate::error::Result;
+#[cfg(feature = "das")]
+use crate::rpc_client::RpcClient;
+#[cfg(feature = "das")]
+use crate::types::{
+    Asset, AssetList, Edition, EditionsList, GetAssetSignatures, GetAssetsByAuthority, GetAssetsByCreator,
+    GetAssetsByGroup, GetAssetsByOwner, GetNftEditions, GetTokenAccounts, SearchAssets, TokenAccount,
+    TokenAccountsList, TransactionSignatureList,
+};
+
+#[cfg(feature = "das")]
+use futures_util::stream::TryStreamExt;
+#[cfg(any(feature = "das", feature = "rpc-v2"))]
+use futures_util::stream::{self, BoxStream, StreamExt};
+#[cfg(feature = "das")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "das")]
+use serde::Serialize;
+#[cfg(any(feature = "das", feature = "rpc-v2"))]
+use tokio::sync::mpsc;
+
+/// The largest number of items the DAS API returns per page
+pub const DAS_MAX_PAGE_SIZE: u32 = 1000;
+
+/// The largest number of accounts `getProgramAccountsV2` and `getTokenAccountsByOwnerV2` return per page
+pub const V2_MAX_PAGE_SIZE: u32 = 10_000;
+
+/// Controls how a paginating stream fetches pages and when it stops
+///
+/// # Example
+/// ```rust
+/// use helius::pagination::PaginationOptions;
+///
+/// let options: PaginationOptions = PaginationOptions::default()
+///     .with_page_size(500)
+///     .with_max_items(2_000)
+///     .with_prefetch(1);
+/// assert_eq!(options.page_size, Some(500));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PaginationOptions {
+    /// The number of items requested per page. Defaults to the largest page size the method allows
+    pub page_size: Option<u32>,
+    /// The maximum number of items the stream yields before it ends. Unlimited by default
+    pub max_items: Option<usize>,
+    /// The number of pages fetched in the background ahead of the consumer. Defaults to `0`, which only fetches a page
+    /// once the previous one has been consumed
+    pub prefetch: usize,
+}
+
+impl PaginationOptions {
+    /// Requests `page_size` items per page
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Ends the stream after it yields `max_items` items
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Fetches up to `pages` pages in the background while the consumer processes the current one. Memory use stays
+    /// bounded by the number of pages buffered
+    pub fn with_prefetch(mut self, pages: usize) -> Self {
+        self.prefetch = pages;
+        self
+    }
+
+    /// Returns the page size to request, never asking for more items than the stream may yield
+    #[cfg(any(feature = "das", feature = "rpc-v2"))]
+    pub(crate) fn effective_page_size(&self, default: u32) -> u32 {
+        let page_size: u32 = self.page_size.unwrap_or(default);
+        let capped: u32 = match self.max_items {
+            Some(max_items) => page_size.min(u32::try_from(max_items).unwrap_or(u32::MAX)),
+            None => page_size,
+        };
+        capped.max(1)
+    }
+}
+
+/// A page of accounts from `getProgramAccountsV2` or `getTokenAccountsByOwnerV2`
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountPage<T> {
+    pub accounts: Vec<T>,
+    /// The key of the page that follows this one, or `None` if this is the last page. Save it as a checkpoint and set
+    /// it as the config's `pagination_key` to resume the stream after this page
+    pub pagination_key: Option<String>,
+    /// The total number of matching accounts, if the method returned it
+    pub total_results: Option<u64>,
+    /// The slot the page was read at, if the method returned a context
+    pub context_slot: Option<u64>,
+}
+
+/// Drives `pages` from a background task that stays up to `prefetch` pages ahead of the consumer
+///
+/// The task is spawned when the returned stream is first polled, and stops once the stream is dropped or `pages`
+/// yields an error
+#[cfg(any(feature = "das", feature = "rpc-v2"))]
+pub(crate) fn prefetched<T: Send + 'static>(
+    pages: BoxStream<'static, Result<T>>,
+    prefetch: usize,
+) -> BoxStream<'static, Result<T>> {
+    if prefetch == 0 {
+        return pages;
+    }
+
+    enum Prefetcher<T> {
+        Idle(BoxStream<'static, Result<T>>),
+        Running(mpsc::Receiver<Result<T>>),
+    }
+
+    stream::unfold(Prefetcher::Idle(pages), move |prefetcher| async move {
+        let mut receiver: mpsc::Receiver<Result<T>> = match prefetcher {
+            Prefetcher::Running(receiver) => receiver,
+            Prefetcher::Idle(mut pages) => {
+                let (sender, receiver) = mpsc::channel(prefetch);
+                tokio::spawn(async move {
+                    while let Some(page) = pages.next().await {
+                        let failed: bool = page.is_err();
+                        if sender.send(page).await.is_err() || failed {
+                            break;
+                        }
+                    }
+                });
+                receiver
+            }
+        };
+
+        let page: Result<T> = receiver.recv().await?;
+        Some((page, Prefetcher::Running(receiver)))
+    })
+    .boxed()
+}
+
+/// Streams the pages of the V2 method named `_method`, which paginates by `pagination_key`, starting from `first_key`
+///
+/// `fetch` is called with the key and limit of each page. The stream ends after the page without a next key, or once
+/// `max_items` accounts have been yielded, in which case the last page is requested with a smaller limit
+#[cfg(feature = "rpc-v2")]
+pub(crate) fn v2_stream<T, F, Fut>(
+    _method: &'static str,
+    first_key: Option<String>,
+    default_page_size: u32,
+    options: PaginationOptions,
+    fetch: F,
+) -> BoxStream<'static, Result<AccountPage<T>>>
+where
+    T: Send + 'static,
+    F: Fn(Option<String>, u32) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<AccountPage<T>>> + Send + 'static,
+{
+    let page_size: u32 = options.effective_page_size(default_page_size);
+
+    struct V2Pager<F> {
+        fetch: F,
+        /// The key of the next page, or `None` once the stream is done
+        next: Option<Option<String>>,
+        remaining: Option<usize>,
+    }
+
+    let pager: V2Pager<F> = V2Pager {
+        fetch,
+        next: Some(first_key),
+        remaining: options.max_items,
+    };
+
+    let pages = stream::try_unfold(pager, move |mut pager| async move {
+        let Some(key) = pager.next.take() else {
+            return Ok(None);
+        };
+        let limit: u32 = match pager.remaining {
+            Some(0) => return Ok(None),
+            Some(remaining) => page_size.min(u32::try_from(remaining).unwrap_or(u32::MAX)),
+            None => page_size,
+        };
+
+        let page: AccountPage<T> = (pager.fetch)(key, limit).await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            method = _method,
+            fetched = page.accounts.len(),
+            has_more = page.pagination_key.is_some(),
+            "fetched page"
+        );
+
+        pager.remaining = pager
+            .remaining
+            .map(|remaining| remaining.saturating_sub(page.accounts.len()));
+        pager.next = page.pagination_key.clone().map(Some);
+        Ok(Some((page, pager)))
+    })
+    .boxed();
+
+    prefetched(pages, options.prefetch)
+}
+
+/// A DAS request that returns one page of a list, along with how to move it to another page
+#[cfg(feature = "das")]
+pub(crate) trait DasListRequest: Debug + Serialize + Send + Sync + 'static {
+    type Page: Debug + DeserializeOwned + Default + Send;
+    type Item: Send + 'static;
+
+    const METHOD: &'static str;
+
+    fn set_limit(&mut self, limit: u32);
+    fn page(&self) -> Option<u32>;
+    fn set_page(&mut self, page: Option<u32>);
+    fn cursor(&self) -> Option<&str>;
+    fn set_cursor(&mut self, cursor: Option<String>);
+
+    /// Splits a page into its items and the cursor of the next page, if the method returned one
+    fn into_items(page: Self::Page) -> (Vec<Self::Item>, Option<String>);
+}
+
+/// Where the next page of a stream starts
+#[cfg(feature = "das")]
+enum Position {
+    /// The first page, as described by the caller's request
+    First,
+    Cursor(String),
+    Page(u32),
+    Done,
+}
+
+#[cfg(feature = "das")]
+struct DasPager<R> {
+    client: RpcClient,
+    request: R,
+    page_size: u32,
+    position: Position,
+}
+
+/// Streams every item of a DAS list method, fetching the next page only once the current one has been consumed, or
+/// ahead of time when prefetching
+///
+/// Pages are followed by cursor when the method returns one, and by page number otherwise. The stream ends at the
+/// first empty page, at a short page when paginating by page number, or after `max_items` items
+#[cfg(feature = "das")]
+pub(crate) fn das_stream<R: DasListRequest>(
+    client: RpcClient,
+    mut request: R,
+    options: PaginationOptions,
+) -> BoxStream<'static, Result<R::Item>> {
+    let page_size: u32 = options.effective_page_size(DAS_MAX_PAGE_SIZE);
+    request.set_limit(page_size);
+
+    let pager: DasPager<R> = DasPager {
+        client,
+        request,
+        page_size,
+        position: Position::First,
+    };
+
+    let pages = stream::try_unfold(pager, next_page).boxed();
+    prefetched(pages, options.prefetch)
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+        .take(options.max_items.unwrap_or(usize::MAX))
+        .boxed()
+}
+
+#[cfg(feature = "das")]
+async fn next_page<R: DasListRequest>(mut pager: DasPager<R>) -> Result<Option<(Vec<R::Item>, DasPager<R>)>> {
+    match std::mem::replace(&mut pager.position, Position::Done) {
+        Position::Done => return Ok(None),
+        Position::First => {}
+        Position::Cursor(cursor) => {
+            pager.request.set_page(None);
+            pager.request.set_cursor(Some(cursor));
+        }
+        Position::Page(page) => {
+            pager.request.set_cursor(None);
+            pager.request.set_page(Some(page));
+        }
+    }
+
+    let page: R::Page = pager.client.post_rpc_request(R::METHOD, &pager.request).await?;
+    let (items, cursor) = R::into_items(page);
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        method = R::METHOD,
+        fetched = items.len(),
+        has_cursor = cursor.is_some(),
+        "fetched page"
+    );
+
+    pager.position = match (cursor, pager.request.cursor()) {
+        _ if items.is_empty() => Position::Done,
+        (Some(next), sent) if sent != Some(next.as_str()) => Position::Cursor(next),
+        // A repeated cursor, or a cursor-paginated request whose response has no cursor, is the last page
+        (Some(_), _) | (None, Some(_)) => Position::Done,
+        (None, None) if (items.len() as u64) < u64::from(pager.page_size) => Position::Done,
+        (None, None) => Position::Page(pager.request.page().unwrap_or(1) + 1),
+    };
+
+    Ok(Some((items, pager)))
+}
+
+#[cfg(feature = "das")]
+impl DasListRequest for GetAssetsByOwner {
+    type Page = AssetList;
+    type Item = Asset;
+
+    const METHOD: &'static str = "getAssetsByOwner";
+
+    fn set_limit(&mut self, limit: u32) {
+        self.limit = Some(i32::try_from(limit).unwrap_or(i32::MAX));
+    }
+
+    fn page(&self) -> Option<u32> {
+        (self.page != 0).then_some(self.page)
+    }
+
+    fn set_page(&mut self, page: Option<u32>) {
+        self.page = page.unwrap_or_default();
+    }
+
+    fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn into_items(page: AssetList) -> (Vec<Asset>, Option<String>) {
+        (page.items, page.cursor)
+    }
+}
+
+#[cfg(feature = "das")]
+impl DasListRequest for GetAssetsByAuthority {
+    type Page = AssetList;
+    type Item = Asset;
+
+    const METHOD: &'static str = "getAssetsByAuthority";
+
+    fn set_limit(&mut self, limit: u32) {
+        self.limit = Some(limit);
+    }
+
+    fn page(&self) -> Option<u32> {
+        (self.page != 0).then_some(self.page)
+    }
+
+    fn set_page(&mut self, page: Option<u32>) {
+        self.page = page.unwrap_or_default();
+    }
+
+    fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn into_items(page: AssetList) -> (Vec<Asset>, Option<String>) {
+        (page.items, page.cursor)
+    }
+}
+
+#[cfg(feature = "das")]
+impl DasListRequest for GetAssetsByCreator {
+    type Page = AssetList;
+    type Item = Asset;
+
+    const METHOD: &'static str = "getAssetsByCreator";
+
+    fn set_limit(&mut self, limit: u32) {
+        self.limit = Some(limit);
+    }
+
+    fn page(&self) -> Option<u32> {
+        self.page
+    }
+
+    fn set_page(&mut self, page: Option<u32>) {
+        self.page = page;
+    }
+
+    fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn into_items(page: AssetList) -> (Vec<Asset>, Option<String>) {
+        (page.items, page.cursor)
+    }
+}
+
+#[cfg(feature = "das")]
+impl DasListRequest for GetAssetsByGroup {
+    type Page = AssetList;
+    type Item = Asset;
+
+    const METHOD: &'static str = "getAssetsByGroup";
+
+    fn set_limit(&mut self, limit: u32) {
+        self.limit = Some(limit);
+    }
+
+    fn page(&self) -> Option<u32> {
+        self.page
+    }
+
+    fn set_page(&mut self, page: Option<u32>) {
+        self.page = page;
+    }
+
+    fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn into_items(page: AssetList) -> (Vec<Asset>, Option<String>) {
+        (page.items, page.cursor)
+    }
+}
+
+#[cfg(feature = "das")]
+impl DasListRequest for SearchAssets {
+    type Page = AssetList;
+    type Item = Asset;
+
+    const METHOD: &'static str = "searchAssets";
+
+    fn set_limit(&mut self, limit: u32) {
+        self.limit = Some(limit);
+    }
+
+    fn page(&self) -> Option<u32> {
+        self.page
+    }
+
+    fn set_page(&mut self, page: Option<u32>) {
+        self.page = page;
+    }
+
+    fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn into_items(page: AssetList) -> (Vec<Asset>, Option<String>) {
+        (page.items, page.cursor)
+    }
+}
+
+#[cfg(feature = "das")]
+impl DasListRequest for GetTokenAccounts {
+    type Page = TokenAccountsList;
+    type Item = TokenAccount;
+
+    const METHOD: &'static str = "getTokenAccounts";
+
+    fn set_limit(&mut self, limit: u32) {
+        self.limit = Some(limit);
+    }
+
+    fn page(&self) -> Option<u32> {
+        self.page
+    }
+
+    fn set_page(&mut self, page: Option<u32>) {
+        self.page = page;
+    }
+
+    fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn into_items(page: TokenAccountsList) -> (Vec<TokenAccount>, Option<String>) {
+        (page.token_accounts, page.cursor)
+    }
+}
+
+#[cfg(feature = "das")]
+impl DasListRequest for GetAssetSignatures {
+    type Page = TransactionSignatureList;
+    /// A transaction signature and its instruction type
+    type Item = (String, String);
+
+    const METHOD: &'static str = "getSignaturesForAsset";
+
+    fn set_limit(&mut self, limit: u32) {
+        self.limit = Some(limit);
+    }
+
+    fn page(&self) -> Option<u32> {
+        self.page
+    }
+
+    fn set_page(&mut self, page: Option<u32>) {
+        self.page = page;
+    }
+
+    fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+
+    fn into_items(page: TransactionSignatureList) -> (Vec<(String, String)>, Option<String>) {
+        (page.items, None)
+    }
+}
+
+#[cfg(feature = "das")]
+impl DasListRequest for GetNftEditions {
+    type Page = EditionsList;
+    type Item = Edition;
+
+    const METHOD: &'static str = "getNftEditions";
+
+    fn set_limit(&mut self, limit: u32) {
+        self.limit = Some(limit);
+    }
+
+    fn page(&self) -> Option<u32> {
+        self.page
+    }
+
+    fn set_page(&mut self, page: Option<u32>) {
+        self.page = page;
+    }
+
+    // `getNftEditions` only paginates by page number
+    fn cursor(&self) -> Option<&str> {
+        None
+    }
+
+    fn set_cursor(&mut self, _cursor: Option<String>) {}
+
+    fn into_items(page: EditionsList) -> (Vec<Edition>, Option<String>) {
+        (page.editions, None)
+    }
+}
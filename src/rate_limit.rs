@@ -0,0 +1,194 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::{sleep, Instant};
+
+use crate::error::{HeliusError, Result};
+use crate::request_handler::EndpointFamily;
+
+/// Requests per second allowed for each endpoint family
+///
+/// A limit of `None` leaves that family unthrottled. The plan presets reflect the published Helius limits at the time of
+/// writing. Please visit https://docs.helius.dev/welcome/pricing-and-rate-limits for the current limits of each plan
+///
+/// # Example
+/// ```rust
+/// use helius::rate_limit::RateLimits;
+///
+/// let limits: RateLimits = RateLimits::developer().with_das(Some(5));
+/// assert_eq!(limits.rpc, Some(50));
+/// assert_eq!(limits.das, Some(5));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimits {
+    /// Requests per second for standard and V2 RPC methods (e.g., `getPriorityFeeEstimate` and `getProgramAccountsV2`)
+    pub rpc: Option<u32>,
+    /// Requests per second for DAS API methods (e.g., `getAsset` and `searchAssets`)
+    pub das: Option<u32>,
+    /// Requests per second for the enhanced transactions and webhook APIs
+    pub enhanced_api: Option<u32>,
+}
+
+impl RateLimits {
+    /// Limits for the Free plan
+    pub fn free() -> Self {
+        Self::per_second(10, 2, 2)
+    }
+
+    /// Limits for the Developer plan
+    pub fn developer() -> Self {
+        Self::per_second(50, 10, 10)
+    }
+
+    /// Limits for the Business plan
+    pub fn business() -> Self {
+        Self::per_second(200, 50, 50)
+    }
+
+    /// Limits for the Professional plan
+    pub fn professional() -> Self {
+        Self::per_second(500, 100, 100)
+    }
+
+    fn per_second(rpc: u32, das: u32, enhanced_api: u32) -> Self {
+        Self {
+            rpc: Some(rpc),
+            das: Some(das),
+            enhanced_api: Some(enhanced_api),
+        }
+    }
+
+    /// Sets the requests per second for RPC methods
+    pub fn with_rpc(mut self, limit: Option<u32>) -> Self {
+        self.rpc = limit;
+        self
+    }
+
+    /// Sets the requests per second for DAS API methods
+    pub fn with_das(mut self, limit: Option<u32>) -> Self {
+        self.das = limit;
+        self
+    }
+
+    /// Sets the requests per second for the enhanced transactions and webhook APIs
+    pub fn with_enhanced_api(mut self, limit: Option<u32>) -> Self {
+        self.enhanced_api = limit;
+        self
+    }
+
+    /// Returns the limit for the given endpoint family
+    pub fn limit_for(&self, family: EndpointFamily) -> Option<u32> {
+        match family {
+            EndpointFamily::Rpc => self.rpc,
+            EndpointFamily::Das => self.das,
+            EndpointFamily::EnhancedApi => self.enhanced_api,
+            EndpointFamily::Other => None,
+        }
+    }
+
+    /// Checks that every configured limit is greater than zero
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if a limit is set to zero
+    pub fn validate(&self) -> Result<()> {
+        if [self.rpc, self.das, self.enhanced_api].contains(&Some(0)) {
+            return Err(HeliusError::InvalidInput(
+                "Rate limits must be greater than zero".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// A client-side token-bucket rate limiter with a separate bucket for each endpoint family
+///
+/// Each bucket holds up to one second's worth of requests and refills continuously. Share a limiter between clients by
+/// wrapping it in an `Arc`; clones of `Helius` and `RpcClient` share the limiter they were built with
+#[derive(Debug)]
+pub struct RateLimiter {
+    limits: RateLimits,
+    rpc: Option<TokenBucket>,
+    das: Option<TokenBucket>,
+    enhanced_api: Option<TokenBucket>,
+}
+
+impl RateLimiter {
+    /// Creates a new `RateLimiter` with full buckets
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if a limit is set to zero
+    pub fn new(limits: RateLimits) -> Result<Self> {
+        limits.validate()?;
+
+        Ok(Self {
+            limits,
+            rpc: limits.rpc.map(TokenBucket::new),
+            das: limits.das.map(TokenBucket::new),
+            enhanced_api: limits.enhanced_api.map(TokenBucket::new),
+        })
+    }
+
+    /// Returns the limits this limiter was created with
+    pub fn limits(&self) -> &RateLimits {
+        &self.limits
+    }
+
+    /// Waits until a request to the given endpoint family can be sent without exceeding its limit
+    pub async fn acquire(&self, family: EndpointFamily) {
+        let bucket: Option<&TokenBucket> = match family {
+            EndpointFamily::Rpc => self.rpc.as_ref(),
+            EndpointFamily::Das => self.das.as_ref(),
+            EndpointFamily::EnhancedApi => self.enhanced_api.as_ref(),
+            EndpointFamily::Other => None,
+        };
+
+        if let Some(bucket) = bucket {
+            while let Some(wait) = bucket.try_acquire() {
+                sleep(wait).await;
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(requests_per_second: u32) -> Self {
+        let rate: f64 = f64::from(requests_per_second);
+        Self {
+            rate,
+            state: Mutex::new(BucketState {
+                tokens: rate,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Takes a token if one is available, otherwise returns how long to wait before one will be
+    fn try_acquire(&self) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let now: Instant = Instant::now();
+        let refilled: f64 = now.duration_since(state.last_refill).as_secs_f64() * self.rate;
+        state.tokens = (state.tokens + refilled).min(self.rate);
+        state.last_refill = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64((1.0 - state.tokens) / self.rate))
+        }
+    }
+}
@@ -1,9 +1,19 @@
+#[cfg(feature = "cassette")]
+use crate::cassette::Cassette;
+use crate::config::API_KEY_HEADER;
 use crate::error::{HeliusError, Result};
-use reqwest::{Client, Method, RequestBuilder, Response, StatusCode, Url};
+use crate::metrics::MetricsSink;
+use crate::middleware::Middleware;
+use crate::rate_limit::RateLimiter;
+use crate::retry::{parse_retry_after, RetryPolicy};
+use reqwest::header::{HeaderValue, RETRY_AFTER};
+use reqwest::{Client, Method, Request, RequestBuilder, Response, StatusCode, Url};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fmt::Debug;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
 
 /// The SDK version, sourced from Cargo.toml at compile time
 pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -12,6 +22,95 @@ pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// Format: "helius-rust-sdk/{version} (server)"
 pub const SDK_USER_AGENT: &str = concat!("helius-rust-sdk/", env!("CARGO_PKG_VERSION"), " (server)");
 
+/// The families of Helius endpoints, each of which has its own rate limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointFamily {
+    /// Standard and V2 RPC methods
+    Rpc,
+    /// DAS API methods
+    Das,
+    /// The enhanced transactions and webhook APIs
+    EnhancedApi,
+    /// Endpoints without a Helius rate limit, such as Sender and the Jito tip floor. The API key is never attached to
+    /// these requests
+    Other,
+}
+
+impl EndpointFamily {
+    /// Determines the endpoint family of a JSON-RPC method
+    pub fn for_rpc_method(method: &str) -> Self {
+        match method {
+            "getAsset"
+            | "getAssetBatch"
+            | "getAssetProof"
+            | "getAssetProofBatch"
+            | "getAssetsByOwner"
+            | "getAssetsByAuthority"
+            | "getAssetsByCreator"
+            | "getAssetsByGroup"
+            | "searchAssets"
+            | "getSignaturesForAsset"
+            | "getTokenAccounts"
+            | "getNftEditions" => EndpointFamily::Das,
+            _ => EndpointFamily::Rpc,
+        }
+    }
+}
+
+/// Describes a request sent through the `RequestHandler`
+///
+/// The context determines which rate limit applies to the request and whether it is safe to retry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+    /// The name of the operation, such as the JSON-RPC method (e.g., `getAsset`) or the API call (e.g., `createWebhook`)
+    pub operation: String,
+    /// The endpoint family the request is sent to
+    pub family: EndpointFamily,
+    /// Whether the request can be retried without side effects
+    pub idempotent: bool,
+}
+
+impl RequestContext {
+    /// Creates a new, non-idempotent `RequestContext`
+    pub fn new(operation: impl Into<String>, family: EndpointFamily) -> Self {
+        Self {
+            operation: operation.into(),
+            family,
+            idempotent: false,
+        }
+    }
+
+    /// Creates a new `RequestContext` for a JSON-RPC method
+    ///
+    /// Only known read-only methods (see `is_read_only_rpc_method`) are marked as idempotent. Any other method, such as
+    /// `mintCompressedNft`, is never retried
+    pub fn rpc(method: &str) -> Self {
+        Self::new(method, EndpointFamily::for_rpc_method(method)).idempotent(Self::is_read_only_rpc_method(method))
+    }
+
+    /// Determines whether a JSON-RPC method is known to be read-only, and so safe to retry
+    ///
+    /// These are the DAS methods, the V2 RPC methods, `getTransactionsForAddress`, `getPriorityFeeEstimate`, and
+    /// `getSlot`
+    pub fn is_read_only_rpc_method(method: &str) -> bool {
+        EndpointFamily::for_rpc_method(method) == EndpointFamily::Das
+            || matches!(
+                method,
+                "getProgramAccountsV2"
+                    | "getTokenAccountsByOwnerV2"
+                    | "getTransactionsForAddress"
+                    | "getPriorityFeeEstimate"
+                    | "getSlot"
+            )
+    }
+
+    /// Marks whether the request can be retried without side effects
+    pub fn idempotent(mut self, idempotent: bool) -> Self {
+        self.idempotent = idempotent;
+        self
+    }
+}
+
 /// Manages HTTP requests for the `Helius` client
 ///
 /// This struct is responsible for sending HTTP requests and handling responses. It encapsulates details
@@ -19,6 +118,13 @@ pub const SDK_USER_AGENT: &str = concat!("helius-rust-sdk/", env!("CARGO_PKG_VER
 #[derive(Clone)]
 pub struct RequestHandler {
     pub http_client: Arc<Client>,
+    api_key_header: Option<HeaderValue>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    metrics: Option<Arc<dyn MetricsSink>>,
+    #[cfg(feature = "cassette")]
+    cassette: Option<Arc<Cassette>>,
 }
 
 impl RequestHandler {
@@ -27,25 +133,88 @@ impl RequestHandler {
     /// # Arguments
     /// * `client` - A shared instance of a `reqwest::Client`
     pub fn new(client: Arc<Client>) -> Result<Self> {
-        Ok(Self { http_client: client })
+        Ok(Self {
+            http_client: client,
+            api_key_header: None,
+            retry_policy: None,
+            rate_limiter: None,
+            middleware: Vec::new(),
+            metrics: None,
+            #[cfg(feature = "cassette")]
+            cassette: None,
+        })
+    }
+
+    /// Adds a `Middleware` to the end of the chain that every request passes through
+    pub fn with_middleware(mut self, middleware: Arc<dyn Middleware>) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Reports the latency, outcome, and retries of every request to the given `MetricsSink`. The sink is shared with any
+    /// clones of this handler
+    pub fn with_metrics(mut self, metrics: Arc<dyn MetricsSink>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Returns the `MetricsSink` used by this handler, if any
+    pub fn metrics(&self) -> Option<&Arc<dyn MetricsSink>> {
+        self.metrics.as_ref()
+    }
+
+    /// Records every request and its response to the given `Cassette`, or answers requests from it without touching the
+    /// network, depending on its mode. The cassette is shared with any clones of this handler
+    #[cfg(feature = "cassette")]
+    pub fn with_cassette(mut self, cassette: Arc<Cassette>) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Returns the `Cassette` used by this handler, if any
+    #[cfg(feature = "cassette")]
+    pub fn cassette(&self) -> Option<&Arc<Cassette>> {
+        self.cassette.as_ref()
+    }
+
+    /// Waits for capacity from the given `RateLimiter` before each request. The limiter is shared with any clones of
+    /// this handler
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Returns the `RateLimiter` used by this handler, if any
+    pub fn rate_limiter(&self) -> Option<&Arc<RateLimiter>> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Retries failed requests according to the given `RetryPolicy`. By default, requests are not retried
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Returns the `RetryPolicy` used by this handler, if any
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
     }
 
-    /// Asynchronously sends a specified HTTP request using the `RequestBuilder`
+    /// Sends the given API key in the `X-Api-Key` header with every request
     ///
     /// # Arguments
-    /// * `request_builder` - Configured `RequestBuilder` for a specific HTTP request
-    ///
-    /// # Returns
-    /// A `Result` wrapping a `reqwest::Response` if the request is send and received successfully
-    ///
-    /// # Errors
-    /// Returns `HeliusError::Network` if there is an issue sending the request
-    async fn send_request(&self, request_builder: RequestBuilder) -> Result<Response> {
-        request_builder.send().await.map_err(HeliusError::Network)
+    /// * `api_key` - The API key as a header value. Mark it as sensitive to keep it out of `Debug` output
+    pub fn with_api_key_header(mut self, api_key: HeaderValue) -> Self {
+        self.api_key_header = Some(api_key);
+        self
     }
 
     /// Sends an HTTP request and processes the response to deserialize into a specified generic type
     ///
+    /// Requests with an idempotent HTTP method (e.g., `GET`, `PUT`, or `DELETE`) are retried according to the handler's
+    /// `RetryPolicy`, if one is set. The request is counted against the RPC rate limit; use `send_with_context` to
+    /// describe it more precisely
+    ///
     /// # Type Parameters
     /// * `R` - The type of the request body, which must implement `Serialize`
     /// * `T` - The expected type of the response, which must implement `Deserialize`
@@ -65,17 +234,234 @@ impl RequestHandler {
     where
         R: Serialize + ?Sized + Send + Sync + Debug,
         T: for<'de> Deserialize<'de> + Default,
+    {
+        let context: RequestContext =
+            RequestContext::new(url.path(), EndpointFamily::Rpc).idempotent(method.is_idempotent());
+        self.send_with_context(&context, method, url, body).await
+    }
+
+    /// Sends an HTTP request like `send`, but treats it as idempotent regardless of its HTTP method
+    ///
+    /// This is used for read-only requests sent with `POST`, such as JSON-RPC calls, so they can be retried
+    ///
+    /// # Errors
+    /// Returns an error if the request fails at any stage, including network errors, serialization errors
+    /// or if the response status is not successful
+    pub async fn send_idempotent<R, T>(&self, method: Method, url: Url, body: Option<&R>) -> Result<T>
+    where
+        R: Serialize + ?Sized + Send + Sync + Debug,
+        T: for<'de> Deserialize<'de> + Default,
+    {
+        let context: RequestContext = RequestContext::new(url.path(), EndpointFamily::Rpc).idempotent(true);
+        self.send_with_context(&context, method, url, body).await
+    }
+
+    /// Sends an HTTP request described by the given `RequestContext`
+    ///
+    /// The request waits for capacity from the rate limiter of its endpoint family, if one is set, before each attempt.
+    /// It is only retried if the context marks it as idempotent. With the `tracing` feature enabled, the request is
+    /// recorded in a `helius.request` span named after its operation
+    ///
+    /// # Arguments
+    /// * `context` - Describes the operation, its endpoint family, and whether it is idempotent
+    /// * `method` - The HTTP method to be used for the request
+    /// * `url` - The URL to which the request is sent
+    /// * `body` - An optional request body, serialized as JSON if provided
+    ///
+    /// # Errors
+    /// Returns an error if the request fails at any stage, including network errors, serialization errors
+    /// or if the response status is not successful
+    pub async fn send_with_context<R, T>(
+        &self,
+        context: &RequestContext,
+        method: Method,
+        url: Url,
+        body: Option<&R>,
+    ) -> Result<T>
+    where
+        R: Serialize + ?Sized + Send + Sync + Debug,
+        T: for<'de> Deserialize<'de> + Default,
+    {
+        let start: Instant = Instant::now();
+        let request = self.send_with_retries(context, method, url, body);
+        #[cfg(feature = "tracing")]
+        let request = tracing::Instrument::instrument(request, request_span(context));
+        let result: Result<T> = request.await;
+
+        self.record_operation(context, start, result.as_ref().err());
+        result
+    }
+
+    /// Sends a request until it succeeds, fails with an error that should not be retried, or runs out of retries
+    async fn send_with_retries<R, T>(
+        &self,
+        context: &RequestContext,
+        method: Method,
+        url: Url,
+        body: Option<&R>,
+    ) -> Result<T>
+    where
+        R: Serialize + ?Sized + Send + Sync + Debug,
+        T: for<'de> Deserialize<'de> + Default,
+    {
+        let start: Instant = Instant::now();
+        let mut retry: u32 = 0;
+
+        loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire(context.family).await;
+            }
+
+            let (error, retry_after) = match self.send_once(context, method.clone(), url.clone(), body).await {
+                Ok(data) => return Ok(data),
+                Err(failure) => failure,
+            };
+
+            let policy: &RetryPolicy = match &self.retry_policy {
+                Some(policy) if context.idempotent && retry < policy.max_retries && error.is_retryable() => policy,
+                _ => return Err(error),
+            };
+
+            let delay: Duration = retry_after
+                .map(|retry_after| retry_after.min(policy.max_backoff))
+                .unwrap_or_else(|| policy.backoff(retry));
+            if policy
+                .max_elapsed
+                .is_some_and(|max_elapsed| start.elapsed() + delay > max_elapsed)
+            {
+                return Err(error);
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                retry = retry + 1,
+                delay_ms = delay.as_millis() as u64,
+                error = %error,
+                "retrying request"
+            );
+
+            if let Some(metrics) = &self.metrics {
+                metrics.record_retry(&context.operation);
+            }
+
+            sleep(delay).await;
+            retry += 1;
+        }
+    }
+
+    /// Sends a single HTTP request described by the given `RequestContext` and returns the raw response
+    ///
+    /// The request waits for rate limiter capacity and passes through the middleware like any other request, but it is not
+    /// retried and the response status is not checked. This is used for endpoints with their own response formats, such
+    /// as Sender. It is only reported to the `MetricsSink` as failed if it cannot be sent
+    ///
+    /// # Errors
+    /// Returns `HeliusError::Network` if there is an issue sending the request, or any error returned by a middleware
+    pub async fn send_raw<R>(
+        &self,
+        context: &RequestContext,
+        method: Method,
+        url: Url,
+        body: Option<&R>,
+    ) -> Result<Response>
+    where
+        R: Serialize + ?Sized + Send + Sync + Debug,
+    {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire(context.family).await;
+        }
+
+        let start: Instant = Instant::now();
+        let request = self.execute(context, method, url, body);
+        #[cfg(feature = "tracing")]
+        let request = tracing::Instrument::instrument(request, request_span(context));
+        let result: Result<Response> = request.await;
+
+        self.record_operation(context, start, result.as_ref().err());
+        result
+    }
+
+    /// Reports a completed request to the `MetricsSink`, if one is set
+    fn record_operation(&self, context: &RequestContext, start: Instant, error: Option<&HeliusError>) {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_operation(&context.operation, start.elapsed(), error);
+        }
+    }
+
+    /// Makes a single attempt at a request, returning the error along with any `Retry-After` delay on failure
+    async fn send_once<R, T>(
+        &self,
+        context: &RequestContext,
+        method: Method,
+        url: Url,
+        body: Option<&R>,
+    ) -> std::result::Result<T, (HeliusError, Option<Duration>)>
+    where
+        R: Serialize + ?Sized + Send + Sync + Debug,
+        T: for<'de> Deserialize<'de> + Default,
+    {
+        let response: Response = self.execute(context, method, url, body).await.map_err(|e| (e, None))?;
+        let retry_after: Option<Duration> = response.headers().get(RETRY_AFTER).and_then(parse_retry_after);
+
+        self.handle_response(response).await.map_err(|e| (e, retry_after))
+    }
+
+    /// Sends the request through the cassette if one is attached, and over the network otherwise
+    async fn dispatch(&self, request: Request) -> Result<Response> {
+        #[cfg(feature = "cassette")]
+        if let Some(cassette) = &self.cassette {
+            return cassette.execute(&self.http_client, request).await;
+        }
+
+        self.http_client.execute(request).await.map_err(HeliusError::network)
+    }
+
+    /// Builds a request, passes it through the middleware chain, and sends it
+    ///
+    /// # Errors
+    /// Returns `HeliusError::Network` if there is an issue sending the request. The API key is redacted from the error's URL
+    async fn execute<R>(&self, context: &RequestContext, method: Method, url: Url, body: Option<&R>) -> Result<Response>
+    where
+        R: Serialize + ?Sized + Send + Sync + Debug,
     {
         let mut request_builder: RequestBuilder = self.http_client.request(method, url);
 
         request_builder = request_builder.header("User-Agent", SDK_USER_AGENT);
 
+        if let Some(api_key) = &self.api_key_header {
+            if context.family != EndpointFamily::Other {
+                request_builder = request_builder.header(API_KEY_HEADER, api_key.clone());
+            }
+        }
+
         if let Some(body) = body {
             request_builder = request_builder.json(body);
         }
 
-        let response: Response = self.send_request(request_builder).await?;
-        self.handle_response(response).await
+        let mut request: Request = request_builder.build()?;
+        for middleware in &self.middleware {
+            middleware.before_request(context, &mut request)?;
+        }
+
+        let start: Instant = Instant::now();
+        let result: Result<Response> = self.dispatch(request).await;
+
+        let elapsed: Duration = start.elapsed();
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(response) => tracing::debug!(
+                status = response.status().as_u16(),
+                elapsed_ms = elapsed.as_millis() as u64,
+                "received response"
+            ),
+            Err(error) => tracing::debug!(error = %error, elapsed_ms = elapsed.as_millis() as u64, "request failed"),
+        }
+
+        for middleware in self.middleware.iter().rev() {
+            middleware.after_response(context, result.as_ref(), elapsed);
+        }
+
+        result
     }
 
     /// Handles the Response for a given HTTP request, attempting to deserialize the response body into the requested type
@@ -101,15 +487,15 @@ impl RequestHandler {
                 return Ok(T::default());
             }
 
-            match serde_json::from_str::<T>(&body_text) {
-                Ok(data) => Ok(data),
-                Err(e) => {
-                    println!("Deserialization error: {}", e);
-                    println!("Raw JSON: {}", body_text);
-                    Err(HeliusError::from(e))
-                }
-            }
+            serde_json::from_str::<T>(&body_text).map_err(|err| {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(error = %err, body = %body_text, "failed to deserialize response");
+                HeliusError::from(err)
+            })
         } else {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(status = status.as_u16(), body = %body_text, "request returned an error status");
+
             let body_json: serde_json::Result<Value> = serde_json::from_str(&body_text);
             match body_json {
                 Ok(body) => {
@@ -130,3 +516,14 @@ impl RequestHandler {
         }
     }
 }
+
+/// Creates the span that a request described by the given `RequestContext` is recorded in
+#[cfg(feature = "tracing")]
+fn request_span(context: &RequestContext) -> tracing::Span {
+    tracing::debug_span!(
+        "helius.request",
+        operation = %context.operation,
+        family = ?context.family,
+        idempotent = context.idempotent
+    )
+}
@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use reqwest::header::HeaderValue;
+
+/// Controls how failed requests are retried by the `RequestHandler`
+///
+/// Only idempotent requests are retried, and only when the error is retryable (see `HeliusError::is_retryable`). The delay
+/// between attempts grows exponentially from `initial_backoff` up to `max_backoff`, with optional jitter. If the server
+/// responds with a `Retry-After` header, that delay is used instead, capped at `max_backoff`
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use helius::retry::RetryPolicy;
+///
+/// let policy: RetryPolicy = RetryPolicy::default()
+///     .with_max_retries(5)
+///     .with_initial_backoff(Duration::from_millis(250))
+///     .with_max_elapsed(Some(Duration::from_secs(10)));
+/// assert_eq!(policy.max_retries, 5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of retries after the initial attempt
+    pub max_retries: u32,
+    /// The delay before the first retry
+    pub initial_backoff: Duration,
+    /// The upper bound for the delay between attempts, including delays requested with `Retry-After`
+    pub max_backoff: Duration,
+    /// The factor the delay is multiplied by after each attempt
+    pub multiplier: f64,
+    /// Whether to randomize each delay to between half and all of its computed value
+    pub jitter: bool,
+    /// The total time after which no further retries are attempted, measured from the first attempt
+    pub max_elapsed: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: true,
+            max_elapsed: Some(Duration::from_secs(30)),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the maximum number of retries after the initial attempt
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the upper bound for the delay between attempts
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the factor the delay is multiplied by after each attempt
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Enables or disables jitter
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the total time after which no further retries are attempted
+    pub fn with_max_elapsed(mut self, max_elapsed: Option<Duration>) -> Self {
+        self.max_elapsed = max_elapsed;
+        self
+    }
+
+    /// Returns the delay before the given retry, where `retry` starts at 0 for the first retry
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent: i32 = retry.min(i32::MAX as u32) as i32;
+        let delay: f64 = self.initial_backoff.as_secs_f64() * self.multiplier.max(1.0).powi(exponent);
+        let delay: Duration = Duration::try_from_secs_f64(delay)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+
+        if self.jitter && !delay.is_zero() {
+            delay.mul_f64(rand::rng().random_range(0.5..=1.0))
+        } else {
+            delay
+        }
+    }
+}
+
+/// Parses a `Retry-After` header given either in seconds or as an HTTP date
+pub(crate) fn parse_retry_after(value: &HeaderValue) -> Option<Duration> {
+    let value: &str = value.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date: DateTime<Utc> = DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+    Some((date - Utc::now()).to_std().unwrap_or(Duration::ZERO))
+}
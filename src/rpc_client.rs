@@ -8,41 +8,68 @@
 /// - `BadRequest`: Incorrect request format or parameters. Check the path and the text for details
 /// - `Unauthorized`: Incorrect or missing API key. Ensure you've provided the correct API key
 /// - `NotFound`: The requested resource was not found. This could mean an invalid ID or a non-existent endpoint
-/// - `RateLimitExceeded`: Too many requests have been sent in a short period. Consider implementing retries with an exponential backoff
+/// - `RateLimitExceeded`: Too many requests have been sent in a short period. Consider setting a `RetryPolicy` to retry with an exponential backoff
 /// - `InternalError`: Server-side errors. These are rare and typically indicate issues on the server side. If these issues persist, please contact Helius support
 /// - `Network`: Errors during HTTP communication, typically from underlying network issues
 /// - `SerdeJson`: Errors during the serialization or deserialization process
 /// - `Unknown`: Catch-all for unclassified errors, with a status code and message provided for further investigation
 ///
 /// Ensure to handle these errors gracefully in your application to maintain robustness and stellar UX
-use std::collections::HashMap;
+#[cfg(feature = "das")]
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::sync::Arc;
 
+use crate::batch::RpcBatch;
+#[cfg(feature = "das")]
+use crate::batch::{ChunkOptions, ChunkedAssetBatch, ChunkedAssetProofBatch};
 use crate::config::Config;
 use crate::error::Result;
-use crate::request_handler::RequestHandler;
+#[cfg(feature = "das")]
+use crate::pagination::das_stream;
+#[cfg(any(feature = "das", feature = "rpc-v2"))]
+use crate::pagination::PaginationOptions;
+#[cfg(feature = "rpc-v2")]
+use crate::pagination::{v2_stream, AccountPage, V2_MAX_PAGE_SIZE};
+use crate::request_handler::{EndpointFamily, RequestContext, RequestHandler};
 use crate::types::inner::{RpcRequest, RpcResponse};
+#[cfg(feature = "das")]
 use crate::types::{
-    Asset, AssetList, AssetProof, EditionsList, GetAsset, GetAssetBatch, GetAssetProof, GetAssetProofBatch,
+    Asset, AssetList, AssetProof, Edition, EditionsList, GetAsset, GetAssetBatch, GetAssetProof, GetAssetProofBatch,
     GetAssetSignatures, GetAssetsByAuthority, GetAssetsByCreator, GetAssetsByGroup, GetAssetsByOwner, GetNftEditions,
-    GetPriorityFeeEstimateRequest, GetPriorityFeeEstimateResponse, GetProgramAccountsV2Config,
-    GetProgramAccountsV2Request, GetProgramAccountsV2Response, GetTokenAccounts, GetTokenAccountsByOwnerV2Config,
-    GetTokenAccountsByOwnerV2Request, GetTokenAccountsByOwnerV2Response, GetTransactionsForAddressOptions,
-    GetTransactionsForAddressRequest, GetTransactionsForAddressResponse, GpaAccount, SearchAssets, TokenAccountRecord,
-    TokenAccountsList, TokenAccountsOwnerFilter, TransactionSignatureList,
+    GetTokenAccounts, SearchAssets, TokenAccount, TokenAccountsList, TransactionSignatureList,
+};
+use crate::types::{
+    GetPriorityFeeEstimateRequest, GetPriorityFeeEstimateResponse, MintCompressedNftRequest, MintResponse,
+};
+#[cfg(feature = "rpc-v2")]
+use crate::types::{
+    GetProgramAccountsV2Config, GetProgramAccountsV2Request, GetProgramAccountsV2Response,
+    GetTokenAccountsByOwnerV2Config, GetTokenAccountsByOwnerV2Request, GetTokenAccountsByOwnerV2Response,
+    GetTransactionsForAddressOptions, GetTransactionsForAddressRequest, GetTransactionsForAddressResponse, GpaAccount,
+    TokenAccountRecord, TokenAccountsOwnerFilter,
 };
 
+#[cfg(any(feature = "das", feature = "rpc-v2"))]
+use futures_util::stream::BoxStream;
+#[cfg(feature = "das")]
+use futures_util::stream::{self, StreamExt};
+#[cfg(any(feature = "das", feature = "rpc-v2"))]
+use futures_util::TryStreamExt;
 use reqwest::{Client, Method, Url};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use solana_client::nonblocking::rpc_client::RpcClient as AsyncSolanaRpcClient;
 use solana_client::rpc_client::RpcClient as SolanaRpcClient;
 use solana_commitment_config::CommitmentConfig;
 
+#[derive(Clone)]
 pub struct RpcClient {
     pub handler: RequestHandler,
     pub config: Arc<Config>,
     pub solana_client: Arc<SolanaRpcClient>,
+    /// The asynchronous Solana client used by the SDK's async methods, so they never block the runtime
+    pub async_solana_client: Arc<AsyncSolanaRpcClient>,
 }
 
 impl RpcClient {
@@ -58,15 +85,7 @@ impl RpcClient {
     /// # Errors
     /// Returns `HeliusError` if the URL isn't formatted correctly or the `RequestHandler` fails to initialize
     pub fn new(client: Arc<Client>, config: Arc<Config>) -> Result<Self> {
-        let handler: RequestHandler = RequestHandler::new(client)?;
-        let url: String = format!("{}/?api-key={}", config.endpoints.rpc, config.api_key);
-        let solana_client: Arc<SolanaRpcClient> = Arc::new(SolanaRpcClient::new(url));
-
-        Ok(RpcClient {
-            handler,
-            config,
-            solana_client,
-        })
+        Self::new_with_commitment(client, config, CommitmentConfig::default())
     }
 
     /// Initializes a new RpcClient instance with an embedded Solana client and a commitment config
@@ -82,14 +101,16 @@ impl RpcClient {
     /// # Errors
     /// Returns `HeliusError` if the URL isn't formatted correctly or the `RequestHandler` fails to initialize
     pub fn new_with_commitment(client: Arc<Client>, config: Arc<Config>, commitment: CommitmentConfig) -> Result<Self> {
-        let handler: RequestHandler = RequestHandler::new(client)?;
-        let url: String = format!("{}/?api-key={}", config.endpoints.rpc, config.api_key);
-        let solana_client: Arc<SolanaRpcClient> = Arc::new(SolanaRpcClient::new_with_commitment(url, commitment));
+        let handler: RequestHandler = config.request_handler(client)?;
+        let solana_client: Arc<SolanaRpcClient> = Arc::new(config.solana_rpc_client(commitment, None)?);
+        let async_solana_client: Arc<AsyncSolanaRpcClient> =
+            Arc::new(config.async_solana_rpc_client(commitment, None)?);
 
         Ok(RpcClient {
             handler,
             config,
             solana_client,
+            async_solana_client,
         })
     }
 
@@ -103,21 +124,106 @@ impl RpcClient {
     /// A result that, if successful, contains the deserialized response data
     ///
     /// # Errors
-    /// Returns `HeliusError` if the URL cannot be parsed or the HTTP request fails
+    /// Returns `HeliusError::Rpc` if the server responds with a JSON-RPC error, or another `HeliusError` if the URL cannot
+    /// be parsed or the HTTP request fails
     pub async fn post_rpc_request<R, T>(&self, method: &str, request: R) -> Result<T>
     where
         R: Debug + Serialize + Send + Sync,
         T: Debug + DeserializeOwned + Default,
     {
-        let base_url: String = format!("{}/?api-key={}", self.config.endpoints.rpc, self.config.api_key);
-        let url: Url = Url::parse(&base_url).expect("Failed to parse URL");
+        self.post_rpc_request_with_context(&RequestContext::rpc(method), method, request)
+            .await
+    }
+
+    /// Streamlines an RPC POST request with an explicit `RequestContext`
+    ///
+    /// Use this for methods with side effects, such as `mintCompressedNft`, so they aren't retried by a `RetryPolicy`
+    ///
+    /// # Arguments
+    /// * `context` - Describes the request's endpoint family and whether it is idempotent
+    /// * `method` - RPC method name as a string reference (e.g., "getAsset")
+    /// * `request` - Request data for a given method that conforms to the Debug, Serialize, Send, and Sync traits
+    ///
+    /// # Returns
+    /// A result that, if successful, contains the deserialized response data
+    ///
+    /// # Errors
+    /// Returns `HeliusError::Rpc` if the server responds with a JSON-RPC error, or another `HeliusError` if the URL cannot
+    /// be parsed or the HTTP request fails
+    pub async fn post_rpc_request_with_context<R, T>(
+        &self,
+        context: &RequestContext,
+        method: &str,
+        request: R,
+    ) -> Result<T>
+    where
+        R: Debug + Serialize + Send + Sync,
+        T: Debug + DeserializeOwned + Default,
+    {
+        let url: Url = self
+            .config
+            .authenticated_url(&format!("{}/", self.config.endpoints.rpc))?;
 
         let rpc_request: RpcRequest<R> = RpcRequest::new(method.to_string(), request);
-        let rpc_response: RpcResponse<T> = self.handler.send(Method::POST, url, Some(&rpc_request)).await?;
+        let rpc_response: RpcResponse<T> = self
+            .handler
+            .send_with_context(context, Method::POST, url, Some(&rpc_request))
+            .await?;
 
-        Ok(rpc_response.result)
+        rpc_response.into_result()
+    }
+
+    /// Creates an `RpcBatch` that sends multiple JSON-RPC calls in a single HTTP request
+    ///
+    /// # Returns
+    /// An empty `RpcBatch`. Add calls to it, then send it with `RpcBatch::send`
+    pub fn batch(&self) -> RpcBatch {
+        RpcBatch::new(self.clone())
     }
 
+    /// Gets an estimate of the priority fees required for a transaction to be processed more quickly
+    ///
+    /// This method calculates varying levels of transaction fees that can influence the priority of a transaction, based on current network conditions
+    ///
+    /// # Arguments
+    /// * `request` - A struct that includes the following:
+    /// * `transaction` - Optionally, the serialized transaction for which the fee estimate is requested
+    /// * `account_key` - Optionally, a list of account public keys involved in a given transaction to help determine the necessary priority fee based on the accounts' recent activity
+    /// * `options` - Additional options for fine-tuning the request, such as the desired priority level or the number of slots to look back and consider for the estimate
+    ///
+    /// # Returns
+    /// A `Result` that, if successful, wraps the `GetPriorityFeeEstimateResponse` struct, containing:
+    /// - `priority_fee_estimate`: The estimated priority fee in micro lamports
+    /// - `priority_fee_levels`: A detailed breakdown of potential priority fees at various levels
+    pub async fn get_priority_fee_estimate(
+        &self,
+        request: GetPriorityFeeEstimateRequest,
+    ) -> Result<GetPriorityFeeEstimateResponse> {
+        self.post_rpc_request("getPriorityFeeEstimate", vec![request]).await
+    }
+
+    /// Mints a compressed NFT with the Helius Mint API, which pays for and submits the mint
+    ///
+    /// Minting into a verified collection requires the collection's authority to be delegated to the Mint API first.
+    /// See `Helius::delegate_collection_to_mint_api`
+    ///
+    /// # Arguments
+    /// * `request` - The NFT's metadata, owner, and optionally its collection, delegate, and creators
+    ///
+    /// # Returns
+    /// A `Result` wrapping a `MintResponse` with the mint transaction's signature, whether it was confirmed, and the
+    /// minted asset's ID once it's known
+    pub async fn mint_compressed_nft(&self, request: MintCompressedNftRequest) -> Result<MintResponse> {
+        // Minting isn't idempotent, so a retried request could mint the NFT twice
+        let context: RequestContext = RequestContext::new("mintCompressedNft", EndpointFamily::Rpc);
+        self.post_rpc_request_with_context(&context, "mintCompressedNft", request)
+            .await
+    }
+}
+
+/// DAS API methods
+#[cfg(feature = "das")]
+impl RpcClient {
     /// Gets an asset by its ID
     ///
     /// # Arguments
@@ -165,6 +271,104 @@ impl RpcClient {
         self.post_rpc_request("getAssetProofBatch", request).await
     }
 
+    /// Gets any number of assets by their ID, splitting the IDs into batches the server accepts
+    ///
+    /// The batches are sent concurrently, and their results are merged back in the order the IDs were given
+    ///
+    /// # Arguments
+    /// * `request` - A struct containing the IDs of the assets to fetch, along with optional display options applied to every batch
+    /// * `options` - The number of IDs per batch and the number of batches in flight at once
+    ///
+    /// # Returns
+    /// A `ChunkedAssetBatch` with one optional `Asset` per ID provided, and the IDs for which no asset was found
+    ///
+    /// # Errors
+    /// Returns the error of the first batch that fails, in which case no results are returned
+    pub async fn get_asset_batch_chunked(
+        &self,
+        request: GetAssetBatch,
+        options: ChunkOptions,
+    ) -> Result<ChunkedAssetBatch> {
+        let GetAssetBatch { ids, display_options } = request;
+        let chunk_size: usize = options.effective_chunk_size();
+
+        let batches: Vec<Vec<Option<Asset>>> = stream::iter(ids.chunks(chunk_size))
+            .map(|chunk| {
+                self.get_asset_batch(GetAssetBatch {
+                    ids: chunk.to_vec(),
+                    display_options: display_options.clone(),
+                })
+            })
+            .buffered(options.effective_concurrency())
+            .try_collect()
+            .await?;
+
+        let mut result: ChunkedAssetBatch = ChunkedAssetBatch {
+            assets: Vec::with_capacity(ids.len()),
+            missing: Vec::new(),
+        };
+        for (chunk, mut assets) in ids.chunks(chunk_size).zip(batches) {
+            // Line the results up with the chunk's IDs even if the server returned too few or too many
+            assets.resize_with(chunk.len(), || None);
+            for (id, asset) in chunk.iter().zip(assets) {
+                if asset.is_none() {
+                    result.missing.push(id.clone());
+                }
+                result.assets.push(asset);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Gets the proofs of any number of assets by their IDs, splitting the IDs into batches the server accepts
+    ///
+    /// The batches are sent concurrently, and duplicate IDs are only requested once
+    ///
+    /// # Arguments
+    /// * `request` - A struct containing the IDs of the assets for which proofs are requested
+    /// * `options` - The number of IDs per batch and the number of batches in flight at once
+    ///
+    /// # Returns
+    /// A `ChunkedAssetProofBatch` with the proofs keyed by asset ID, and the IDs for which no proof was returned
+    ///
+    /// # Errors
+    /// Returns the error of the first batch that fails, in which case no results are returned
+    pub async fn get_asset_proof_batch_chunked(
+        &self,
+        request: GetAssetProofBatch,
+        options: ChunkOptions,
+    ) -> Result<ChunkedAssetProofBatch> {
+        let mut seen: HashSet<&str> = HashSet::new();
+        let ids: Vec<String> = request
+            .ids
+            .iter()
+            .filter(|id| seen.insert(id.as_str()))
+            .cloned()
+            .collect();
+
+        let mut proofs: HashMap<String, Option<AssetProof>> = stream::iter(ids.chunks(options.effective_chunk_size()))
+            .map(|chunk| self.get_asset_proof_batch(GetAssetProofBatch { ids: chunk.to_vec() }))
+            .buffered(options.effective_concurrency())
+            .try_fold(HashMap::with_capacity(ids.len()), |mut proofs, batch| async move {
+                proofs.extend(batch);
+                Ok(proofs)
+            })
+            .await?;
+
+        let mut result: ChunkedAssetProofBatch = ChunkedAssetProofBatch::default();
+        for id in ids {
+            match proofs.remove(&id).flatten() {
+                Some(proof) => {
+                    result.proofs.insert(id, proof);
+                }
+                None => result.missing.push(id),
+            }
+        }
+
+        Ok(result)
+    }
+
     /// Gets a list of assets of a given authority
     ///
     /// # Arguments
@@ -253,27 +457,138 @@ impl RpcClient {
         self.post_rpc_request("getNftEditions", request).await
     }
 
-    /// Gets an estimate of the priority fees required for a transaction to be processed more quickly
+    /// Streams all assets owned by a given address, fetching pages lazily as the stream is polled
     ///
-    /// This method calculates varying levels of transaction fees that can influence the priority of a transaction, based on current network conditions
+    /// # Arguments
+    /// * `request` - A struct containing the owner's address, along with optional sorting and display options. Its `limit` is replaced by the page size, and its `page` or `cursor`, if set, is where the stream starts
+    /// * `options` - The page size and an optional cap on the number of items yielded
+    ///
+    /// # Returns
+    /// A stream of `Asset` items that ends after the last page, or with the first error encountered
+    pub fn get_assets_by_owner_stream(
+        &self,
+        request: GetAssetsByOwner,
+        options: PaginationOptions,
+    ) -> BoxStream<'static, Result<Asset>> {
+        das_stream(self.clone(), request, options)
+    }
+
+    /// Streams all assets in a group, fetching pages lazily as the stream is polled
     ///
     /// # Arguments
-    /// * `request` - A struct that includes the following:
-    /// * `transaction` - Optionally, the serialized transaction for which the fee estimate is requested
-    /// * `account_key` - Optionally, a list of account public keys involved in a given transaction to help determine the necessary priority fee based on the accounts' recent activity
-    /// * `options` - Additional options for fine-tuning the request, such as the desired priority level or the number of slots to look back and consider for the estimate
+    /// * `request` - A struct containing the group key and value, along with optional sorting and display options. Its `limit` is replaced by the page size, and its `page` or `cursor`, if set, is where the stream starts
+    /// * `options` - The page size and an optional cap on the number of items yielded
     ///
     /// # Returns
-    /// A `Result` that, if successful, wraps the `GetPriorityFeeEstimateResponse` struct, containing:
-    /// - `priority_fee_estimate`: The estimated priority fee in micro lamports
-    /// - `priority_fee_levels`: A detailed breakdown of potential priority fees at various levels
-    pub async fn get_priority_fee_estimate(
+    /// A stream of `Asset` items that ends after the last page, or with the first error encountered
+    pub fn get_assets_by_group_stream(
         &self,
-        request: GetPriorityFeeEstimateRequest,
-    ) -> Result<GetPriorityFeeEstimateResponse> {
-        self.post_rpc_request("getPriorityFeeEstimate", vec![request]).await
+        request: GetAssetsByGroup,
+        options: PaginationOptions,
+    ) -> BoxStream<'static, Result<Asset>> {
+        das_stream(self.clone(), request, options)
+    }
+
+    /// Streams all assets of a given creator, fetching pages lazily as the stream is polled
+    ///
+    /// # Arguments
+    /// * `request` - A struct containing the creator's address and optional filters for verification, sorting, and display options. Its `limit` is replaced by the page size, and its `page` or `cursor`, if set, is where the stream starts
+    /// * `options` - The page size and an optional cap on the number of items yielded
+    ///
+    /// # Returns
+    /// A stream of `Asset` items that ends after the last page, or with the first error encountered
+    pub fn get_assets_by_creator_stream(
+        &self,
+        request: GetAssetsByCreator,
+        options: PaginationOptions,
+    ) -> BoxStream<'static, Result<Asset>> {
+        das_stream(self.clone(), request, options)
+    }
+
+    /// Streams all assets of a given authority, fetching pages lazily as the stream is polled
+    ///
+    /// # Arguments
+    /// * `request` - A struct containing the authority's address, along with optional sorting and display options. Its `limit` is replaced by the page size, and its `page` or `cursor`, if set, is where the stream starts
+    /// * `options` - The page size and an optional cap on the number of items yielded
+    ///
+    /// # Returns
+    /// A stream of `Asset` items that ends after the last page, or with the first error encountered
+    pub fn get_assets_by_authority_stream(
+        &self,
+        request: GetAssetsByAuthority,
+        options: PaginationOptions,
+    ) -> BoxStream<'static, Result<Asset>> {
+        das_stream(self.clone(), request, options)
+    }
+
+    /// Streams all assets matching the custom search criteria passed in, fetching pages lazily as the stream is polled
+    ///
+    /// # Arguments
+    /// * `request` - A struct containing the search conditions, filtering options, and sorting preferences. Its `limit` is replaced by the page size, and its `page` or `cursor`, if set, is where the stream starts
+    /// * `options` - The page size and an optional cap on the number of items yielded
+    ///
+    /// # Returns
+    /// A stream of `Asset` items that ends after the last page, or with the first error encountered
+    pub fn search_assets_stream(
+        &self,
+        request: SearchAssets,
+        options: PaginationOptions,
+    ) -> BoxStream<'static, Result<Asset>> {
+        das_stream(self.clone(), request, options)
+    }
+
+    /// Streams all token accounts for a specific mint or owner, fetching pages lazily as the stream is polled
+    ///
+    /// # Arguments
+    /// * `request` - A struct containing the owner or mint address, along with optional display options. Its `limit` is replaced by the page size, and its `page` or `cursor`, if set, is where the stream starts
+    /// * `options` - The page size and an optional cap on the number of items yielded
+    ///
+    /// # Returns
+    /// A stream of `TokenAccount` items that ends after the last page, or with the first error encountered
+    pub fn get_token_accounts_stream(
+        &self,
+        request: GetTokenAccounts,
+        options: PaginationOptions,
+    ) -> BoxStream<'static, Result<TokenAccount>> {
+        das_stream(self.clone(), request, options)
+    }
+
+    /// Streams all transaction signatures for a given asset, each paired with its instruction type, fetching pages lazily as the stream is polled
+    ///
+    /// # Arguments
+    /// * `request` - A struct containing the asset's ID, or its tree and leaf index. Its `limit` is replaced by the page size, and its `page` or `cursor`, if set, is where the stream starts
+    /// * `options` - The page size and an optional cap on the number of items yielded
+    ///
+    /// # Returns
+    /// A stream of `(String, String)` items that ends after the last page, or with the first error encountered
+    pub fn get_signatures_for_asset_stream(
+        &self,
+        request: GetAssetSignatures,
+        options: PaginationOptions,
+    ) -> BoxStream<'static, Result<(String, String)>> {
+        das_stream(self.clone(), request, options)
+    }
+
+    /// Streams all NFT editions of a specific master NFT, fetching pages lazily as the stream is polled
+    ///
+    /// # Arguments
+    /// * `request` - A struct containing the master NFT's mint. Its `limit` is replaced by the page size, and its `page` or `cursor`, if set, is where the stream starts
+    /// * `options` - The page size and an optional cap on the number of items yielded
+    ///
+    /// # Returns
+    /// A stream of `Edition` items that ends after the last page, or with the first error encountered
+    pub fn get_nft_editions_stream(
+        &self,
+        request: GetNftEditions,
+        options: PaginationOptions,
+    ) -> BoxStream<'static, Result<Edition>> {
+        das_stream(self.clone(), request, options)
     }
+}
 
+/// V2 RPC methods with cursor-based pagination, and `getTransactionsForAddress`
+#[cfg(feature = "rpc-v2")]
+impl RpcClient {
     /// An enhanced version of getProgramAccounts with cursor-based pagination and changedSlotSince support for efficiently querying large sets of accounts owned by specific Solana
     /// programs with incremental updates.
     ///
@@ -283,6 +598,7 @@ impl RpcClient {
     ///
     /// # Returns
     /// A `GetProgramAccountsV2Response` with:
+    /// * `context` - The RPC context (i.e., slot and API version), if `config.with_context` is set
     /// * `accounts` - The page of program accounts, each with a pubkey and the relevant account info
     /// * `pagination_key` - The cursor for the next page
     /// * `total_results` - The total matches, if available. Otherwise, it returns `None`
@@ -337,9 +653,121 @@ impl RpcClient {
         self.post_rpc_request("getTokenAccountsByOwnerV2", params).await
     }
 
+    /// Streams the pages of program accounts, fetching each page lazily as the stream is polled
+    ///
+    /// Unlike `get_all_program_accounts`, at most one page, plus any prefetched pages, is held in memory at a time, and
+    /// each page is available as soon as it is fetched
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID to query
+    /// * `config` - A config struct that controls the encoding, memcmp/data size filters, and incremental updates defined by the type `GetProgramAccountsV2Config`.
+    ///   Set `pagination_key` to a checkpoint from a previous page to resume after it. The page size is taken from `options`, then `limit`, and defaults to `10000`
+    /// * `options` - The page size, an optional cap on the number of accounts, and the number of pages to prefetch
+    ///
+    /// # Returns
+    /// A stream of `AccountPage`s that ends after the last page, or with the first error encountered
+    pub fn get_program_accounts_v2_stream(
+        &self,
+        program_id: String,
+        config: GetProgramAccountsV2Config,
+        options: PaginationOptions,
+    ) -> BoxStream<'static, Result<AccountPage<GpaAccount>>> {
+        let client: RpcClient = self.clone();
+        let options: PaginationOptions = PaginationOptions {
+            page_size: options.page_size.or(config.limit),
+            ..options
+        };
+        let first_key: Option<String> = config.pagination_key.clone();
+
+        v2_stream(
+            "getProgramAccountsV2",
+            first_key,
+            V2_MAX_PAGE_SIZE,
+            options,
+            move |key, limit| {
+                let client: RpcClient = client.clone();
+                let program_id: String = program_id.clone();
+                let config: GetProgramAccountsV2Config = GetProgramAccountsV2Config {
+                    pagination_key: key,
+                    limit: Some(limit),
+                    ..config.clone()
+                };
+
+                async move {
+                    let response: GetProgramAccountsV2Response =
+                        client.get_program_accounts_v2(program_id, config).await?;
+                    Ok(AccountPage {
+                        accounts: response.accounts,
+                        pagination_key: response.pagination_key,
+                        total_results: response.total_results,
+                        context_slot: response.context.map(|context| context.slot),
+                    })
+                }
+            },
+        )
+    }
+
+    /// Streams the pages of token accounts owned by a wallet, fetching each page lazily as the stream is polled
+    ///
+    /// Unlike `get_all_token_accounts_by_owner`, at most one page, plus any prefetched pages, is held in memory at a
+    /// time, and each page is available as soon as it is fetched
+    ///
+    /// # Arguments
+    /// * `owner` - The Base58 wallet address whose token accounts you want to fetch
+    /// * `filter` - Filter by mint or programId
+    /// * `config` - A config struct that controls the encoding and `changed_since_slot` defined by the type `GetTokenAccountsByOwnerV2Config`.
+    ///   Set `pagination_key` to a checkpoint from a previous page to resume after it. The page size is taken from `options`, then `limit`, and defaults to `10000`
+    /// * `options` - The page size, an optional cap on the number of accounts, and the number of pages to prefetch
+    ///
+    /// # Returns
+    /// A stream of `AccountPage`s that ends after the last page, or with the first error encountered
+    pub fn get_token_accounts_by_owner_v2_stream(
+        &self,
+        owner: String,
+        filter: TokenAccountsOwnerFilter,
+        config: GetTokenAccountsByOwnerV2Config,
+        options: PaginationOptions,
+    ) -> BoxStream<'static, Result<AccountPage<TokenAccountRecord>>> {
+        let client: RpcClient = self.clone();
+        let options: PaginationOptions = PaginationOptions {
+            page_size: options.page_size.or(config.limit),
+            ..options
+        };
+        let first_key: Option<String> = config.pagination_key.clone();
+
+        v2_stream(
+            "getTokenAccountsByOwnerV2",
+            first_key,
+            V2_MAX_PAGE_SIZE,
+            options,
+            move |key, limit| {
+                let client: RpcClient = client.clone();
+                let owner: String = owner.clone();
+                let filter: TokenAccountsOwnerFilter = filter.clone();
+                let config: GetTokenAccountsByOwnerV2Config = GetTokenAccountsByOwnerV2Config {
+                    pagination_key: key,
+                    limit: Some(limit),
+                    ..config.clone()
+                };
+
+                async move {
+                    let response: GetTokenAccountsByOwnerV2Response =
+                        client.get_token_accounts_by_owner_v2(owner, filter, config).await?;
+                    Ok(AccountPage {
+                        accounts: response.value.accounts,
+                        pagination_key: response.value.pagination_key.or(response.pagination_key),
+                        total_results: response.total_results,
+                        context_slot: response.context.map(|context| context.slot),
+                    })
+                }
+            },
+        )
+    }
+
     /// Get all program accounts by auto-paginating through results
     ///
-    /// Automatically handles pagination to fetch all accounts. Please use with caution for programs with many accounts
+    /// Automatically handles pagination to fetch all accounts. Please use with caution for programs with many accounts,
+    /// and prefer `get_program_accounts_v2_stream` to process them page by page
     ///
     /// # Arguments
     /// * `program_id` - The program ID to query
@@ -356,31 +784,18 @@ impl RpcClient {
         // Ignore the user-provided pagination_key since we auto-paginate
         config.pagination_key = None;
 
-        // Default to 10k
-        if config.limit.is_none() {
-            config.limit = Some(10000);
-        }
-
-        let mut all_accounts: Vec<GpaAccount> = Vec::new();
-        loop {
-            let response: GetProgramAccountsV2Response =
-                self.get_program_accounts_v2(program_id.clone(), config.clone()).await?;
-            all_accounts.extend(response.accounts);
-
-            println!("Fetched {} accounts so far", all_accounts.len());
-
-            if let Some(key) = response.pagination_key {
-                config.pagination_key = Some(key);
-            } else {
-                break;
-            }
-        }
-
-        Ok(all_accounts)
+        self.get_program_accounts_v2_stream(program_id, config, PaginationOptions::default())
+            .try_fold(Vec::new(), |mut all_accounts, page| async move {
+                all_accounts.extend(page.accounts);
+                Ok(all_accounts)
+            })
+            .await
     }
 
     /// Get all token accounts by owner by auto-paginating through results
     ///
+    /// Prefer `get_token_accounts_by_owner_v2_stream` to process large sets of token accounts page by page
+    ///
     /// # Arguments
     /// * `owner` - The Base58 wallet address whose token accounts you want to fetch
     /// * `filter` - Filter by mint or programId
@@ -398,26 +813,12 @@ impl RpcClient {
         // Ignore the user-provided pagination_key since we auto-paginate
         config.pagination_key = None;
 
-        // Default to 10k
-        if config.limit.is_none() {
-            config.limit = Some(10000);
-        }
-
-        let mut all_accounts: Vec<TokenAccountRecord> = Vec::new();
-        loop {
-            let response: GetTokenAccountsByOwnerV2Response = self
-                .get_token_accounts_by_owner_v2(owner.clone(), filter.clone(), config.clone())
-                .await?;
-            all_accounts.extend(response.value.accounts);
-
-            if let Some(key) = response.value.pagination_key {
-                config.pagination_key = Some(key);
-            } else {
-                break;
-            }
-        }
-
-        Ok(all_accounts)
+        self.get_token_accounts_by_owner_v2_stream(owner, filter, config, PaginationOptions::default())
+            .try_fold(Vec::new(), |mut all_accounts, page| async move {
+                all_accounts.extend(page.accounts);
+                Ok(all_accounts)
+            })
+            .await
     }
 
     /// Gets transactions for a specific address with advanced filtering and sorting
@@ -0,0 +1,418 @@
+//! A typed, validated builder for `searchAssets` requests
+//!
+//! `SearchAssets` mirrors the wire format of `searchAssets`, so invalid combinations of its fields, such as page-based
+//! and cursor-based pagination in the same request, are only rejected by the server. `SearchAssetsQuery` takes typed
+//! pubkeys and timestamps, and `build` checks the pagination mode and condition type before compiling the query down to a
+//! `SearchAssets`
+//!
+//! # Example
+//! ```rust,no_run
+//! use chrono::{TimeZone, Utc};
+//! use helius::error::Result;
+//! use helius::search::SearchAssetsQuery;
+//! use helius::types::{AssetList, SearchConditionType};
+//! use helius::Helius;
+//! use solana_sdk::pubkey::Pubkey;
+//!
+//! async fn recent_collection_assets(helius: &Helius, owner: Pubkey, collection: Pubkey) -> Result<AssetList> {
+//!     let request = SearchAssetsQuery::new()
+//!         .owner(owner)
+//!         .collection(collection)
+//!         .created_after(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+//!         .condition_type(SearchConditionType::All)
+//!         .limit(100)
+//!         .page(1)
+//!         .build()?;
+//!     helius.rpc().search_assets(request).await
+//! }
+//! ```
+use crate::error::{HeliusError, Result};
+use crate::pagination::DAS_MAX_PAGE_SIZE;
+use crate::types::{
+    AssetSortBy, AssetSortDirection, AssetSorting, CreatedAtFilter, Interface, NotFilter, OwnershipModel, RoyaltyModel,
+    SearchAssets, SearchAssetsOptions, SearchConditionType, TokenType,
+};
+
+use chrono::{DateTime, Utc};
+use solana_sdk::pubkey::Pubkey;
+
+/// Builds a `SearchAssets` request from typed filters, validating it before it's sent
+///
+/// Filters are combined with `condition_type`, which defaults to matching all of them on the server. Pagination is
+/// either by page number (`page`), by cursor (`cursor`), or by asset ID range (`before` and `after`), and only one mode
+/// can be used per query
+#[derive(Debug, Default)]
+pub struct SearchAssetsQuery {
+    request: SearchAssets,
+}
+
+impl SearchAssetsQuery {
+    /// Creates a query with no filters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches assets owned by `owner`
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.request.owner_address = Some(owner.to_string());
+        self
+    }
+
+    /// Matches assets with the given ownership model
+    pub fn owner_type(mut self, owner_type: OwnershipModel) -> Self {
+        self.request.owner_type = Some(owner_type);
+        self
+    }
+
+    /// Matches assets that list `creator` as a creator
+    pub fn creator(mut self, creator: Pubkey) -> Self {
+        self.request.creator_address = Some(creator.to_string());
+        self
+    }
+
+    /// Matches assets that list `creator` as a creator, and whose creator entry is verified or not
+    pub fn creator_verified(mut self, creator: Pubkey, verified: bool) -> Self {
+        self.request.creator_address = Some(creator.to_string());
+        self.request.creator_verified = Some(verified);
+        self
+    }
+
+    /// Matches assets whose update authority is `authority`
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.request.authority_address = Some(authority.to_string());
+        self
+    }
+
+    /// Matches assets in the group `key` with the value `value`
+    pub fn group(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.request.grouping = Some((key.into(), value.into()));
+        self
+    }
+
+    /// Matches assets in the collection `collection`
+    pub fn collection(self, collection: Pubkey) -> Self {
+        self.group("collection", collection.to_string())
+    }
+
+    /// Matches assets in any of the given collections
+    pub fn collections(mut self, collections: impl IntoIterator<Item = Pubkey>) -> Self {
+        self.request.collections = Some(
+            collections
+                .into_iter()
+                .map(|collection| collection.to_string())
+                .collect(),
+        );
+        self
+    }
+
+    /// Matches assets delegated to `delegate`
+    pub fn delegate(mut self, delegate: Pubkey) -> Self {
+        self.request.delegate = Some(delegate.to_string());
+        self
+    }
+
+    /// Matches assets in the Merkle tree `tree`
+    pub fn tree(mut self, tree: Pubkey) -> Self {
+        self.request.tree = Some(tree.to_string());
+        self
+    }
+
+    /// Matches assets whose supply mint is `supply_mint`
+    pub fn supply_mint(mut self, supply_mint: Pubkey) -> Self {
+        self.request.supply_mint = Some(supply_mint.to_string());
+        self
+    }
+
+    /// Matches assets whose royalties go to `royalty_target`
+    pub fn royalty_target(mut self, royalty_target: Pubkey) -> Self {
+        self.request.royalty_target = Some(royalty_target.to_string());
+        self
+    }
+
+    /// Matches assets with the given royalty model
+    pub fn royalty_target_type(mut self, royalty_target_type: RoyaltyModel) -> Self {
+        self.request.royalty_target_type = Some(royalty_target_type);
+        self
+    }
+
+    /// Matches assets with the given royalty, in basis points
+    pub fn royalty_amount(mut self, royalty_amount: u32) -> Self {
+        self.request.royalty_amount = Some(royalty_amount);
+        self
+    }
+
+    /// Matches assets with the given interface
+    pub fn interface(mut self, interface: Interface) -> Self {
+        self.request.interface = Some(interface);
+        self
+    }
+
+    /// Matches assets of the given token type
+    pub fn token_type(mut self, token_type: TokenType) -> Self {
+        self.request.token_type = Some(token_type);
+        self
+    }
+
+    /// Matches assets whose name is `name`
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.request.name = Some(name.into());
+        self
+    }
+
+    /// Matches assets whose metadata URI is `json_uri`
+    pub fn json_uri(mut self, json_uri: impl Into<String>) -> Self {
+        self.request.json_uri = Some(json_uri.into());
+        self
+    }
+
+    /// Matches assets with the given supply
+    pub fn supply(mut self, supply: u64) -> Self {
+        self.request.supply = Some(supply);
+        self
+    }
+
+    /// Matches frozen or unfrozen assets
+    pub fn frozen(mut self, frozen: bool) -> Self {
+        self.request.frozen = Some(frozen);
+        self
+    }
+
+    /// Matches compressed or uncompressed assets
+    pub fn compressed(mut self, compressed: bool) -> Self {
+        self.request.compressed = Some(compressed);
+        self
+    }
+
+    /// Matches assets that can or can't be compressed
+    pub fn compressible(mut self, compressible: bool) -> Self {
+        self.request.compressible = Some(compressible);
+        self
+    }
+
+    /// Matches burnt or unburnt assets
+    pub fn burnt(mut self, burnt: bool) -> Self {
+        self.request.burnt = Some(burnt);
+        self
+    }
+
+    /// Matches assets that are or aren't collection NFTs
+    pub fn collection_nft(mut self, collection_nft: bool) -> Self {
+        self.request.collection_nft = Some(collection_nft);
+        self
+    }
+
+    /// Matches assets created after `after`
+    pub fn created_after(mut self, after: DateTime<Utc>) -> Self {
+        self.created_at().after = Some(after);
+        self
+    }
+
+    /// Matches assets created before `before`
+    pub fn created_before(mut self, before: DateTime<Utc>) -> Self {
+        self.created_at().before = Some(before);
+        self
+    }
+
+    /// Excludes assets owned by `owner`
+    pub fn exclude_owner(mut self, owner: Pubkey) -> Self {
+        self.not().owners.get_or_insert_with(Vec::new).push(owner.to_string());
+        self
+    }
+
+    /// Excludes assets that list `creator` as a creator
+    pub fn exclude_creator(mut self, creator: Pubkey) -> Self {
+        self.not()
+            .creators
+            .get_or_insert_with(Vec::new)
+            .push(creator.to_string());
+        self
+    }
+
+    /// Excludes assets whose update authority is `authority`
+    pub fn exclude_authority(mut self, authority: Pubkey) -> Self {
+        self.not()
+            .authorities
+            .get_or_insert_with(Vec::new)
+            .push(authority.to_string());
+        self
+    }
+
+    /// Excludes assets in the collection `collection`
+    pub fn exclude_collection(mut self, collection: Pubkey) -> Self {
+        self.not()
+            .collections
+            .get_or_insert_with(Vec::new)
+            .push(collection.to_string());
+        self
+    }
+
+    /// Sets whether assets must match all or any of the filters
+    pub fn condition_type(mut self, condition_type: SearchConditionType) -> Self {
+        self.request.condition_type = Some(condition_type);
+        self
+    }
+
+    /// Inverts the filters, matching the assets they would otherwise exclude
+    pub fn negate(mut self, negate: bool) -> Self {
+        self.request.negate = Some(negate);
+        self
+    }
+
+    /// Sorts the results
+    pub fn sort_by(mut self, sort_by: AssetSortBy, sort_direction: Option<AssetSortDirection>) -> Self {
+        self.request.sort_by = Some(AssetSorting {
+            sort_by,
+            sort_direction,
+        });
+        self
+    }
+
+    /// Sets the number of assets per page, up to `DAS_MAX_PAGE_SIZE`
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    /// Fetches the given page, starting from 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.request.page = Some(page);
+        self
+    }
+
+    /// Fetches the page after `cursor`, which is the `cursor` of the previous page
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Fetches assets whose IDs sort before `before`
+    pub fn before(mut self, before: Pubkey) -> Self {
+        self.request.before = Some(before.to_string());
+        self
+    }
+
+    /// Fetches assets whose IDs sort after `after`
+    pub fn after(mut self, after: Pubkey) -> Self {
+        self.request.after = Some(after.to_string());
+        self
+    }
+
+    /// Sets the display options of the results
+    pub fn options(mut self, options: SearchAssetsOptions) -> Self {
+        self.request.options = Some(options);
+        self
+    }
+
+    /// Validates the query and compiles it down to a `SearchAssets` request
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` if:
+    /// - More than one pagination mode is set, the page is 0, or the limit isn't between 1 and `DAS_MAX_PAGE_SIZE`
+    /// - Cursor or ID range pagination is combined with a sort other than by ID
+    /// - A condition type or negation is set without any filters to apply it to
+    /// - The created-at range is empty
+    pub fn build(self) -> Result<SearchAssets> {
+        let request: SearchAssets = self.request;
+
+        let range: bool = request.before.is_some() || request.after.is_some();
+        let modes: usize = [request.page.is_some(), request.cursor.is_some(), range]
+            .iter()
+            .filter(|mode| **mode)
+            .count();
+        if modes > 1 {
+            return Err(HeliusError::InvalidInput(
+                "Only one of page, cursor, or before/after pagination can be used".to_string(),
+            ));
+        }
+        if request.page == Some(0) {
+            return Err(HeliusError::InvalidInput("Pages start from 1".to_string()));
+        }
+        if let Some(limit) = request.limit.filter(|limit| !(1..=DAS_MAX_PAGE_SIZE).contains(limit)) {
+            return Err(HeliusError::InvalidInput(format!(
+                "Limit {limit} must be between 1 and {DAS_MAX_PAGE_SIZE}"
+            )));
+        }
+        if (request.cursor.is_some() || range)
+            && request
+                .sort_by
+                .as_ref()
+                .is_some_and(|sorting| sorting.sort_by != AssetSortBy::Id)
+        {
+            return Err(HeliusError::InvalidInput(
+                "Cursor and before/after pagination require sorting by ID".to_string(),
+            ));
+        }
+
+        if (request.condition_type.is_some() || request.negate.is_some()) && !has_filters(&request) {
+            return Err(HeliusError::InvalidInput(
+                "A condition type or negation needs at least one filter".to_string(),
+            ));
+        }
+        if let Some(CreatedAtFilter {
+            after: Some(after),
+            before: Some(before),
+        }) = &request.created_at
+        {
+            if after >= before {
+                return Err(HeliusError::InvalidInput(format!(
+                    "Created-at range is empty: {after} is not before {before}"
+                )));
+            }
+        }
+
+        Ok(request)
+    }
+
+    fn created_at(&mut self) -> &mut CreatedAtFilter {
+        self.request.created_at.get_or_insert(CreatedAtFilter {
+            after: None,
+            before: None,
+        })
+    }
+
+    fn not(&mut self) -> &mut NotFilter {
+        self.request.not.get_or_insert(NotFilter {
+            collections: None,
+            owners: None,
+            creators: None,
+            authorities: None,
+        })
+    }
+}
+
+impl TryFrom<SearchAssetsQuery> for SearchAssets {
+    type Error = HeliusError;
+
+    fn try_from(query: SearchAssetsQuery) -> Result<Self> {
+        query.build()
+    }
+}
+
+/// Whether the request has any filter for a condition type or negation to apply to
+fn has_filters(request: &SearchAssets) -> bool {
+    request.interface.is_some()
+        || request.owner_address.is_some()
+        || request.owner_type.is_some()
+        || request.creator_address.is_some()
+        || request.creator_verified.is_some()
+        || request.authority_address.is_some()
+        || request.grouping.is_some()
+        || request.delegate.is_some()
+        || request.frozen.is_some()
+        || request.supply.is_some()
+        || request.supply_mint.is_some()
+        || request.compressed.is_some()
+        || request.compressible.is_some()
+        || request.royalty_target_type.is_some()
+        || request.royalty_target.is_some()
+        || request.royalty_amount.is_some()
+        || request.burnt.is_some()
+        || request.json_uri.is_some()
+        || request.not.is_some()
+        || request.name.is_some()
+        || request.collections.is_some()
+        || request.token_type.is_some()
+        || request.created_at.is_some()
+        || request.tree.is_some()
+        || request.collection_nft.is_some()
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+
+use url::Url;
+
+/// The placeholder shown in place of a redacted value
+pub const REDACTED: &str = "REDACTED";
+
+/// A string that is never revealed through `Debug` output
+///
+/// `Secret` wraps sensitive values, such as API keys, so they are not accidentally written to logs. The underlying value
+/// is only accessible through `expose`
+///
+/// # Example
+/// ```rust
+/// use helius::secret::Secret;
+///
+/// let api_key: Secret = Secret::from("your_api_key");
+/// assert_eq!(format!("{:?}", api_key), "Secret(REDACTED)");
+/// assert_eq!(api_key.expose(), "your_api_key");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Creates a new `Secret` from the given value
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Returns the underlying value
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the underlying value is empty
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret({})", REDACTED)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&String> for Secret {
+    fn from(value: &String) -> Self {
+        Self::new(value.as_str())
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl PartialEq<str> for Secret {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Secret {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Replaces the value of any `api-key` query parameter in the given URL with a placeholder
+pub(crate) fn redact_url(url: &mut Url) {
+    if !url.query_pairs().any(|(key, _)| key == "api-key") {
+        return;
+    }
+
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            let value: String = if key == "api-key" {
+                REDACTED.to_string()
+            } else {
+                value.into_owned()
+            };
+            (key.into_owned(), value)
+        })
+        .collect();
+
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+}
@@ -0,0 +1,643 @@
+//! In-memory fakes of the service traits for unit tests
+//!
+//! The fakes never make HTTP requests. They are seeded with data up front and answer each call from memory, applying the
+//! common filters and pagination of the real APIs. Returned values are fresh copies of the seeded data
+#[cfg(any(feature = "das", feature = "enhanced-transactions"))]
+use crate::error::Result;
+#[cfg(any(feature = "das", feature = "enhanced-transactions"))]
+use serde::{de::DeserializeOwned, Serialize};
+
+#[cfg(feature = "das")]
+pub use self::das::FakeDas;
+#[cfg(feature = "enhanced-transactions")]
+pub use self::enhanced_transactions::FakeEnhancedTransactions;
+#[cfg(feature = "smart-transactions")]
+pub use self::transaction_sender::{FakeTransactionSender, SentTransaction};
+#[cfg(feature = "webhooks")]
+pub use self::webhooks::FakeWebhooks;
+
+/// Copies a value by round-tripping it through JSON, the same way the real clients receive it
+#[cfg(any(feature = "das", feature = "enhanced-transactions"))]
+fn duplicate<T: Serialize + DeserializeOwned>(value: &T) -> Result<T> {
+    Ok(serde_json::from_value(serde_json::to_value(value)?)?)
+}
+
+#[cfg(feature = "das")]
+mod das {
+    use std::collections::HashMap;
+
+    use super::duplicate;
+    use crate::error::Result;
+    use crate::services::DasApi;
+    use crate::types::{
+        Asset, AssetList, AssetProof, EditionsList, GetAsset, GetAssetBatch, GetAssetProof, GetAssetProofBatch,
+        GetAssetSignatures, GetAssetsByAuthority, GetAssetsByCreator, GetAssetsByGroup, GetAssetsByOwner,
+        GetNftEditions, GetTokenAccounts, SearchAssets, TokenAccount, TokenAccountsList, TransactionSignatureList,
+    };
+
+    const DEFAULT_LIMIT: u32 = 1000;
+
+    /// An in-memory fake of the DAS API
+    ///
+    /// Assets are matched by owner, authority, creator, and group. `search_assets` supports the owner, creator,
+    /// authority, grouping, delegate, frozen, compressed, and burnt filters, and ignores the others. Results are
+    /// paginated by `page` and `limit`, in the order the assets were added
+    ///
+    /// # Example
+    /// ```rust
+    /// use helius::services::fake::FakeDas;
+    /// use helius::services::DasApi;
+    /// use helius::types::GetAssetsByOwner;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let das = FakeDas::new();
+    /// let assets = das
+    ///     .get_assets_by_owner(GetAssetsByOwner {
+    ///         owner_address: "86xCnPeV69n6t3DnyGvkKobf9FdN2H9oiVDdaMpo2MMY".to_string(),
+    ///         page: 1,
+    ///         ..Default::default()
+    ///     })
+    ///     .await
+    ///     .unwrap();
+    /// assert!(assets.items.is_empty());
+    /// # });
+    /// ```
+    #[derive(Debug, Default)]
+    pub struct FakeDas {
+        assets: Vec<Asset>,
+        proofs: HashMap<String, AssetProof>,
+        signatures: HashMap<String, Vec<(String, String)>>,
+        token_accounts: Vec<TokenAccount>,
+        editions: HashMap<String, EditionsList>,
+    }
+
+    impl FakeDas {
+        /// Creates an empty `FakeDas`
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Adds an asset, replacing any asset with the same ID
+        pub fn with_asset(mut self, asset: Asset) -> Self {
+            self.assets.retain(|existing| existing.id != asset.id);
+            self.assets.push(asset);
+            self
+        }
+
+        /// Adds the merkle proof returned for the given asset ID
+        pub fn with_asset_proof(mut self, id: impl Into<String>, proof: AssetProof) -> Self {
+            self.proofs.insert(id.into(), proof);
+            self
+        }
+
+        /// Sets the `(signature, type)` pairs returned by `get_signatures_for_asset` for the given asset ID
+        pub fn with_signatures(mut self, id: impl Into<String>, signatures: Vec<(String, String)>) -> Self {
+            self.signatures.insert(id.into(), signatures);
+            self
+        }
+
+        /// Adds a token account, matched by its owner and mint
+        pub fn with_token_account(mut self, token_account: TokenAccount) -> Self {
+            self.token_accounts.push(token_account);
+            self
+        }
+
+        /// Sets the editions returned by `get_nft_editions` for the given master NFT mint
+        pub fn with_editions(mut self, mint: impl Into<String>, editions: EditionsList) -> Self {
+            self.editions.insert(mint.into(), editions);
+            self
+        }
+
+        fn find_asset(&self, id: &str) -> Result<Option<Asset>> {
+            self.assets
+                .iter()
+                .find(|asset| asset.id == id)
+                .map(duplicate)
+                .transpose()
+        }
+
+        fn find_proof(&self, id: &str) -> Result<Option<AssetProof>> {
+            self.proofs.get(id).map(duplicate).transpose()
+        }
+
+        fn list<F>(&self, page: Option<u32>, limit: Option<u32>, filter: F) -> Result<AssetList>
+        where
+            F: Fn(&Asset) -> bool,
+        {
+            let matches: Vec<&Asset> = self.assets.iter().filter(|asset| filter(asset)).collect();
+            let (page, limit, items) = paginate(&matches, page, limit);
+
+            Ok(AssetList {
+                grand_total: Some(matches.len() as u64),
+                total: items.len() as u32,
+                limit,
+                page: Some(page),
+                items: items.iter().map(|asset| duplicate(*asset)).collect::<Result<_>>()?,
+                ..Default::default()
+            })
+        }
+    }
+
+    /// Returns the 1-indexed page of items, along with the page and limit that were applied
+    fn paginate<T>(items: &[T], page: Option<u32>, limit: Option<u32>) -> (u32, u32, &[T]) {
+        let page: u32 = page.unwrap_or(1).max(1);
+        let limit: u32 = limit.unwrap_or(DEFAULT_LIMIT);
+        let start: usize = ((page - 1) as usize).saturating_mul(limit as usize).min(items.len());
+        let end: usize = start.saturating_add(limit as usize).min(items.len());
+
+        (page, limit, &items[start..end])
+    }
+
+    fn has_authority(asset: &Asset, address: &str) -> bool {
+        asset
+            .authorities
+            .iter()
+            .flatten()
+            .any(|authority| authority.address == address)
+    }
+
+    fn has_creator(asset: &Asset, address: &str, verified: Option<bool>) -> bool {
+        asset
+            .creators
+            .iter()
+            .flatten()
+            .any(|creator| creator.address == address && verified.is_none_or(|verified| creator.verified == verified))
+    }
+
+    fn in_group(asset: &Asset, key: &str, value: &str) -> bool {
+        asset
+            .grouping
+            .iter()
+            .flatten()
+            .any(|group| group.group_key == key && group.group_value.as_deref() == Some(value))
+    }
+
+    fn matches_search(asset: &Asset, request: &SearchAssets) -> bool {
+        request
+            .owner_address
+            .as_ref()
+            .is_none_or(|owner| &asset.ownership.owner == owner)
+            && request
+                .creator_address
+                .as_ref()
+                .is_none_or(|creator| has_creator(asset, creator, request.creator_verified))
+            && request
+                .authority_address
+                .as_ref()
+                .is_none_or(|authority| has_authority(asset, authority))
+            && request
+                .grouping
+                .as_ref()
+                .is_none_or(|(key, value)| in_group(asset, key, value))
+            && request
+                .delegate
+                .as_ref()
+                .is_none_or(|delegate| asset.ownership.delegate.as_ref() == Some(delegate))
+            && request.frozen.is_none_or(|frozen| asset.ownership.frozen == frozen)
+            && request.compressed.is_none_or(|compressed| {
+                asset
+                    .compression
+                    .as_ref()
+                    .is_some_and(|compression| compression.compressed)
+                    == compressed
+            })
+            && request.burnt.is_none_or(|burnt| asset.burnt == burnt)
+    }
+
+    impl DasApi for FakeDas {
+        async fn get_asset(&self, request: GetAsset) -> Result<Option<Asset>> {
+            self.find_asset(&request.id)
+        }
+
+        async fn get_asset_batch(&self, request: GetAssetBatch) -> Result<Vec<Option<Asset>>> {
+            request.ids.iter().map(|id| self.find_asset(id)).collect()
+        }
+
+        async fn get_asset_proof(&self, request: GetAssetProof) -> Result<Option<AssetProof>> {
+            self.find_proof(&request.id)
+        }
+
+        async fn get_asset_proof_batch(
+            &self,
+            request: GetAssetProofBatch,
+        ) -> Result<HashMap<String, Option<AssetProof>>> {
+            request
+                .ids
+                .into_iter()
+                .map(|id| Ok((id.clone(), self.find_proof(&id)?)))
+                .collect()
+        }
+
+        async fn get_assets_by_authority(&self, request: GetAssetsByAuthority) -> Result<AssetList> {
+            self.list(Some(request.page), request.limit, |asset| {
+                has_authority(asset, &request.authority_address)
+            })
+        }
+
+        async fn get_assets_by_creator(&self, request: GetAssetsByCreator) -> Result<AssetList> {
+            let verified: Option<bool> = request.only_verified.filter(|only_verified| *only_verified);
+            self.list(request.page, request.limit, |asset| {
+                has_creator(asset, &request.creator_address, verified)
+            })
+        }
+
+        async fn get_assets_by_group(&self, request: GetAssetsByGroup) -> Result<AssetList> {
+            self.list(request.page, request.limit, |asset| {
+                in_group(asset, &request.group_key, &request.group_value)
+            })
+        }
+
+        async fn get_assets_by_owner(&self, request: GetAssetsByOwner) -> Result<AssetList> {
+            let limit: Option<u32> = request.limit.map(|limit| limit.max(0) as u32);
+            self.list(Some(request.page), limit, |asset| {
+                asset.ownership.owner == request.owner_address
+            })
+        }
+
+        async fn search_assets(&self, request: SearchAssets) -> Result<AssetList> {
+            self.list(request.page, request.limit, |asset| matches_search(asset, &request))
+        }
+
+        async fn get_signatures_for_asset(&self, request: GetAssetSignatures) -> Result<TransactionSignatureList> {
+            let signatures: &[(String, String)] = request
+                .id
+                .as_ref()
+                .and_then(|id| self.signatures.get(id))
+                .map_or(&[], Vec::as_slice);
+            let (page, limit, items) = paginate(signatures, request.page, request.limit);
+
+            Ok(TransactionSignatureList {
+                total: items.len() as u32,
+                limit,
+                page: Some(page),
+                items: items.to_vec(),
+                ..Default::default()
+            })
+        }
+
+        async fn get_token_accounts(&self, request: GetTokenAccounts) -> Result<TokenAccountsList> {
+            let matches: Vec<&TokenAccount> = self
+                .token_accounts
+                .iter()
+                .filter(|account| request.owner.is_none() || account.owner == request.owner)
+                .filter(|account| request.mint.is_none() || account.mint == request.mint)
+                .collect();
+            let (page, limit, items) = paginate(&matches, request.page, request.limit);
+
+            Ok(TokenAccountsList {
+                total: items.len() as u32,
+                limit,
+                page: Some(page),
+                token_accounts: items.iter().map(|account| duplicate(*account)).collect::<Result<_>>()?,
+                ..Default::default()
+            })
+        }
+
+        async fn get_nft_editions(&self, request: GetNftEditions) -> Result<EditionsList> {
+            match request.mint.as_ref().and_then(|mint| self.editions.get(mint)) {
+                Some(editions) => duplicate(editions),
+                None => Ok(EditionsList {
+                    limit: request.limit.unwrap_or(DEFAULT_LIMIT),
+                    page: Some(request.page.unwrap_or(1)),
+                    master_edition_address: request.mint.unwrap_or_default(),
+                    ..Default::default()
+                }),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "webhooks")]
+mod webhooks {
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    use crate::error::{HeliusError, Result};
+    use crate::services::WebhookApi;
+    use crate::types::{CreateWebhookRequest, EditWebhookRequest, Webhook};
+
+    /// An in-memory fake of the webhook API
+    ///
+    /// Webhooks created through the fake are assigned sequential IDs (e.g., `fake-webhook-1`). Looking up, editing, or
+    /// deleting an unknown webhook returns `HeliusError::NotFound`, as the real API does
+    #[derive(Debug, Default)]
+    pub struct FakeWebhooks {
+        state: Mutex<WebhookState>,
+    }
+
+    #[derive(Debug, Default)]
+    struct WebhookState {
+        next_id: u64,
+        webhooks: BTreeMap<String, Webhook>,
+    }
+
+    impl FakeWebhooks {
+        /// Creates a `FakeWebhooks` with no webhooks
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Adds an existing webhook, keyed by its `webhook_id`
+        pub fn with_webhook(self, webhook: Webhook) -> Self {
+            self.lock().webhooks.insert(webhook.webhook_id.clone(), webhook);
+            self
+        }
+
+        /// Returns a snapshot of the stored webhooks, ordered by ID
+        pub fn webhooks(&self) -> Vec<Webhook> {
+            self.lock().webhooks.values().cloned().collect()
+        }
+
+        fn lock(&self) -> std::sync::MutexGuard<'_, WebhookState> {
+            self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+        }
+
+        fn update<F>(&self, webhook_id: &str, update: F) -> Result<Webhook>
+        where
+            F: FnOnce(&mut Webhook),
+        {
+            let mut state = self.lock();
+            let webhook: &mut Webhook = state
+                .webhooks
+                .get_mut(webhook_id)
+                .ok_or_else(|| not_found(webhook_id))?;
+            update(webhook);
+            Ok(webhook.clone())
+        }
+    }
+
+    fn not_found(webhook_id: &str) -> HeliusError {
+        HeliusError::NotFound {
+            text: format!("Webhook {} not found", webhook_id),
+        }
+    }
+
+    impl WebhookApi for FakeWebhooks {
+        async fn create_webhook(&self, request: CreateWebhookRequest) -> Result<Webhook> {
+            let mut state = self.lock();
+            state.next_id += 1;
+
+            let webhook: Webhook = Webhook {
+                webhook_id: format!("fake-webhook-{}", state.next_id),
+                webhook_url: request.webhook_url,
+                transaction_types: request.transaction_types,
+                account_addresses: request.account_addresses,
+                webhook_type: request.webhook_type,
+                auth_header: request.auth_header,
+                txn_status: request.txn_status,
+                encoding: request.encoding,
+                ..Default::default()
+            };
+            state.webhooks.insert(webhook.webhook_id.clone(), webhook.clone());
+            Ok(webhook)
+        }
+
+        async fn edit_webhook(&self, request: EditWebhookRequest) -> Result<Webhook> {
+            self.update(&request.webhook_id, |webhook| {
+                webhook.webhook_url = request.webhook_url;
+                webhook.transaction_types = request.transaction_types;
+                webhook.account_addresses = request.account_addresses;
+                webhook.webhook_type = request.webhook_type;
+                webhook.auth_header = request.auth_header;
+                webhook.txn_status = request.txn_status;
+                webhook.encoding = request.encoding;
+            })
+        }
+
+        async fn append_addresses_to_webhook(&self, webhook_id: &str, new_addresses: &[String]) -> Result<Webhook> {
+            self.update(webhook_id, |webhook| {
+                webhook.account_addresses.extend_from_slice(new_addresses)
+            })
+        }
+
+        async fn remove_addresses_from_webhook(
+            &self,
+            webhook_id: &str,
+            addresses_to_remove: &[String],
+        ) -> Result<Webhook> {
+            self.update(webhook_id, |webhook| {
+                webhook
+                    .account_addresses
+                    .retain(|address| !addresses_to_remove.contains(address))
+            })
+        }
+
+        async fn get_webhook_by_id(&self, webhook_id: &str) -> Result<Webhook> {
+            self.lock()
+                .webhooks
+                .get(webhook_id)
+                .cloned()
+                .ok_or_else(|| not_found(webhook_id))
+        }
+
+        async fn get_all_webhooks(&self) -> Result<Vec<Webhook>> {
+            Ok(self.webhooks())
+        }
+
+        async fn delete_webhook(&self, webhook_id: &str) -> Result<()> {
+            self.lock()
+                .webhooks
+                .remove(webhook_id)
+                .map(|_| ())
+                .ok_or_else(|| not_found(webhook_id))
+        }
+    }
+}
+
+#[cfg(feature = "enhanced-transactions")]
+mod enhanced_transactions {
+    use super::duplicate;
+    use crate::error::Result;
+    use crate::services::EnhancedTransactionsApi;
+    use crate::types::{EnhancedTransaction, ParseTransactionsRequest, ParsedTransactionHistoryRequest};
+
+    const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+    /// An in-memory fake of the enhanced transactions API
+    ///
+    /// Transactions are treated as newest first, in the order they were added. An address's history includes every
+    /// transaction it paid the fee for or appears in the account data of, and supports the `before`, `until`, `source`,
+    /// `transaction_type`, and `limit` filters
+    #[derive(Debug, Default)]
+    pub struct FakeEnhancedTransactions {
+        transactions: Vec<EnhancedTransaction>,
+    }
+
+    impl FakeEnhancedTransactions {
+        /// Creates a `FakeEnhancedTransactions` with no transactions
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Adds a parsed transaction, older than every transaction added before it
+        pub fn with_transaction(mut self, transaction: EnhancedTransaction) -> Self {
+            self.transactions.push(transaction);
+            self
+        }
+    }
+
+    fn involves(transaction: &EnhancedTransaction, address: &str) -> bool {
+        transaction.fee_payer == address || transaction.account_data.iter().any(|data| data.account == address)
+    }
+
+    impl EnhancedTransactionsApi for FakeEnhancedTransactions {
+        async fn parse_transactions(&self, request: ParseTransactionsRequest) -> Result<Vec<EnhancedTransaction>> {
+            request
+                .transactions
+                .iter()
+                .filter_map(|signature| {
+                    self.transactions
+                        .iter()
+                        .find(|transaction| &transaction.signature == signature)
+                })
+                .map(duplicate)
+                .collect()
+        }
+
+        async fn parsed_transaction_history(
+            &self,
+            request: ParsedTransactionHistoryRequest,
+        ) -> Result<Vec<EnhancedTransaction>> {
+            let history = self
+                .transactions
+                .iter()
+                .filter(|transaction| involves(transaction, &request.address));
+
+            // `before` starts the history after the given signature, and `until` ends it before the given signature
+            let history: Vec<&EnhancedTransaction> = match &request.before {
+                Some(before) => history
+                    .skip_while(|transaction| &transaction.signature != before)
+                    .skip(1)
+                    .collect(),
+                None => history.collect(),
+            };
+
+            history
+                .into_iter()
+                .take_while(|transaction| Some(&transaction.signature) != request.until.as_ref())
+                .filter(|transaction| {
+                    request
+                        .source
+                        .as_ref()
+                        .is_none_or(|source| &transaction.source == source)
+                })
+                .filter(|transaction| {
+                    request
+                        .transaction_type
+                        .as_ref()
+                        .is_none_or(|transaction_type| &transaction.transaction_type == transaction_type)
+                })
+                .take(request.limit.map_or(DEFAULT_HISTORY_LIMIT, |limit| limit as usize))
+                .map(duplicate)
+                .collect()
+        }
+    }
+}
+
+#[cfg(feature = "smart-transactions")]
+mod transaction_sender {
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
+    use crate::error::{HeliusError, Result};
+    use crate::services::TransactionSender;
+    use crate::types::{CreateSmartTransactionSeedConfig, SmartTransactionConfig, Timeout};
+
+    use solana_client::rpc_config::RpcSendTransactionConfig;
+    use solana_sdk::{
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::{keypair_from_seed, Keypair, Signature},
+        signer::Signer,
+    };
+
+    /// A transaction sent through a `FakeTransactionSender`
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SentTransaction {
+        /// The fee payer of the transaction
+        pub fee_payer: Pubkey,
+        /// The instructions of the transaction, before any compute budget instructions are added
+        pub instructions: Vec<Instruction>,
+    }
+
+    /// An in-memory fake that records smart transactions instead of sending them
+    ///
+    /// Each send returns the next queued outcome, or a unique signature if none is queued. Sends are validated the same
+    /// way as the real client, so a config without signers fails with `HeliusError::InvalidInput`
+    #[derive(Debug, Default)]
+    pub struct FakeTransactionSender {
+        outcomes: Mutex<VecDeque<Result<Signature>>>,
+        sent: Mutex<Vec<SentTransaction>>,
+    }
+
+    impl FakeTransactionSender {
+        /// Creates a `FakeTransactionSender` that succeeds with unique signatures
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Queues the outcome of a future send (e.g., an error to simulate a failed confirmation)
+        pub fn push_outcome(&self, outcome: Result<Signature>) {
+            self.outcomes
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .push_back(outcome);
+        }
+
+        /// Returns the transactions sent so far, in order
+        pub fn sent(&self) -> Vec<SentTransaction> {
+            self.sent
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .clone()
+        }
+
+        fn send(&self, fee_payer: Pubkey, instructions: Vec<Instruction>) -> Result<Signature> {
+            self.sent
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .push(SentTransaction {
+                    fee_payer,
+                    instructions,
+                });
+
+            self.outcomes
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .pop_front()
+                .unwrap_or_else(|| Ok(Signature::new_unique()))
+        }
+    }
+
+    impl TransactionSender for FakeTransactionSender {
+        async fn send_smart_transaction(&self, config: SmartTransactionConfig) -> Result<Signature> {
+            let create_config = config.create_config;
+            if create_config.signers.is_empty() {
+                return Err(HeliusError::InvalidInput(
+                    "The fee payer must sign the transaction".to_string(),
+                ));
+            }
+
+            let fee_payer: Pubkey = create_config
+                .fee_payer
+                .as_ref()
+                .map_or(create_config.signers[0].pubkey(), |signer| signer.pubkey());
+            self.send(fee_payer, create_config.instructions)
+        }
+
+        async fn send_smart_transaction_with_seeds(
+            &self,
+            create_config: CreateSmartTransactionSeedConfig,
+            _send_options: Option<RpcSendTransactionConfig>,
+            _timeout: Option<Timeout>,
+        ) -> Result<Signature> {
+            let seed: [u8; 32] = create_config
+                .fee_payer_seed
+                .or_else(|| create_config.signer_seeds.first().copied())
+                .ok_or_else(|| HeliusError::InvalidInput("At least one signer seed must be provided".to_string()))?;
+            let fee_payer: Keypair = keypair_from_seed(&seed).map_err(|e| HeliusError::InvalidInput(e.to_string()))?;
+
+            self.send(fee_payer.pubkey(), create_config.instructions)
+        }
+    }
+}
@@ -0,0 +1,354 @@
+//! Service traits for the main Helius APIs
+//!
+//! Each trait describes one API and is implemented by the HTTP-backed clients (`RpcClient` and `Helius`) as well as by
+//! the in-memory fakes in [`fake`]. Code that depends on these traits instead of the concrete clients can be unit tested
+//! without any HTTP by swapping in a fake
+//!
+//! # Example
+//! ```rust
+//! use helius::error::Result;
+//! use helius::services::fake::FakeDas;
+//! use helius::services::DasApi;
+//! use helius::types::GetAsset;
+//!
+//! async fn is_burnt<D: DasApi>(das: &D, id: &str) -> Result<bool> {
+//!     let asset = das
+//!         .get_asset(GetAsset {
+//!             id: id.to_string(),
+//!             display_options: None,
+//!         })
+//!         .await?;
+//!     Ok(asset.is_some_and(|asset| asset.burnt))
+//! }
+//!
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! assert!(!is_burnt(&FakeDas::new(), "F9Lw3ki3hJ7PF9HQXsBzoY8GyE6sPoEZZdXJBsTTD2rk").await.unwrap());
+//! # });
+//! ```
+pub mod fake;
+
+#[cfg(feature = "das")]
+use std::collections::HashMap;
+#[cfg(any(
+    feature = "das",
+    feature = "webhooks",
+    feature = "enhanced-transactions",
+    feature = "smart-transactions"
+))]
+use std::future::Future;
+
+#[cfg(any(
+    feature = "das",
+    feature = "webhooks",
+    feature = "enhanced-transactions",
+    feature = "smart-transactions"
+))]
+use crate::error::Result;
+#[cfg(feature = "das")]
+use crate::rpc_client::RpcClient;
+#[cfg(feature = "das")]
+use crate::types::{
+    Asset, AssetList, AssetProof, EditionsList, GetAsset, GetAssetBatch, GetAssetProof, GetAssetProofBatch,
+    GetAssetSignatures, GetAssetsByAuthority, GetAssetsByCreator, GetAssetsByGroup, GetAssetsByOwner, GetNftEditions,
+    GetTokenAccounts, SearchAssets, TokenAccountsList, TransactionSignatureList,
+};
+#[cfg(feature = "smart-transactions")]
+use crate::types::{CreateSmartTransactionSeedConfig, SmartTransactionConfig, Timeout};
+#[cfg(feature = "webhooks")]
+use crate::types::{CreateWebhookRequest, EditWebhookRequest, Webhook};
+#[cfg(feature = "enhanced-transactions")]
+use crate::types::{EnhancedTransaction, ParseTransactionsRequest, ParsedTransactionHistoryRequest};
+#[cfg(any(
+    feature = "das",
+    feature = "webhooks",
+    feature = "enhanced-transactions",
+    feature = "smart-transactions"
+))]
+use crate::Helius;
+
+#[cfg(feature = "smart-transactions")]
+use solana_client::rpc_config::RpcSendTransactionConfig;
+#[cfg(feature = "smart-transactions")]
+use solana_sdk::signature::Signature;
+
+/// The Digital Asset Standard (DAS) API
+///
+/// Implemented by `RpcClient`, `Helius`, and `FakeDas`
+#[cfg(feature = "das")]
+pub trait DasApi {
+    /// Gets an asset by its ID
+    fn get_asset(&self, request: GetAsset) -> impl Future<Output = Result<Option<Asset>>> + Send;
+
+    /// Gets multiple assets by their ID
+    fn get_asset_batch(&self, request: GetAssetBatch) -> impl Future<Output = Result<Vec<Option<Asset>>>> + Send;
+
+    /// Gets a merkle proof for a compressed asset by its ID
+    fn get_asset_proof(&self, request: GetAssetProof) -> impl Future<Output = Result<Option<AssetProof>>> + Send;
+
+    /// Gets multiple asset proofs by their IDs
+    fn get_asset_proof_batch(
+        &self,
+        request: GetAssetProofBatch,
+    ) -> impl Future<Output = Result<HashMap<String, Option<AssetProof>>>> + Send;
+
+    /// Gets a list of assets of a given authority
+    fn get_assets_by_authority(&self, request: GetAssetsByAuthority) -> impl Future<Output = Result<AssetList>> + Send;
+
+    /// Gets a list of assets of a given creator
+    fn get_assets_by_creator(&self, request: GetAssetsByCreator) -> impl Future<Output = Result<AssetList>> + Send;
+
+    /// Gets a list of assets by a group key and value
+    fn get_assets_by_group(&self, request: GetAssetsByGroup) -> impl Future<Output = Result<AssetList>> + Send;
+
+    /// Gets a list of assets owned by a given address
+    fn get_assets_by_owner(&self, request: GetAssetsByOwner) -> impl Future<Output = Result<AssetList>> + Send;
+
+    /// Gets assets based on the custom search criteria passed in
+    fn search_assets(&self, request: SearchAssets) -> impl Future<Output = Result<AssetList>> + Send;
+
+    /// Gets transaction signatures for a given asset
+    fn get_signatures_for_asset(
+        &self,
+        request: GetAssetSignatures,
+    ) -> impl Future<Output = Result<TransactionSignatureList>> + Send;
+
+    /// Gets information about all token accounts for a specific mint or owner
+    fn get_token_accounts(&self, request: GetTokenAccounts) -> impl Future<Output = Result<TokenAccountsList>> + Send;
+
+    /// Gets all the NFT editions associated with a specific master NFT
+    fn get_nft_editions(&self, request: GetNftEditions) -> impl Future<Output = Result<EditionsList>> + Send;
+}
+
+/// The webhook API
+///
+/// Implemented by `Helius` and `FakeWebhooks`
+#[cfg(feature = "webhooks")]
+pub trait WebhookApi {
+    /// Creates a webhook given account addresses
+    fn create_webhook(&self, request: CreateWebhookRequest) -> impl Future<Output = Result<Webhook>> + Send;
+
+    /// Edits a webhook
+    fn edit_webhook(&self, request: EditWebhookRequest) -> impl Future<Output = Result<Webhook>> + Send;
+
+    /// Appends a set of addresses to a given webhook
+    fn append_addresses_to_webhook(
+        &self,
+        webhook_id: &str,
+        new_addresses: &[String],
+    ) -> impl Future<Output = Result<Webhook>> + Send;
+
+    /// Removes a list of addresses from an existing webhook by its ID
+    fn remove_addresses_from_webhook(
+        &self,
+        webhook_id: &str,
+        addresses_to_remove: &[String],
+    ) -> impl Future<Output = Result<Webhook>> + Send;
+
+    /// Gets a webhook config given a webhook ID
+    fn get_webhook_by_id(&self, webhook_id: &str) -> impl Future<Output = Result<Webhook>> + Send;
+
+    /// Retrieves all Helius webhooks
+    fn get_all_webhooks(&self) -> impl Future<Output = Result<Vec<Webhook>>> + Send;
+
+    /// Deletes a given Helius webhook permanently
+    fn delete_webhook(&self, webhook_id: &str) -> impl Future<Output = Result<()>> + Send;
+}
+
+/// The enhanced transactions API
+///
+/// Implemented by `Helius` and `FakeEnhancedTransactions`
+#[cfg(feature = "enhanced-transactions")]
+pub trait EnhancedTransactionsApi {
+    /// Parses transactions given an array of transaction IDs
+    fn parse_transactions(
+        &self,
+        request: ParseTransactionsRequest,
+    ) -> impl Future<Output = Result<Vec<EnhancedTransaction>>> + Send;
+
+    /// Retrieves a parsed transaction history for a specific address
+    fn parsed_transaction_history(
+        &self,
+        request: ParsedTransactionHistoryRequest,
+    ) -> impl Future<Output = Result<Vec<EnhancedTransaction>>> + Send;
+}
+
+/// Sends smart transactions
+///
+/// Implemented by `Helius` and `FakeTransactionSender`. Unlike the other service traits, the returned futures are not
+/// required to be `Send` since a `SmartTransactionConfig` holds its signers as `Arc<dyn Signer>`
+#[cfg(feature = "smart-transactions")]
+pub trait TransactionSender {
+    /// Builds and sends an optimized transaction, and handles its confirmation status
+    fn send_smart_transaction(&self, config: SmartTransactionConfig) -> impl Future<Output = Result<Signature>>;
+
+    /// Sends a smart transaction built from the given signer seeds, and handles its confirmation status
+    fn send_smart_transaction_with_seeds(
+        &self,
+        create_config: CreateSmartTransactionSeedConfig,
+        send_options: Option<RpcSendTransactionConfig>,
+        timeout: Option<Timeout>,
+    ) -> impl Future<Output = Result<Signature>>;
+}
+
+#[cfg(feature = "das")]
+impl DasApi for RpcClient {
+    async fn get_asset(&self, request: GetAsset) -> Result<Option<Asset>> {
+        RpcClient::get_asset(self, request).await
+    }
+
+    async fn get_asset_batch(&self, request: GetAssetBatch) -> Result<Vec<Option<Asset>>> {
+        RpcClient::get_asset_batch(self, request).await
+    }
+
+    async fn get_asset_proof(&self, request: GetAssetProof) -> Result<Option<AssetProof>> {
+        RpcClient::get_asset_proof(self, request).await
+    }
+
+    async fn get_asset_proof_batch(&self, request: GetAssetProofBatch) -> Result<HashMap<String, Option<AssetProof>>> {
+        RpcClient::get_asset_proof_batch(self, request).await
+    }
+
+    async fn get_assets_by_authority(&self, request: GetAssetsByAuthority) -> Result<AssetList> {
+        RpcClient::get_assets_by_authority(self, request).await
+    }
+
+    async fn get_assets_by_creator(&self, request: GetAssetsByCreator) -> Result<AssetList> {
+        RpcClient::get_assets_by_creator(self, request).await
+    }
+
+    async fn get_assets_by_group(&self, request: GetAssetsByGroup) -> Result<AssetList> {
+        RpcClient::get_assets_by_group(self, request).await
+    }
+
+    async fn get_assets_by_owner(&self, request: GetAssetsByOwner) -> Result<AssetList> {
+        RpcClient::get_assets_by_owner(self, request).await
+    }
+
+    async fn search_assets(&self, request: SearchAssets) -> Result<AssetList> {
+        RpcClient::search_assets(self, request).await
+    }
+
+    async fn get_signatures_for_asset(&self, request: GetAssetSignatures) -> Result<TransactionSignatureList> {
+        RpcClient::get_signatures_for_asset(self, request).await
+    }
+
+    async fn get_token_accounts(&self, request: GetTokenAccounts) -> Result<TokenAccountsList> {
+        RpcClient::get_token_accounts(self, request).await
+    }
+
+    async fn get_nft_editions(&self, request: GetNftEditions) -> Result<EditionsList> {
+        RpcClient::get_nft_editions(self, request).await
+    }
+}
+
+#[cfg(feature = "das")]
+impl DasApi for Helius {
+    async fn get_asset(&self, request: GetAsset) -> Result<Option<Asset>> {
+        self.rpc_client.get_asset(request).await
+    }
+
+    async fn get_asset_batch(&self, request: GetAssetBatch) -> Result<Vec<Option<Asset>>> {
+        self.rpc_client.get_asset_batch(request).await
+    }
+
+    async fn get_asset_proof(&self, request: GetAssetProof) -> Result<Option<AssetProof>> {
+        self.rpc_client.get_asset_proof(request).await
+    }
+
+    async fn get_asset_proof_batch(&self, request: GetAssetProofBatch) -> Result<HashMap<String, Option<AssetProof>>> {
+        self.rpc_client.get_asset_proof_batch(request).await
+    }
+
+    async fn get_assets_by_authority(&self, request: GetAssetsByAuthority) -> Result<AssetList> {
+        self.rpc_client.get_assets_by_authority(request).await
+    }
+
+    async fn get_assets_by_creator(&self, request: GetAssetsByCreator) -> Result<AssetList> {
+        self.rpc_client.get_assets_by_creator(request).await
+    }
+
+    async fn get_assets_by_group(&self, request: GetAssetsByGroup) -> Result<AssetList> {
+        self.rpc_client.get_assets_by_group(request).await
+    }
+
+    async fn get_assets_by_owner(&self, request: GetAssetsByOwner) -> Result<AssetList> {
+        self.rpc_client.get_assets_by_owner(request).await
+    }
+
+    async fn search_assets(&self, request: SearchAssets) -> Result<AssetList> {
+        self.rpc_client.search_assets(request).await
+    }
+
+    async fn get_signatures_for_asset(&self, request: GetAssetSignatures) -> Result<TransactionSignatureList> {
+        self.rpc_client.get_signatures_for_asset(request).await
+    }
+
+    async fn get_token_accounts(&self, request: GetTokenAccounts) -> Result<TokenAccountsList> {
+        self.rpc_client.get_token_accounts(request).await
+    }
+
+    async fn get_nft_editions(&self, request: GetNftEditions) -> Result<EditionsList> {
+        self.rpc_client.get_nft_editions(request).await
+    }
+}
+
+#[cfg(feature = "webhooks")]
+impl WebhookApi for Helius {
+    async fn create_webhook(&self, request: CreateWebhookRequest) -> Result<Webhook> {
+        Helius::create_webhook(self, request).await
+    }
+
+    async fn edit_webhook(&self, request: EditWebhookRequest) -> Result<Webhook> {
+        Helius::edit_webhook(self, request).await
+    }
+
+    async fn append_addresses_to_webhook(&self, webhook_id: &str, new_addresses: &[String]) -> Result<Webhook> {
+        Helius::append_addresses_to_webhook(self, webhook_id, new_addresses).await
+    }
+
+    async fn remove_addresses_from_webhook(&self, webhook_id: &str, addresses_to_remove: &[String]) -> Result<Webhook> {
+        Helius::remove_addresses_from_webhook(self, webhook_id, addresses_to_remove).await
+    }
+
+    async fn get_webhook_by_id(&self, webhook_id: &str) -> Result<Webhook> {
+        Helius::get_webhook_by_id(self, webhook_id).await
+    }
+
+    async fn get_all_webhooks(&self) -> Result<Vec<Webhook>> {
+        Helius::get_all_webhooks(self).await
+    }
+
+    async fn delete_webhook(&self, webhook_id: &str) -> Result<()> {
+        Helius::delete_webhook(self, webhook_id).await
+    }
+}
+
+#[cfg(feature = "enhanced-transactions")]
+impl EnhancedTransactionsApi for Helius {
+    async fn parse_transactions(&self, request: ParseTransactionsRequest) -> Result<Vec<EnhancedTransaction>> {
+        Helius::parse_transactions(self, request).await
+    }
+
+    async fn parsed_transaction_history(
+        &self,
+        request: ParsedTransactionHistoryRequest,
+    ) -> Result<Vec<EnhancedTransaction>> {
+        Helius::parsed_transaction_history(self, request).await
+    }
+}
+
+#[cfg(feature = "smart-transactions")]
+impl TransactionSender for Helius {
+    async fn send_smart_transaction(&self, config: SmartTransactionConfig) -> Result<Signature> {
+        Helius::send_smart_transaction(self, config).await
+    }
+
+    async fn send_smart_transaction_with_seeds(
+        &self,
+        create_config: CreateSmartTransactionSeedConfig,
+        send_options: Option<RpcSendTransactionConfig>,
+        timeout: Option<Timeout>,
+    ) -> Result<Signature> {
+        Helius::send_smart_transaction_with_seeds(self, create_config, send_options, timeout).await
+    }
+}
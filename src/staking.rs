@@ -55,8 +55,9 @@ impl Helius {
     /// fails
     pub async fn create_stake_transaction(&self, owner: Pubkey, amount_sol: f64) -> Result<(String, Pubkey)> {
         let rent_exempt: u64 = self
-            .connection()
-            .get_minimum_balance_for_rent_exemption(StakeStateV2::size_of())?;
+            .async_connection()?
+            .get_minimum_balance_for_rent_exemption(StakeStateV2::size_of())
+            .await?;
         let lamports: u64 = ((amount_sol * LAMPORTS_PER_SOL as f64).round() as u64) + rent_exempt;
 
         let stake_account: Keypair = Keypair::new();
@@ -77,7 +78,7 @@ impl Helius {
         let delegate_ix: Instruction =
             stake_instruction::delegate_stake(&stake_account.pubkey(), &owner, &HELIUS_VALIDATOR_PUBKEY);
 
-        let blockhash: Hash = self.connection().get_latest_blockhash()?;
+        let blockhash: Hash = self.async_connection()?.get_latest_blockhash().await?;
         let mut instructions: Vec<Instruction> = create_ix;
         instructions.push(delegate_ix);
 
@@ -112,7 +113,7 @@ impl Helius {
     pub async fn create_unstake_transaction(&self, owner: Pubkey, stake_account: Pubkey) -> Result<String> {
         let deactivate_ix: Instruction = stake_instruction::deactivate_stake(&stake_account, &owner);
 
-        let blockhash: Hash = self.connection().get_latest_blockhash()?;
+        let blockhash: Hash = self.async_connection()?.get_latest_blockhash().await?;
 
         let mut tx: Transaction = Transaction::new_with_payer(&[deactivate_ix], Some(&owner));
 
@@ -159,7 +160,7 @@ impl Helius {
             None, // Custodian
         );
 
-        let blockhash: Hash = self.connection().get_latest_blockhash()?;
+        let blockhash: Hash = self.async_connection()?.get_latest_blockhash().await?;
 
         let mut tx: Transaction = Transaction::new_with_payer(&[withdraw_ix], Some(&owner));
         tx.message.recent_blockhash = blockhash;
@@ -194,8 +195,9 @@ impl Helius {
     /// Returns an error if fetching the rent-exempt minimum balance fails
     pub async fn get_stake_instructions(&self, owner: Pubkey, amount_sol: f64) -> Result<(Vec<Instruction>, Keypair)> {
         let rent_exempt: u64 = self
-            .connection()
-            .get_minimum_balance_for_rent_exemption(StakeStateV2::size_of())?;
+            .async_connection()?
+            .get_minimum_balance_for_rent_exemption(StakeStateV2::size_of())
+            .await?;
 
         let lamports: u64 = ((amount_sol * LAMPORTS_PER_SOL as f64).round() as u64) + rent_exempt;
 
@@ -286,8 +288,9 @@ impl Helius {
     /// Returns an error if the account cannot be found or isn't a valid stake account
     pub async fn get_withdrawable_amount(&self, stake_account: Pubkey, include_rent_exempt: bool) -> Result<u64> {
         let account = self
-            .connection()
-            .get_account_with_commitment(&stake_account, CommitmentConfig::confirmed())?
+            .async_connection()?
+            .get_account_with_commitment(&stake_account, CommitmentConfig::confirmed())
+            .await?
             .value
             .ok_or_else(|| HeliusError::NotFound {
                 text: format!("Stake account {} not found", stake_account),
@@ -307,7 +310,7 @@ impl Helius {
             }
         };
 
-        let current_epoch = self.connection().get_epoch_info()?.epoch;
+        let current_epoch = self.async_connection()?.get_epoch_info().await?.epoch;
 
         if deactivation_epoch > current_epoch {
             return Ok(0); // Still cooling down
@@ -318,8 +321,9 @@ impl Helius {
         }
 
         let rent_exempt = self
-            .connection()
-            .get_minimum_balance_for_rent_exemption(StakeStateV2::size_of())?;
+            .async_connection()?
+            .get_minimum_balance_for_rent_exemption(StakeStateV2::size_of())
+            .await?;
 
         Ok(lamports.saturating_sub(rent_exempt))
     }
@@ -359,10 +363,11 @@ impl Helius {
             ..Default::default()
         };
 
+        #[allow(deprecated)]
         let accounts: Vec<(Pubkey, Account)> = self
-            .connection()
+            .async_connection()?
             .get_program_accounts_with_config(&solana_stake_interface::program::id(), cfg)
-            .map_err(|e| HeliusError::InvalidInput(e.to_string()))?;
+            .await?;
 
         Ok(accounts)
     }
@@ -0,0 +1,390 @@
+//! HTTP routing for the fake server: JSON-RPC, webhooks, Sender, and websocket upgrades
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use super::{websocket, SenderSubmission, ServerState, BLOCK_HEIGHT, SLOT};
+use crate::error::{HeliusError, Result};
+use crate::services::{DasApi, WebhookApi};
+use crate::types::{
+    CreateWebhookRequest, EditWebhookRequest, GetPriorityFeeEstimateRequest, GetProgramAccountsV2Request, GpaAccount,
+    PriorityLevel, RpcError, RpcPayload, RpcResponse,
+};
+
+use base64::{engine::general_purpose::STANDARD as B64, Engine};
+use http_body_util::{BodyExt, Full};
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{HeaderValue, CONNECTION, CONTENT_TYPE, SEC_WEBSOCKET_ACCEPT, SEC_WEBSOCKET_KEY, UPGRADE};
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use solana_sdk::signature::Signature;
+use tokio::sync::watch;
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+use tokio_tungstenite::tungstenite::protocol::Role;
+use tokio_tungstenite::WebSocketStream;
+use url::form_urlencoded;
+
+/// The page size of `getProgramAccountsV2` when the request does not set a limit
+const DEFAULT_PROGRAM_ACCOUNTS_LIMIT: usize = 1000;
+
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+type HttpResponse = Response<Full<Bytes>>;
+type RpcResult = std::result::Result<Value, RpcError>;
+
+/// Routes a request to the handler for its endpoint
+pub(super) async fn handle(
+    request: Request<Incoming>,
+    state: Arc<ServerState>,
+    shutdown: watch::Receiver<()>,
+) -> std::result::Result<HttpResponse, Infallible> {
+    let path: String = request.uri().path().to_string();
+    let is_sender: bool = path == "/fast" || path == "/ping";
+
+    if !is_sender && !is_authorized(&request, &state) {
+        return Ok(error_response(StatusCode::UNAUTHORIZED, "invalid api key"));
+    }
+
+    if request.headers().contains_key(SEC_WEBSOCKET_KEY) {
+        return Ok(upgrade_websocket(request, state, shutdown));
+    }
+
+    let method: Method = request.method().clone();
+    let swqos_only: bool = query_param(&request, "swqos_only").is_some_and(|value| value == "true");
+    let body: Bytes = match request.into_body().collect().await {
+        Ok(body) => body.to_bytes(),
+        Err(err) => return Ok(error_response(StatusCode::BAD_REQUEST, &err.to_string())),
+    };
+
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let response: HttpResponse = match (&method, segments.as_slice()) {
+        (&Method::GET, ["ping"]) => text_response(StatusCode::OK, "pong"),
+        (&Method::POST, ["fast"]) => send_via_sender(&state, &body, swqos_only),
+        (_, ["v0", "webhooks"]) | (_, ["v0", "webhooks", _]) => {
+            handle_webhooks(&state, &method, segments.get(2).copied(), &body).await
+        }
+        (&Method::POST, _) => handle_rpc(&state, &body).await,
+        _ => error_response(StatusCode::NOT_FOUND, "not found"),
+    };
+
+    Ok(response)
+}
+
+fn query_param(request: &Request<Incoming>, name: &str) -> Option<String> {
+    form_urlencoded::parse(request.uri().query().unwrap_or_default().as_bytes())
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.into_owned())
+}
+
+fn is_authorized(request: &Request<Incoming>, state: &ServerState) -> bool {
+    let Some(api_key) = &state.api_key else {
+        return true;
+    };
+
+    let header: Option<&str> = request.headers().get("x-api-key").and_then(|value| value.to_str().ok());
+    header == Some(api_key.as_str()) || query_param(request, "api-key").as_ref() == Some(api_key)
+}
+
+/// Accepts a websocket handshake and serves the connection once hyper hands over the upgraded stream
+fn upgrade_websocket(
+    mut request: Request<Incoming>,
+    state: Arc<ServerState>,
+    shutdown: watch::Receiver<()>,
+) -> HttpResponse {
+    let accept: String = derive_accept_key(request.headers()[SEC_WEBSOCKET_KEY].as_bytes());
+
+    tokio::spawn(async move {
+        if let Ok(upgraded) = hyper::upgrade::on(&mut request).await {
+            let ws = WebSocketStream::from_raw_socket(TokioIo::new(upgraded), Role::Server, None).await;
+            websocket::serve(ws, state, shutdown).await;
+        }
+    });
+
+    let mut response: HttpResponse = Response::new(Full::default());
+    *response.status_mut() = StatusCode::SWITCHING_PROTOCOLS;
+    let headers = response.headers_mut();
+    headers.insert(CONNECTION, HeaderValue::from_static("Upgrade"));
+    headers.insert(UPGRADE, HeaderValue::from_static("websocket"));
+    if let Ok(accept) = HeaderValue::from_str(&accept) {
+        headers.insert(SEC_WEBSOCKET_ACCEPT, accept);
+    }
+    response
+}
+
+fn text_response(status: StatusCode, body: &str) -> HttpResponse {
+    let mut response: HttpResponse = Response::new(Full::new(Bytes::from(body.to_string())));
+    *response.status_mut() = status;
+    response
+}
+
+fn json_response<T: Serialize>(status: StatusCode, body: &T) -> HttpResponse {
+    let body: Vec<u8> = serde_json::to_vec(body).unwrap_or_default();
+    let mut response: HttpResponse = Response::new(Full::new(Bytes::from(body)));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    response
+}
+
+fn error_response(status: StatusCode, message: &str) -> HttpResponse {
+    json_response(status, &json!({ "error": message }))
+}
+
+/// Converts the outcome of a fake into an HTTP response, mapping its error to the matching status code
+fn rest_response<T: Serialize>(result: Result<T>) -> HttpResponse {
+    match result {
+        Ok(value) => json_response(StatusCode::OK, &value),
+        Err(HeliusError::NotFound { text }) => error_response(StatusCode::NOT_FOUND, &text),
+        Err(err) => error_response(StatusCode::BAD_REQUEST, &err.to_string()),
+    }
+}
+
+async fn handle_webhooks(state: &ServerState, method: &Method, webhook_id: Option<&str>, body: &[u8]) -> HttpResponse {
+    match (method, webhook_id) {
+        (&Method::GET, None) => rest_response(state.webhooks.get_all_webhooks().await),
+        (&Method::POST, None) => match serde_json::from_slice::<CreateWebhookRequest>(body) {
+            Ok(request) => rest_response(state.webhooks.create_webhook(request).await),
+            Err(err) => error_response(StatusCode::BAD_REQUEST, &err.to_string()),
+        },
+        (&Method::GET, Some(id)) => rest_response(state.webhooks.get_webhook_by_id(id).await),
+        (&Method::PUT, Some(id)) => {
+            // The webhook ID is part of the path rather than the body
+            let request = serde_json::from_slice::<Value>(body).and_then(|mut request| {
+                request["webhookId"] = Value::String(id.to_string());
+                serde_json::from_value::<EditWebhookRequest>(request)
+            });
+            match request {
+                Ok(request) => rest_response(state.webhooks.edit_webhook(request).await),
+                Err(err) => error_response(StatusCode::BAD_REQUEST, &err.to_string()),
+            }
+        }
+        (&Method::DELETE, Some(id)) => match state.webhooks.delete_webhook(id).await {
+            Ok(()) => text_response(StatusCode::OK, ""),
+            Err(err) => rest_response::<()>(Err(err)),
+        },
+        _ => error_response(StatusCode::METHOD_NOT_ALLOWED, "method not allowed"),
+    }
+}
+
+/// Answers a single JSON-RPC request, or each request of a batch
+async fn handle_rpc(state: &ServerState, body: &[u8]) -> HttpResponse {
+    match serde_json::from_slice::<Value>(body) {
+        Ok(Value::Array(requests)) => {
+            let mut responses: Vec<RpcResponse<Value>> = Vec::with_capacity(requests.len());
+            for request in requests {
+                responses.push(answer_rpc(state, request).await);
+            }
+            json_response(StatusCode::OK, &responses)
+        }
+        Ok(request) => json_response(StatusCode::OK, &answer_rpc(state, request).await),
+        Err(err) => json_response(
+            StatusCode::OK,
+            &rpc_response(Value::Null, Err(rpc_error(-32700, err.to_string()))),
+        ),
+    }
+}
+
+async fn answer_rpc(state: &ServerState, request: Value) -> RpcResponse<Value> {
+    let id: Value = request.get("id").cloned().unwrap_or(Value::Null);
+    let method: &str = request.get("method").and_then(Value::as_str).unwrap_or_default();
+    let params: Value = request.get("params").cloned().unwrap_or(Value::Null);
+
+    rpc_response(id, call(state, method, params).await)
+}
+
+fn rpc_response(id: Value, result: RpcResult) -> RpcResponse<Value> {
+    RpcResponse {
+        jsonrpc: "2.0".to_string(),
+        id: match id {
+            Value::String(id) => id,
+            Value::Null => String::new(),
+            id => id.to_string(),
+        },
+        payload: match result {
+            Ok(result) => RpcPayload::Result(result),
+            Err(error) => RpcPayload::Error(error),
+        },
+    }
+}
+
+fn rpc_error(code: i64, message: impl Into<String>) -> RpcError {
+    RpcError {
+        code,
+        message: message.into(),
+        data: None,
+    }
+}
+
+fn parse<T: DeserializeOwned>(params: Value) -> std::result::Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|err| rpc_error(INVALID_PARAMS, format!("Invalid params: {err}")))
+}
+
+fn to_result<T: Serialize>(result: Result<T>) -> RpcResult {
+    result
+        .and_then(|value| Ok(serde_json::to_value(value)?))
+        .map_err(|err| rpc_error(INTERNAL_ERROR, err.to_string()))
+}
+
+async fn call(state: &ServerState, method: &str, params: Value) -> RpcResult {
+    let das = &state.das;
+    match method {
+        "getAsset" => to_result(das.get_asset(parse(params)?).await),
+        "getAssetBatch" => to_result(das.get_asset_batch(parse(params)?).await),
+        "getAssetProof" => to_result(das.get_asset_proof(parse(params)?).await),
+        "getAssetProofBatch" => to_result(das.get_asset_proof_batch(parse(params)?).await),
+        "getAssetsByAuthority" => to_result(das.get_assets_by_authority(parse(params)?).await),
+        "getAssetsByCreator" => to_result(das.get_assets_by_creator(parse(params)?).await),
+        "getAssetsByGroup" => to_result(das.get_assets_by_group(parse(params)?).await),
+        "getAssetsByOwner" => to_result(das.get_assets_by_owner(parse(params)?).await),
+        "searchAssets" => to_result(das.search_assets(parse(params)?).await),
+        "getSignaturesForAsset" => to_result(das.get_signatures_for_asset(parse(params)?).await),
+        "getTokenAccounts" => to_result(das.get_token_accounts(parse(params)?).await),
+        "getNftEditions" => to_result(das.get_nft_editions(parse(params)?).await),
+        "getProgramAccountsV2" => get_program_accounts_v2(state, parse(params)?),
+        "getPriorityFeeEstimate" => get_priority_fee_estimate(state, parse(params)?),
+        "getBlockHeight" => Ok(json!(BLOCK_HEIGHT)),
+        "getSignatureStatuses" => get_signature_statuses(state, parse(params[0].clone())?),
+        _ => Err(rpc_error(METHOD_NOT_FOUND, format!("Method not found: {method}"))),
+    }
+}
+
+/// Pages through the seeded accounts of a program. The pagination key is the pubkey of the last account returned
+fn get_program_accounts_v2(state: &ServerState, (program_id, config): GetProgramAccountsV2Request) -> RpcResult {
+    let accounts: &[GpaAccount] = state
+        .program_accounts
+        .get(&program_id)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let start: usize = match &config.pagination_key {
+        Some(key) => {
+            accounts
+                .iter()
+                .position(|account| &account.pubkey == key)
+                .ok_or_else(|| rpc_error(INVALID_PARAMS, "Invalid pagination key"))?
+                + 1
+        }
+        None => 0,
+    };
+    let limit: usize = config
+        .limit
+        .map_or(DEFAULT_PROGRAM_ACCOUNTS_LIMIT, |limit| limit as usize);
+    let end: usize = (start + limit).min(accounts.len());
+    let page: &[GpaAccount] = &accounts[start..end];
+
+    Ok(json!({
+        "accounts": page,
+        "paginationKey": (end < accounts.len()).then(|| page.last().map(|account| account.pubkey.clone())).flatten(),
+        "totalResults": accounts.len(),
+    }))
+}
+
+/// Estimates from the configured fee levels. The `Default` level and `recommended` estimates use the medium level
+fn get_priority_fee_estimate(state: &ServerState, params: Vec<GetPriorityFeeEstimateRequest>) -> RpcResult {
+    let options = params.into_iter().next().and_then(|request| request.options);
+    let levels = &state.priority_fee_levels;
+
+    if options
+        .as_ref()
+        .is_some_and(|options| options.include_all_priority_fee_levels == Some(true))
+    {
+        return Ok(json!({ "priorityFeeLevels": levels }));
+    }
+
+    let estimate: f64 = match options.and_then(|options| options.priority_level) {
+        Some(PriorityLevel::Min) => levels.min,
+        Some(PriorityLevel::Low) => levels.low,
+        Some(PriorityLevel::High) => levels.high,
+        Some(PriorityLevel::VeryHigh) => levels.very_high,
+        Some(PriorityLevel::UnsafeMax) => levels.unsafe_max,
+        Some(PriorityLevel::Medium) | Some(PriorityLevel::Default) | None => levels.medium,
+    };
+    Ok(json!({ "priorityFeeEstimate": estimate }))
+}
+
+/// Reports every transaction submitted to Sender as finalized, and every other signature as unknown
+fn get_signature_statuses(state: &ServerState, signatures: Vec<String>) -> RpcResult {
+    let submissions = state
+        .sender_submissions
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let statuses: Vec<Value> = signatures
+        .iter()
+        .map(|signature| {
+            if submissions
+                .iter()
+                .any(|submission| &submission.signature.to_string() == signature)
+            {
+                json!({
+                    "slot": SLOT,
+                    "confirmations": null,
+                    "err": null,
+                    "status": { "Ok": null },
+                    "confirmationStatus": "finalized",
+                })
+            } else {
+                Value::Null
+            }
+        })
+        .collect();
+    Ok(json!({ "context": { "slot": SLOT }, "value": statuses }))
+}
+
+/// Records a base64 wire transaction sent to `/fast` and answers with its first signature
+fn send_via_sender(state: &ServerState, body: &[u8], swqos_only: bool) -> HttpResponse {
+    let request: Value = serde_json::from_slice(body).unwrap_or_default();
+    let id: Value = request.get("id").cloned().unwrap_or(Value::Null);
+
+    let transaction: Option<Vec<u8>> = request["params"][0]
+        .as_str()
+        .and_then(|transaction| B64.decode(transaction).ok());
+    let Some((transaction, signature)) =
+        transaction.and_then(|transaction| first_signature(&transaction).map(|signature| (transaction, signature)))
+    else {
+        let error: RpcError = rpc_error(INVALID_PARAMS, "failed to deserialize transaction");
+        return json_response(StatusCode::OK, &rpc_response(id, Err(error)));
+    };
+
+    state
+        .sender_submissions
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(SenderSubmission {
+            signature,
+            transaction,
+            swqos_only,
+        });
+    json_response(
+        StatusCode::OK,
+        &rpc_response(id, Ok(Value::String(signature.to_string()))),
+    )
+}
+
+/// Reads the first signature of a wire-format transaction, which starts with a compact-u16 signature count
+fn first_signature(transaction: &[u8]) -> Option<Signature> {
+    let mut count: usize = 0;
+    let mut offset: usize = 0;
+    loop {
+        let byte: u8 = *transaction.get(offset)?;
+        count |= usize::from(byte & 0x7f) << (7 * offset);
+        offset += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        if offset == 3 {
+            return None;
+        }
+    }
+
+    if count == 0 {
+        return None;
+    }
+    let bytes: &[u8] = transaction.get(offset..offset + 64)?;
+    Signature::try_from(bytes).ok()
+}
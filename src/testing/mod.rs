@@ -0,0 +1,338 @@
+//! An in-process fake of the Helius HTTP and websocket APIs for integration tests
+//!
+//! `FakeHeliusServer` listens on a local port and emulates a realistic subset of Helius, so tests can point a real
+//! `Helius` client at it and run offline and deterministically. It serves:
+//! - The DAS methods, answered from a seeded [`FakeDas`]
+//! - Webhook CRUD, with state kept in a [`FakeWebhooks`]
+//! - `getProgramAccountsV2`, paginated over seeded program accounts
+//! - `getPriorityFeeEstimate`, from configured fee levels
+//! - `transactionSubscribe` and `accountSubscribe`, with notifications pushed by the test
+//! - The Sender `/fast` and `/ping` endpoints, recording each submitted transaction
+//! - `getBlockHeight` and `getSignatureStatuses`, so transactions submitted to Sender are confirmed
+//!
+//! Any other JSON-RPC method is answered with a "method not found" error.
+//!
+//! # Example
+//! ```rust
+//! use helius::error::Result;
+//! use helius::services::fake::FakeDas;
+//! use helius::testing::FakeHeliusServer;
+//! use helius::types::GetAsset;
+//! use helius::Helius;
+//!
+//! # async fn run() -> Result<()> {
+//! let server = FakeHeliusServer::builder().das(FakeDas::new()).start().await.unwrap();
+//! let helius = Helius::builder()
+//!     .api_key("fake_api_key")
+//!     .cluster(server.cluster())
+//!     .build()?;
+//!
+//! let asset = helius
+//!     .rpc()
+//!     .get_asset(GetAsset {
+//!         id: "F9Lw3ki3hJ7PF9HQXsBzoY8GyE6sPoEZZdXJBsTTD2rk".to_string(),
+//!         display_options: None,
+//!     })
+//!     .await?;
+//! assert!(asset.is_none());
+//! # Ok(())
+//! # }
+//! # tokio::runtime::Runtime::new().unwrap().block_on(run()).unwrap();
+//! ```
+mod http;
+mod websocket;
+
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::atomic::AtomicU64;
+use std::sync::{Arc, Mutex};
+
+use crate::error::Result;
+use crate::services::fake::{FakeDas, FakeWebhooks};
+use crate::types::{
+    Cluster, GpaAccount, HeliusEndpoints, MicroLamportPriorityFeeLevels, TransactionNotification, Webhook,
+};
+
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper_util::rt::TokioIo;
+use serde_json::{json, Value};
+use solana_account_decoder::UiAccount;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use tokio::net::TcpListener;
+use tokio::sync::{broadcast, watch};
+use tokio::task::JoinHandle;
+
+/// The block height reported by the server, which never advances
+pub const BLOCK_HEIGHT: u64 = 1_000;
+
+/// The slot reported in the context of the server's responses
+pub const SLOT: u64 = 1_000;
+
+/// The number of pushed notifications a websocket connection can fall behind by before it starts skipping them
+const NOTIFICATION_CAPACITY: usize = 1024;
+
+/// A transaction submitted to the fake Sender `/fast` endpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct SenderSubmission {
+    /// The first signature of the transaction
+    pub signature: Signature,
+    /// The wire-format transaction, as decoded from the request
+    pub transaction: Vec<u8>,
+    /// Whether the transaction was sent with `swqos_only=true`
+    pub swqos_only: bool,
+}
+
+/// A notification pushed to the subscribers of the fake websocket
+#[derive(Debug, Clone)]
+pub(crate) enum Notification {
+    Transaction { accounts: Vec<String>, value: Value },
+    Account { pubkey: String, value: Value },
+}
+
+/// The state shared by every connection to a `FakeHeliusServer`
+pub(crate) struct ServerState {
+    pub(crate) das: FakeDas,
+    pub(crate) webhooks: FakeWebhooks,
+    pub(crate) program_accounts: HashMap<String, Vec<GpaAccount>>,
+    pub(crate) priority_fee_levels: MicroLamportPriorityFeeLevels,
+    pub(crate) api_key: Option<String>,
+    pub(crate) sender_submissions: Mutex<Vec<SenderSubmission>>,
+    pub(crate) notifications: broadcast::Sender<Notification>,
+    pub(crate) next_subscription: AtomicU64,
+}
+
+/// A builder for seeding and starting a `FakeHeliusServer`
+#[derive(Debug)]
+pub struct FakeHeliusServerBuilder {
+    das: FakeDas,
+    webhooks: FakeWebhooks,
+    program_accounts: HashMap<String, Vec<GpaAccount>>,
+    priority_fee_levels: MicroLamportPriorityFeeLevels,
+    api_key: Option<String>,
+}
+
+impl Default for FakeHeliusServerBuilder {
+    fn default() -> Self {
+        Self {
+            das: FakeDas::new(),
+            webhooks: FakeWebhooks::new(),
+            program_accounts: HashMap::new(),
+            priority_fee_levels: MicroLamportPriorityFeeLevels {
+                min: 0.0,
+                low: 1_000.0,
+                medium: 10_000.0,
+                high: 100_000.0,
+                very_high: 1_000_000.0,
+                unsafe_max: 10_000_000.0,
+            },
+            api_key: None,
+        }
+    }
+}
+
+impl FakeHeliusServerBuilder {
+    /// Creates a builder for a server with no fixtures and the default priority fee levels
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the fake that answers the DAS methods
+    pub fn das(mut self, das: FakeDas) -> Self {
+        self.das = das;
+        self
+    }
+
+    /// Sets the fake that stores the server's webhooks
+    pub fn webhooks(mut self, webhooks: FakeWebhooks) -> Self {
+        self.webhooks = webhooks;
+        self
+    }
+
+    /// Adds an account owned by `program_id`, after every account added for that program before it
+    pub fn with_program_account(mut self, program_id: impl Into<String>, account: GpaAccount) -> Self {
+        self.program_accounts
+            .entry(program_id.into())
+            .or_default()
+            .push(account);
+        self
+    }
+
+    /// Sets the fee levels returned by `getPriorityFeeEstimate`
+    pub fn priority_fee_levels(mut self, levels: MicroLamportPriorityFeeLevels) -> Self {
+        self.priority_fee_levels = levels;
+        self
+    }
+
+    /// Requires every HTTP request, other than those to Sender, and every websocket connection to authenticate with the
+    /// given API key, either as an `api-key` query parameter or in the `X-Api-Key` header
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Binds the server to a free local port and starts serving requests in the background
+    ///
+    /// # Returns
+    /// The running `FakeHeliusServer`, which stops serving once dropped
+    ///
+    /// # Errors
+    /// Returns an `io::Error` if a local port could not be bound. Must be called within a Tokio runtime
+    pub async fn start(self) -> io::Result<FakeHeliusServer> {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await?;
+        let address: SocketAddr = listener.local_addr()?;
+        let (notifications, _) = broadcast::channel(NOTIFICATION_CAPACITY);
+
+        let state: Arc<ServerState> = Arc::new(ServerState {
+            das: self.das,
+            webhooks: self.webhooks,
+            program_accounts: self.program_accounts,
+            priority_fee_levels: self.priority_fee_levels,
+            api_key: self.api_key,
+            sender_submissions: Mutex::new(Vec::new()),
+            notifications,
+            next_subscription: AtomicU64::new(1),
+        });
+
+        let (shutdown, shutdown_receiver) = watch::channel(());
+        let accept: JoinHandle<()> = tokio::spawn(accept_connections(listener, state.clone(), shutdown_receiver));
+
+        Ok(FakeHeliusServer {
+            address,
+            state,
+            _shutdown: shutdown,
+            accept,
+        })
+    }
+}
+
+/// A local server emulating the Helius HTTP and websocket APIs
+///
+/// The server runs until it is dropped, at which point open HTTP and websocket connections are closed as well
+pub struct FakeHeliusServer {
+    address: SocketAddr,
+    state: Arc<ServerState>,
+    // Dropping the sender signals every connection to close
+    _shutdown: watch::Sender<()>,
+    accept: JoinHandle<()>,
+}
+
+impl FakeHeliusServer {
+    /// Creates a builder for seeding and starting a `FakeHeliusServer`
+    pub fn builder() -> FakeHeliusServerBuilder {
+        FakeHeliusServerBuilder::new()
+    }
+
+    /// Starts a server with no fixtures
+    ///
+    /// # Errors
+    /// Returns an `io::Error` if a local port could not be bound
+    pub async fn start() -> io::Result<Self> {
+        Self::builder().start().await
+    }
+
+    /// The base HTTP URL of the server, without a trailing slash (e.g., `http://127.0.0.1:3000`)
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// The websocket URL of the server (e.g., `ws://127.0.0.1:3000/`)
+    pub fn websocket_url(&self) -> String {
+        format!("ws://{}/", self.address)
+    }
+
+    /// The endpoints that route every Helius API, including Sender and the enhanced websocket, to this server
+    pub fn endpoints(&self) -> HeliusEndpoints {
+        HeliusEndpoints {
+            api: format!("{}/", self.url()),
+            rpc: self.url(),
+            websocket: Some(self.websocket_url()),
+            sender: Some(self.url()),
+        }
+    }
+
+    /// A custom cluster using this server's endpoints, for passing to `Helius::builder().cluster(..)`
+    pub fn cluster(&self) -> Cluster {
+        Cluster::Custom(self.endpoints())
+    }
+
+    /// Returns the webhooks currently stored by the server, ordered by ID
+    pub fn webhooks(&self) -> Vec<Webhook> {
+        self.state.webhooks.webhooks()
+    }
+
+    /// Returns the transactions submitted to Sender so far, in order. Each is reported as finalized by
+    /// `getSignatureStatuses`
+    pub fn sender_submissions(&self) -> Vec<SenderSubmission> {
+        self.state
+            .sender_submissions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Pushes a transaction notification to every `transactionSubscribe` subscription whose filter matches it
+    ///
+    /// A transaction's accounts are read from the account keys of its message and the loaded addresses of its metadata,
+    /// and are matched against the `accountInclude`, `accountExclude`, and `accountRequired` filters. The `signature`
+    /// filter is matched as well, while the `vote` and `failed` filters are ignored
+    ///
+    /// # Errors
+    /// Returns `HeliusError::SerdeJson` if the notification could not be serialized
+    pub fn push_transaction(&self, notification: &TransactionNotification) -> Result<()> {
+        let value: Value = serde_json::to_value(notification)?;
+        let accounts: Vec<String> = websocket::transaction_accounts(&value);
+
+        // Sending only fails when there are no connections, in which case there's nobody to notify
+        let _ = self
+            .state
+            .notifications
+            .send(Notification::Transaction { accounts, value });
+        Ok(())
+    }
+
+    /// Pushes an account update to every `accountSubscribe` subscription for `pubkey`
+    ///
+    /// # Errors
+    /// Returns `HeliusError::SerdeJson` if the account could not be serialized
+    pub fn push_account(&self, pubkey: &Pubkey, account: &UiAccount, slot: u64) -> Result<()> {
+        let value: Value = json!({
+            "context": { "slot": slot },
+            "value": serde_json::to_value(account)?,
+        });
+
+        let _ = self.state.notifications.send(Notification::Account {
+            pubkey: pubkey.to_string(),
+            value,
+        });
+        Ok(())
+    }
+}
+
+impl Drop for FakeHeliusServer {
+    fn drop(&mut self) {
+        self.accept.abort();
+    }
+}
+
+async fn accept_connections(listener: TcpListener, state: Arc<ServerState>, shutdown: watch::Receiver<()>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        let state: Arc<ServerState> = state.clone();
+        let mut shutdown: watch::Receiver<()> = shutdown.clone();
+
+        tokio::spawn(async move {
+            let connection_shutdown: watch::Receiver<()> = shutdown.clone();
+            let service = service_fn(move |request| http::handle(request, state.clone(), connection_shutdown.clone()));
+            let connection = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .with_upgrades();
+
+            tokio::select! {
+                _ = connection => {},
+                _ = shutdown.changed() => {},
+            }
+        });
+    }
+}
@@ -0,0 +1,177 @@
+//! The enhanced websocket of the fake server, which answers subscriptions and forwards pushed notifications
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use super::{Notification, ServerState};
+use crate::types::TransactionSubscribeFilter;
+
+use futures_util::{SinkExt, StreamExt};
+use hyper::upgrade::Upgraded;
+use hyper_util::rt::TokioIo;
+use serde_json::{json, Value};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::watch;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+
+type ServerStream = WebSocketStream<TokioIo<Upgraded>>;
+
+/// What a single subscription of a connection listens for
+enum Subscription {
+    Transaction(TransactionSubscribeFilter),
+    Account(String),
+}
+
+impl Subscription {
+    fn matches(&self, notification: &Notification) -> bool {
+        match (self, notification) {
+            (Subscription::Transaction(filter), Notification::Transaction { accounts, value }) => {
+                let contains = |account: &String| accounts.contains(account);
+                filter
+                    .signature
+                    .as_ref()
+                    .is_none_or(|signature| value["signature"].as_str() == Some(signature))
+                    && filter
+                        .account_include
+                        .as_ref()
+                        .is_none_or(|include| include.is_empty() || include.iter().any(contains))
+                    && filter
+                        .account_exclude
+                        .as_ref()
+                        .is_none_or(|exclude| !exclude.iter().any(contains))
+                    && filter
+                        .account_required
+                        .as_ref()
+                        .is_none_or(|required| required.iter().all(contains))
+            }
+            (Subscription::Account(pubkey), Notification::Account { pubkey: updated, .. }) => pubkey == updated,
+            _ => false,
+        }
+    }
+
+    fn method(&self) -> &'static str {
+        match self {
+            Subscription::Transaction(_) => "transactionNotification",
+            Subscription::Account(_) => "accountNotification",
+        }
+    }
+}
+
+/// Reads the accounts a transaction notification touches from its message's account keys and its loaded addresses
+pub(super) fn transaction_accounts(notification: &Value) -> Vec<String> {
+    let transaction: &Value = &notification["transaction"];
+    let account_keys = transaction["transaction"]["message"]["accountKeys"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        // Parsed messages list each account as an object with a `pubkey`
+        .filter_map(|key| key.as_str().or_else(|| key["pubkey"].as_str()));
+    let loaded_addresses = ["writable", "readonly"]
+        .into_iter()
+        .flat_map(|kind| {
+            transaction["meta"]["loadedAddresses"][kind]
+                .as_array()
+                .into_iter()
+                .flatten()
+        })
+        .filter_map(Value::as_str);
+
+    account_keys.chain(loaded_addresses).map(str::to_string).collect()
+}
+
+/// Serves a websocket connection until the client disconnects or the server shuts down
+pub(super) async fn serve(mut ws: ServerStream, state: Arc<ServerState>, mut shutdown: watch::Receiver<()>) {
+    let mut notifications = state.notifications.subscribe();
+    let mut subscriptions: HashMap<u64, Subscription> = HashMap::new();
+
+    loop {
+        tokio::select! {
+            _ = shutdown.changed() => {
+                let _ = ws.close(None).await;
+                break;
+            }
+            notification = notifications.recv() => {
+                let notification: Notification = match notification {
+                    Ok(notification) => notification,
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                };
+
+                for (id, subscription) in &subscriptions {
+                    if !subscription.matches(&notification) {
+                        continue;
+                    }
+
+                    let result: &Value = match &notification {
+                        Notification::Transaction { value, .. } | Notification::Account { value, .. } => value,
+                    };
+                    let message: Value = json!({
+                        "jsonrpc": "2.0",
+                        "method": subscription.method(),
+                        "params": { "subscription": id, "result": result },
+                    });
+                    if ws.send(Message::text(message.to_string())).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            message = ws.next() => {
+                let text = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => continue,
+                };
+
+                let response: Value = answer(&state, &mut subscriptions, &text);
+                if ws.send(Message::text(response.to_string())).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Answers a subscribe or unsubscribe request, registering or removing the subscription on this connection
+fn answer(state: &ServerState, subscriptions: &mut HashMap<u64, Subscription>, text: &str) -> Value {
+    let request: Value = serde_json::from_str(text).unwrap_or_default();
+    let id: Value = request.get("id").cloned().unwrap_or(Value::Null);
+    let params: &Value = &request["params"];
+
+    let result: Result<Value, String> = match request["method"].as_str().unwrap_or_default() {
+        "transactionSubscribe" => serde_json::from_value::<TransactionSubscribeFilter>(params[0].clone())
+            .map(Subscription::Transaction)
+            .map_err(|err| err.to_string()),
+        "accountSubscribe" => params[0]
+            .as_str()
+            .map(|pubkey| Subscription::Account(pubkey.to_string()))
+            .ok_or_else(|| "expected a pubkey".to_string()),
+        "transactionUnsubscribe" | "accountUnsubscribe" => {
+            let removed: bool = params[0]
+                .as_u64()
+                .is_some_and(|subscription| subscriptions.remove(&subscription).is_some());
+            return json!({ "jsonrpc": "2.0", "id": id, "result": removed });
+        }
+        method => {
+            return json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": -32601, "message": format!("Method not found: {method}") },
+            });
+        }
+    }
+    .map(|subscription| {
+        let subscription_id: u64 = state.next_subscription.fetch_add(1, Ordering::Relaxed);
+        subscriptions.insert(subscription_id, subscription);
+        json!(subscription_id)
+    });
+
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(message) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": -32602, "message": format!("Invalid params: {message}") },
+        }),
+    }
+}
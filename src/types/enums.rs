@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
 use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
-use solana_program::pubkey::Pubkey;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::transaction::{Transaction, VersionedTransaction};
 use std::str::FromStr;
 
 use super::*;
+use crate::error::{HeliusError, Result};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub enum Interface {
@@ -138,14 +139,22 @@ pub enum MintApiAuthority {
 }
 
 impl MintApiAuthority {
-    pub fn from_cluster(cluster: &Cluster) -> Self {
+    /// Returns the Mint API's authority for a cluster
+    ///
+    /// # Errors
+    /// Returns `HeliusError::InvalidInput` for `Cluster::Localnet` and `Cluster::Custom`, since the Mint API is only
+    /// hosted on mainnet and devnet
+    pub fn from_cluster(cluster: &Cluster) -> Result<Self> {
         match cluster {
-            Cluster::MainnetBeta | Cluster::StakedMainnetBeta => {
-                MintApiAuthority::Mainnet(Pubkey::from_str("HnT5KVAywGgQDhmh6Usk4bxRg4RwKxCK4jmECyaDth5R").unwrap())
-            }
-            Cluster::Devnet => {
-                MintApiAuthority::Devnet(Pubkey::from_str("2LbAtCJSaHqTnP9M5QSjvAMXk79RNLusFspFN5Ew67TC").unwrap())
-            }
+            Cluster::MainnetBeta | Cluster::StakedMainnetBeta => Ok(MintApiAuthority::Mainnet(
+                Pubkey::from_str("HnT5KVAywGgQDhmh6Usk4bxRg4RwKxCK4jmECyaDth5R").unwrap(),
+            )),
+            Cluster::Devnet => Ok(MintApiAuthority::Devnet(
+                Pubkey::from_str("2LbAtCJSaHqTnP9M5QSjvAMXk79RNLusFspFN5Ew67TC").unwrap(),
+            )),
+            Cluster::Localnet | Cluster::Custom(_) => Err(HeliusError::InvalidInput(
+                "The Mint API is only available on mainnet and devnet".to_string(),
+            )),
         }
     }
 }
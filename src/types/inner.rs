@@ -4,6 +4,7 @@ use super::{
     TransactionStatus, TransactionType, UiTransactionEncoding, WebhookType,
 };
 use crate::types::{DisplayOptions, Encoding, GetAssetOptions, GpaFilter, TokenAccountsOwnerFilter};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::Arc;
@@ -19,13 +20,21 @@ pub enum Cluster {
     Devnet,
     MainnetBeta,
     StakedMainnetBeta,
+    /// A local `solana-test-validator` listening on its default ports
+    Localnet,
+    /// A cluster reached through user-provided endpoints, such as a dedicated node or a proxy
+    Custom(HeliusEndpoints),
 }
 
-/// Stores the API and RPC endpoint URLs for a specific Helius cluster
-#[derive(Debug, Clone)]
+/// Stores the endpoint URLs for a specific Helius cluster
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct HeliusEndpoints {
     pub api: String,
     pub rpc: String,
+    /// The enhanced websocket endpoint, if the cluster has one. The API key is appended when connecting
+    pub websocket: Option<String>,
+    /// The Sender endpoint used in place of the regional Sender endpoints, if set
+    pub sender: Option<String>,
 }
 
 impl HeliusEndpoints {
@@ -34,15 +43,28 @@ impl HeliusEndpoints {
             Cluster::Devnet => HeliusEndpoints {
                 api: "https://api-devnet.helius-rpc.com/".to_string(),
                 rpc: "https://devnet.helius-rpc.com/".to_string(),
+                websocket: Some("wss://atlas-devnet.helius-rpc.com/".to_string()),
+                sender: None,
             },
             Cluster::MainnetBeta => HeliusEndpoints {
                 api: "https://api-mainnet.helius-rpc.com/".to_string(),
                 rpc: "https://mainnet.helius-rpc.com/".to_string(),
+                websocket: Some("wss://atlas-mainnet.helius-rpc.com/".to_string()),
+                sender: None,
             },
             Cluster::StakedMainnetBeta => HeliusEndpoints {
                 api: "https://api-mainnet.helius-rpc.com/".to_string(),
                 rpc: "https://staked.helius-rpc.com/".to_string(),
+                websocket: None,
+                sender: None,
             },
+            Cluster::Localnet => HeliusEndpoints {
+                api: "http://127.0.0.1:8899/".to_string(),
+                rpc: "http://127.0.0.1:8899/".to_string(),
+                websocket: Some("ws://127.0.0.1:8900/".to_string()),
+                sender: None,
+            },
+            Cluster::Custom(endpoints) => endpoints.clone(),
         }
     }
 }
@@ -65,19 +87,89 @@ impl<T> RpcRequest<T> {
             parameters,
         }
     }
+
+    /// Sets the ID used to match the response to this request
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+/// A JSON-RPC response, which carries either a result or an error
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct RpcResponse<T> {
     pub jsonrpc: String,
+    /// The ID of the request this response belongs to. Numeric IDs are converted to strings, and a `null` ID (e.g., for a
+    /// request that could not be parsed) is an empty string
+    #[serde(default, deserialize_with = "deserialize_rpc_id")]
     pub id: String,
-    pub result: T,
+    #[serde(flatten)]
+    pub payload: RpcPayload<T>,
+}
+
+impl<T> RpcResponse<T> {
+    /// Converts the response into its result
+    ///
+    /// # Errors
+    /// Returns `HeliusError::Rpc` if the server responded with a JSON-RPC error
+    pub fn into_result(self) -> crate::error::Result<T> {
+        match self.payload {
+            RpcPayload::Result(result) => Ok(result),
+            RpcPayload::Error(error) => Err(error.into()),
+        }
+    }
+}
+
+impl<T: Default> Default for RpcResponse<T> {
+    fn default() -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id: String::new(),
+            payload: RpcPayload::Result(T::default()),
+        }
+    }
+}
+
+/// The outcome of a JSON-RPC request: exactly one of `result` or `error` is present in a response
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RpcPayload<T> {
+    Result(T),
+    Error(RpcError),
+}
+
+/// A JSON-RPC error object
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct RpcError {
+    /// The error code (e.g., `-32602` for invalid params, or `-32002` for a failed transaction simulation)
+    pub code: i64,
+    pub message: String,
+    /// Additional information about the error, such as the logs of a failed transaction simulation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+fn deserialize_rpc_id<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(match Value::deserialize(deserializer)? {
+        Value::Null => String::new(),
+        Value::String(id) => id,
+        id => id.to_string(),
+    })
+}
+
+/// DAS pages start at 1, so page 0 marks a request that paginates by cursor or range instead
+fn is_unset_page(page: &u32) -> bool {
+    *page == 0
 }
 
 #[derive(Serialize, Deserialize, Default, Debug)]
 pub struct GetAssetsByOwner {
     #[serde(rename = "ownerAddress")]
     pub owner_address: String,
+    #[serde(default, skip_serializing_if = "is_unset_page")]
     pub page: u32,
     pub limit: Option<i32>,
     pub before: Option<String>,
@@ -94,6 +186,7 @@ pub struct GetAssetsByOwner {
 pub struct GetAssetsByAuthority {
     #[serde(rename = "authorityAddress")]
     pub authority_address: String,
+    #[serde(default, skip_serializing_if = "is_unset_page")]
     pub page: u32,
     pub limit: Option<u32>,
     pub before: Option<String>,
@@ -212,8 +305,8 @@ pub struct SearchAssets {
     pub collections: Option<Vec<String>>,
     #[serde(default)]
     pub token_type: Option<TokenType>,
-    // #[serde(default)]
-    // pub created_at: Option<CreatedAtFilter>, //TODO: Uncomment this line when the CreatedAtFilter struct is defined
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<CreatedAtFilter>,
     #[serde(default)]
     pub tree: Option<String>,
     #[serde(default)]
@@ -677,15 +770,19 @@ pub struct Uses {
     pub total: u64,
 }
 
+/// DAS returns these fields in snake_case, so they're accepted in either case
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Supply {
+    #[serde(alias = "print_max_supply")]
     pub print_max_supply: Option<u64>,
+    #[serde(alias = "print_current_supply")]
     pub print_current_supply: Option<u64>,
+    #[serde(alias = "edition_nonce")]
     pub edition_nonce: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", alias = "edition_number")]
     pub edition_number: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", alias = "master_edition_mint")]
     pub master_edition_mint: Option<String>,
 }
 
@@ -717,19 +814,19 @@ pub struct MplCoreInfo {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct NotFilter {
     pub collections: Option<Vec<String>>,
-    pub owners: Option<Vec<Vec<u8>>>,
-    pub creators: Option<Vec<Vec<u8>>>,
-    pub authorities: Option<Vec<Vec<u8>>>,
-}
-
-// #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-// #[serde(deny_unknown_fields, rename_all = "camelCase")]
-// pub struct CreatedAtFilter {
-//     #[serde(default)]
-//     pub after: Option<DateTime<Utc>>,
-//     #[serde(default)]
-//     pub before: Option<DateTime<Utc>>,
-// }
+    pub owners: Option<Vec<String>>,
+    pub creators: Option<Vec<String>>,
+    pub authorities: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct CreatedAtFilter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<DateTime<Utc>>,
+}
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct TokenAccount {
@@ -1110,7 +1207,11 @@ pub struct AccountInfo {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(from = "ProgramAccountsV2Result")]
 pub struct GetProgramAccountsV2Response {
+    /// The RPC context the page was read at, which is only returned if the config's `with_context` is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<RpcContext>,
     pub accounts: Vec<GpaAccount>,
     #[serde(rename = "paginationKey")]
     pub pagination_key: Option<String>,
@@ -1118,6 +1219,54 @@ pub struct GetProgramAccountsV2Response {
     pub total_results: Option<u64>,
 }
 
+/// The result of `getProgramAccountsV2`, which is wrapped in a `context` and `value` if `withContext` is set
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ProgramAccountsV2Result {
+    WithContext {
+        context: RpcContext,
+        value: ProgramAccountsV2Page,
+        #[serde(default, rename = "paginationKey")]
+        pagination_key: Option<String>,
+        #[serde(default, rename = "totalResults")]
+        total_results: Option<u64>,
+    },
+    Page(ProgramAccountsV2Page),
+}
+
+#[derive(Deserialize)]
+struct ProgramAccountsV2Page {
+    accounts: Vec<GpaAccount>,
+    #[serde(default, rename = "paginationKey")]
+    pagination_key: Option<String>,
+    #[serde(default, rename = "totalResults")]
+    total_results: Option<u64>,
+}
+
+impl From<ProgramAccountsV2Result> for GetProgramAccountsV2Response {
+    fn from(result: ProgramAccountsV2Result) -> Self {
+        match result {
+            ProgramAccountsV2Result::WithContext {
+                context,
+                value,
+                pagination_key,
+                total_results,
+            } => Self {
+                context: Some(context),
+                accounts: value.accounts,
+                pagination_key: value.pagination_key.or(pagination_key),
+                total_results: value.total_results.or(total_results),
+            },
+            ProgramAccountsV2Result::Page(page) => Self {
+                context: None,
+                accounts: page.accounts,
+                pagination_key: page.pagination_key,
+                total_results: page.total_results,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct GetTokenAccountsByOwnerV2Config {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1,11 +1,14 @@
 pub mod enhanced_transaction_types;
+#[cfg(feature = "enhanced-ws")]
 pub mod enhanced_websocket;
 pub mod enums;
 pub mod inner;
 pub mod options;
 
 pub use self::enhanced_transaction_types::*;
+#[cfg(feature = "enhanced-ws")]
 pub use self::enhanced_websocket::*;
 pub use self::enums::*;
+pub use self::inner::TransactionDetails;
 pub use self::inner::*;
 pub use self::options::*;
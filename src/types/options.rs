@@ -14,7 +14,7 @@ pub struct DisplayOptions {
     pub show_closed_accounts: bool,
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GetAssetOptions {
     pub show_collection_metadata: bool,
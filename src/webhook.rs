@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::request_handler::{EndpointFamily, RequestContext};
 use crate::types::{CreateWebhookRequest, EditWebhookRequest, Webhook};
 use crate::Helius;
 
@@ -13,15 +14,18 @@ impl Helius {
     /// # Returns
     /// A `Result` wrapping a `Webhook` if the webhook is successfully created, or a `HeliusError` if creation fails
     pub async fn create_webhook(&self, request: CreateWebhookRequest) -> Result<Webhook> {
-        let url: String = format!(
-            "{}v0/webhooks?api-key={}",
-            self.config.endpoints.api, self.config.api_key
-        );
-        let parsed_url: Url = Url::parse(&url).expect("Failed to parse URL");
+        let parsed_url: Url = self
+            .config
+            .authenticated_url(&format!("{}v0/webhooks", self.config.endpoints.api))?;
 
         self.rpc_client
             .handler
-            .send(Method::POST, parsed_url, Some(&request))
+            .send_with_context(
+                &RequestContext::new("createWebhook", EndpointFamily::EnhancedApi),
+                Method::POST,
+                parsed_url,
+                Some(&request),
+            )
             .await
     }
 
@@ -33,15 +37,19 @@ impl Helius {
     /// # Returns
     /// A `Result` wrapping the updated `Webhook`, or a `HeliusError` if the edit request fails
     pub async fn edit_webhook(&self, request: EditWebhookRequest) -> Result<Webhook> {
-        let url: String = format!(
-            "{}v0/webhooks/{}?api-key={}",
-            self.config.endpoints.api, request.webhook_id, self.config.api_key
-        );
-        let parsed_url: Url = Url::parse(&url).expect("Failed to parse URL");
+        let parsed_url: Url = self.config.authenticated_url(&format!(
+            "{}v0/webhooks/{}",
+            self.config.endpoints.api, request.webhook_id
+        ))?;
 
         self.rpc_client
             .handler
-            .send(Method::PUT, parsed_url, Some(&request))
+            .send_with_context(
+                &RequestContext::new("editWebhook", EndpointFamily::EnhancedApi).idempotent(true),
+                Method::PUT,
+                parsed_url,
+                Some(&request),
+            )
             .await
     }
 
@@ -110,13 +118,19 @@ impl Helius {
     /// # Returns
     /// A `Result` wrapping the `Webhook` queried, if it exists
     pub async fn get_webhook_by_id(&self, webhook_id: &str) -> Result<Webhook> {
-        let url: String = format!(
-            "{}v0/webhooks/{}?api-key={}",
-            self.config.endpoints.api, webhook_id, self.config.api_key
-        );
-        let parsed_url: Url = Url::parse(&url).expect("Failed to parse URL");
+        let parsed_url: Url = self
+            .config
+            .authenticated_url(&format!("{}v0/webhooks/{}", self.config.endpoints.api, webhook_id))?;
 
-        self.rpc_client.handler.send(Method::GET, parsed_url, None::<&()>).await
+        self.rpc_client
+            .handler
+            .send_with_context(
+                &RequestContext::new("getWebhookById", EndpointFamily::EnhancedApi).idempotent(true),
+                Method::GET,
+                parsed_url,
+                None::<&()>,
+            )
+            .await
     }
 
     /// Retrieves all Helius webhooks programmatically
@@ -126,13 +140,19 @@ impl Helius {
     /// # Returns
     /// A `Result` containing a vector of `Webhook` representing all configured webhooks for a given account
     pub async fn get_all_webhooks(&self) -> Result<Vec<Webhook>> {
-        let url: String = format!(
-            "{}v0/webhooks?api-key={}",
-            self.config.endpoints.api, self.config.api_key
-        );
-        let parsed_url: Url = Url::parse(&url).expect("Failed to parse URL");
+        let parsed_url: Url = self
+            .config
+            .authenticated_url(&format!("{}v0/webhooks", self.config.endpoints.api))?;
 
-        self.rpc_client.handler.send(Method::GET, parsed_url, None::<&()>).await
+        self.rpc_client
+            .handler
+            .send_with_context(
+                &RequestContext::new("getAllWebhooks", EndpointFamily::EnhancedApi).idempotent(true),
+                Method::GET,
+                parsed_url,
+                None::<&()>,
+            )
+            .await
     }
 
     /// Deletes a given Helius webhook programmatically
@@ -143,15 +163,18 @@ impl Helius {
     /// # Returns
     /// A unit since there isn't any response
     pub async fn delete_webhook(&self, webhook_id: &str) -> Result<()> {
-        let url: String = format!(
-            "{}v0/webhooks/{}?api-key={}",
-            self.config.endpoints.api, webhook_id, self.config.api_key
-        );
-        let parsed_url: Url = Url::parse(&url).expect("Failed to parse URL");
+        let parsed_url: Url = self
+            .config
+            .authenticated_url(&format!("{}v0/webhooks/{}", self.config.endpoints.api, webhook_id))?;
 
         self.rpc_client
             .handler
-            .send(Method::DELETE, parsed_url, None::<&()>)
+            .send_with_context(
+                &RequestContext::new("deleteWebhook", EndpointFamily::EnhancedApi).idempotent(true),
+                Method::DELETE,
+                parsed_url,
+                None::<&()>,
+            )
             .await
     }
 }
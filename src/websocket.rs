@@ -1,5 +1,6 @@
 use crate::error::{HeliusError, Result};
-use crate::types::Cluster;
+use crate::metrics::MetricsSink;
+use crate::types::{Cluster, HeliusEndpoints};
 use crate::types::{RpcTransactionsConfig, TransactionNotification};
 use futures_util::{
     future::{ready, BoxFuture, FutureExt},
@@ -14,6 +15,8 @@ use solana_rpc_client_api::{error_object::RpcErrorObject, response::Response as
 use solana_sdk::pubkey::Pubkey;
 use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Instant;
 use tokio::{
     net::TcpStream,
     sync::{mpsc, oneshot, RwLock},
@@ -24,11 +27,13 @@ use tokio_stream::wrappers::UnboundedReceiverStream;
 use tokio_tungstenite::{
     connect_async,
     tungstenite::{
+        client::IntoClientRequest,
         protocol::frame::{coding::CloseCode, CloseFrame},
         Message,
     },
     MaybeTlsStream, WebSocketStream,
 };
+use url::Url;
 
 pub const ENHANCED_WEBSOCKET_URL_MAINNET: &str = "wss://atlas-mainnet.helius-rpc.com/?api-key=";
 pub const ENHANCED_WEBSOCKET_URL_DEVNET: &str = "wss://atlas-devnet.helius-rpc.com/?api-key=";
@@ -59,7 +64,7 @@ impl EnhancedWebsocket {
     ///
     /// # Arguments
     ///
-    /// * `cluster` - The Solana cluster to connect to (MainnetBeta or Devnet)
+    /// * `cluster` - The Solana cluster to connect to
     /// * `api_key` - Your Helius API key
     ///
     /// # Returns
@@ -68,14 +73,14 @@ impl EnhancedWebsocket {
     ///
     /// # Errors
     ///
-    /// Returns `HeliusError::EnhancedWebsocket` if the specified cluster is not MainnetBeta or Devnet.
+    /// Returns `HeliusError::EnhancedWebsocket` if the specified cluster does not have a websocket endpoint.
     /// Note: StakedMainnetBeta is not supported for websocket connections.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use helius::websocket::EnhancedWebsocket;
-    /// use helius::types::Cluster;
+    /// use helius::types::{Cluster, HeliusEndpoints};
     ///
     /// let api_key = "your_api_key";
     ///
@@ -89,24 +94,70 @@ impl EnhancedWebsocket {
     /// println!("Devnet URL: {}", devnet_url);
     /// assert!(devnet_url.eq("wss://atlas-devnet.helius-rpc.com/?api-key=your_api_key"));
     ///
+    /// // For a local test validator
+    /// let localnet_url = EnhancedWebsocket::get_url(&Cluster::Localnet, api_key).expect("Failed to get URL");
+    /// assert!(localnet_url.eq("ws://127.0.0.1:8900/?api-key=your_api_key"));
+    ///
+    /// // For custom endpoints
+    /// let custom = Cluster::Custom(HeliusEndpoints {
+    ///     api: "https://api.example.com/".to_string(),
+    ///     rpc: "https://rpc.example.com/".to_string(),
+    ///     websocket: Some("wss://ws.example.com/".to_string()),
+    ///     sender: None,
+    /// });
+    /// let custom_url = EnhancedWebsocket::get_url(&custom, api_key).expect("Failed to get URL");
+    /// assert!(custom_url.eq("wss://ws.example.com/?api-key=your_api_key"));
+    ///
     /// // For Staked Mainnet (will error)
     /// let staked_result = EnhancedWebsocket::get_url(&Cluster::StakedMainnetBeta, api_key);
     /// assert!(staked_result.is_err());
     /// ```
     pub fn get_url(cluster: &Cluster, api_key: &str) -> Result<String> {
-        match cluster {
-            Cluster::MainnetBeta => Ok(format!("{}{}", ENHANCED_WEBSOCKET_URL_MAINNET, api_key)),
-            Cluster::Devnet => Ok(format!("{}{}", ENHANCED_WEBSOCKET_URL_DEVNET, api_key)),
-            Cluster::StakedMainnetBeta => Err(HeliusError::EnhancedWebsocket {
+        Self::get_url_for_endpoints(&HeliusEndpoints::for_cluster(cluster), api_key)
+    }
+
+    /// Constructs the complete websocket URL from a set of endpoints by appending the API key to its websocket endpoint
+    ///
+    /// # Errors
+    ///
+    /// Returns `HeliusError::EnhancedWebsocket` if the endpoints do not include a websocket endpoint, or
+    /// `HeliusError::UrlParseError` if the websocket endpoint is not a valid URL
+    pub fn get_url_for_endpoints(endpoints: &HeliusEndpoints, api_key: &str) -> Result<String> {
+        let websocket: &str = endpoints
+            .websocket
+            .as_deref()
+            .ok_or_else(|| HeliusError::EnhancedWebsocket {
                 reason: "Unsupported cluster".into(),
-                message: "only mainnet and devnet are supported".into(),
-            }),
-        }
+                message: "no enhanced websocket endpoint is configured for this cluster".into(),
+            })?;
+
+        let mut url: Url = Url::parse(websocket)?;
+        url.query_pairs_mut().append_pair("api-key", api_key);
+        Ok(url.to_string())
     }
 
     /// Expects enhanced websocket endpoint: wss://atlas-mainnet.helius-rpc.com?api-key=<API_KEY>
-    pub async fn new(url: &str, ping_interval_secs: Option<u64>, pong_timeout_secs: Option<u64>) -> Result<Self> {
-        let (ws, _response) = connect_async(url).await.map_err(HeliusError::Tungstenite)?;
+    ///
+    /// A handshake `Request` can be passed instead of a URL to send additional headers, such as the `X-Api-Key` header
+    pub async fn new<R>(request: R, ping_interval_secs: Option<u64>, pong_timeout_secs: Option<u64>) -> Result<Self>
+    where
+        R: IntoClientRequest + Unpin,
+    {
+        Self::new_with_metrics(request, ping_interval_secs, pong_timeout_secs, None).await
+    }
+
+    /// Connects like `new`, reporting the latency and outcome of each subscription, along with each notification
+    /// received, to the given `MetricsSink`
+    pub async fn new_with_metrics<R>(
+        request: R,
+        ping_interval_secs: Option<u64>,
+        pong_timeout_secs: Option<u64>,
+        metrics: Option<Arc<dyn MetricsSink>>,
+    ) -> Result<Self>
+    where
+        R: IntoClientRequest + Unpin,
+    {
+        let (ws, _response) = connect_async(request).await.map_err(HeliusError::Tungstenite)?;
 
         let (subscribe_sender, subscribe_receiver) = mpsc::unbounded_channel();
         let (_request_sender, request_receiver) = mpsc::unbounded_channel();
@@ -136,6 +187,7 @@ impl EnhancedWebsocket {
                 shutdown_receiver,
                 ping_interval,
                 max_failed_pings,
+                metrics,
             )),
         })
     }
@@ -167,11 +219,9 @@ impl EnhancedWebsocket {
         Ok((
             UnboundedReceiverStream::new(notifications)
                 .filter_map(|value| match serde_json::from_value::<T>(value.clone()) {
-                    Err(e) => {
-                        eprintln!(
-                            "Failed to parse websocket notification: {:#?} for value: {:#?}",
-                            e, value
-                        );
+                    Err(_err) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(error = %_err, value = %value, "failed to parse websocket notification");
                         ready(None)
                     }
                     Ok(res) => ready(Some(res)),
@@ -257,6 +307,7 @@ impl EnhancedWebsocket {
         mut shutdown_receiver: oneshot::Receiver<()>,
         ping_duration_seconds: u64,
         max_failed_pings: usize,
+        metrics: Option<Arc<dyn MetricsSink>>,
     ) -> Result<()> {
         let mut request_id: u64 = 0;
         let mut unmatched_pings: usize = 0;
@@ -301,8 +352,10 @@ impl EnhancedWebsocket {
                 request_id += 1;
                 let method = format!("{operation}Subscribe");
                 let body = json!({"jsonrpc":"2.0","id":request_id,"method":method,"params":params});
+                #[cfg(feature = "tracing")]
+                tracing::debug!(request_id, method = %method, "sending websocket subscription");
                 ws.send(body.to_string().into()).await?;
-                requests_subscribe.insert(request_id, (operation, response_sender));
+                requests_subscribe.insert(request_id, (operation, response_sender, Instant::now()));
               },
               // Read message for unsubscribe
               Some((operation, sid, response_sender)) = unsubscribe_receiver.recv() => {
@@ -331,6 +384,9 @@ impl EnhancedWebsocket {
                 unmatched_pings = 0;
 
                 // Get text from the message
+                #[cfg(feature = "tracing")]
+                tracing::trace!(message = ?msg, "received websocket frame");
+
                 let text = match msg {
                   Message::Text(text) => text,
                   Message::Binary(_data) => continue, // Ignore
@@ -383,10 +439,14 @@ impl EnhancedWebsocket {
                     }
                   } else if let Some(response_sender) = requests_unsubscribe.remove(&id) {
                     let _ = response_sender.send(()); // do not care if receiver is closed
-                  } else if let Some((operation, response_sender)) = requests_subscribe.remove(&id) {
+                  } else if let Some((operation, response_sender, started)) = requests_subscribe.remove(&id) {
                     match err {
                       Some(reason) => {
-                        let _ = response_sender.send(Err(HeliusError::EnhancedWebsocket { reason, message: text.as_str().to_string() }));
+                        let error = HeliusError::EnhancedWebsocket { reason, message: text.as_str().to_string() };
+                        if let Some(metrics) = &metrics {
+                          metrics.record_operation(&format!("{operation}Subscribe"), started.elapsed(), Some(&error));
+                        }
+                        let _ = response_sender.send(Err(error));
                       },
                       None => {
                         // Subscribe Id
@@ -394,6 +454,10 @@ impl EnhancedWebsocket {
                           HeliusError::EnhancedWebsocket { reason: "invalid `result` field".into(), message: text.as_str().to_string() }
                         })?;
 
+                        if let Some(metrics) = &metrics {
+                          metrics.record_operation(&format!("{operation}Subscribe"), started.elapsed(), None);
+                        }
+
                         // Create notifications channel and unsubscribe function
                         let (notifications_sender, notifications_receiver) = mpsc::unbounded_channel();
                         let unsubscribe_sender = unsubscribe_sender.clone();
@@ -412,7 +476,8 @@ impl EnhancedWebsocket {
                       }
                     }
                   } else {
-                      eprintln!("Unknown request id: {}", id);
+                      #[cfg(feature = "tracing")]
+                      tracing::warn!(id, "received a response for an unknown websocket request id");
                       break;
                   }
                   continue;
@@ -420,6 +485,13 @@ impl EnhancedWebsocket {
 
                 // Notification, example:
                 // `{"jsonrpc":"2.0","method":"logsNotification","params":{"result":{...},"subscription":3114862}}`
+                if let Some(metrics) = &metrics {
+                  let method = json.get("method").and_then(Value::as_str).unwrap_or_default();
+                  if let Some(subscription) = method.strip_suffix("Notification") {
+                    metrics.record_notification(subscription);
+                  }
+                }
+
                 if let Some(Value::Object(params)) = json.get_mut("params") {
                   if let Some(sid) = params.get("subscription").and_then(Value::as_u64) {
                     let mut unsubscribe_required = false;
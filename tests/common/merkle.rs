@@ -0,0 +1,174 @@
+//! Compressed asset fixtures for the Merkle proof and Bubblegum tests
+use helius::bubblegum::MetadataArgs;
+use helius::merkle::compute_leaf_hash;
+use helius::types::{Asset, AssetProof};
+
+use serde_json::{json, Value};
+use solana_keccak_hasher::hashv;
+use solana_sdk::{bs58, pubkey::Pubkey};
+
+/// The depth of the fixture's tree
+pub const DEPTH: u32 = 3;
+/// The index of the fixture asset's leaf
+pub const LEAF_INDEX: usize = 5;
+
+pub struct Fixture {
+    pub asset: Asset,
+    pub proof: AssetProof,
+    pub tree: Pubkey,
+}
+
+pub fn encode(hash: &[u8]) -> String {
+    bs58::encode(hash).into_string()
+}
+
+/// Builds a compressed asset with full metadata, and a proof for it in a full tree of depth `DEPTH`
+pub fn fixture(delegate: Option<Pubkey>) -> Fixture {
+    let asset_id: Pubkey = Pubkey::new_unique();
+    let owner: Pubkey = Pubkey::new_unique();
+    let tree: Pubkey = Pubkey::new_unique();
+
+    let mut asset: Value = json!({
+        "interface": "V1_NFT",
+        "id": asset_id.to_string(),
+        "content": {
+            "$schema": "https://schema.metaplex.com/nft1.0.json",
+            "json_uri": "https://example.com/cnft.json",
+            "metadata": { "name": "cNFT #5", "symbol": "CNFT" },
+        },
+        "grouping": [{ "group_key": "collection", "group_value": Pubkey::new_unique().to_string(), "verified": true }],
+        "royalty": {
+            "royalty_model": "creators",
+            "target": null,
+            "percent": 0.05,
+            "basis_points": 500,
+            "primary_sale_happened": false,
+            "locked": false,
+        },
+        "creators": [
+            { "address": Pubkey::new_unique().to_string(), "share": 60, "verified": true },
+            { "address": Pubkey::new_unique().to_string(), "share": 40, "verified": false },
+        ],
+        "ownership": {
+            "frozen": false,
+            "delegated": delegate.is_some(),
+            "delegate": delegate.map(|delegate| delegate.to_string()),
+            "ownership_model": "single",
+            "owner": owner.to_string(),
+        },
+        "supply": { "print_max_supply": 0, "print_current_supply": 0, "edition_nonce": 254 },
+        "mutable": true,
+        "burnt": false,
+    });
+    let metadata: MetadataArgs = MetadataArgs::from_asset(&serde_json::from_value(asset.clone()).unwrap()).unwrap();
+    let data_hash: [u8; 32] = metadata.data_hash().unwrap();
+    let creator_hash: [u8; 32] = metadata.creator_hash();
+
+    let leaf: [u8; 32] = compute_leaf_hash(
+        &asset_id,
+        &owner,
+        &delegate.unwrap_or(owner),
+        LEAF_INDEX as u64,
+        &data_hash,
+        &creator_hash,
+    );
+    let mut level: Vec<[u8; 32]> = (0..1 << DEPTH)
+        .map(|i: usize| {
+            if i == LEAF_INDEX {
+                leaf
+            } else {
+                hashv(&[&i.to_le_bytes()]).to_bytes()
+            }
+        })
+        .collect();
+    let mut proof: Vec<String> = Vec::new();
+    let mut index: usize = LEAF_INDEX;
+    while level.len() > 1 {
+        proof.push(encode(&level[index ^ 1]));
+        level = level
+            .chunks(2)
+            .map(|pair| hashv(&[&pair[0], &pair[1]]).to_bytes())
+            .collect();
+        index /= 2;
+    }
+
+    asset["compression"] = json!({
+        "eligible": false,
+        "compressed": true,
+        "data_hash": encode(&data_hash),
+        "creator_hash": encode(&creator_hash),
+        "asset_hash": encode(&leaf),
+        "tree": tree.to_string(),
+        "seq": 6,
+        "leaf_id": LEAF_INDEX,
+    });
+
+    Fixture {
+        asset: serde_json::from_value(asset).unwrap(),
+        proof: AssetProof {
+            root: encode(&level[0]),
+            proof,
+            node_index: (1 << DEPTH) + LEAF_INDEX as i32,
+            leaf: encode(&leaf),
+            tree_id: tree.to_string(),
+        },
+        tree,
+    }
+}
+
+/// A mainnet Helium hotspot cNFT as `getAsset` returns it, from the response recorded in `test_get_asset_batch`
+pub fn helium_hotspot() -> Asset {
+    serde_json::from_value(json!({
+        "interface": "V1_NFT",
+        "id": "81bxPqYCE8j34nQm7Rooqi8Vt3iMHLzgZJ71rUVbQQuz",
+        "content": {
+            "$schema": "https://schema.metaplex.com/nft1.0.json",
+            "json_uri": "https://entities.nft.helium.io/112vepK9Upx7juv4pCdwqzm7reBo6VYEQHKRNmU6PEaKX1ese5NE",
+            "files": [{
+                "uri": "https://shdw-drive.genesysgo.net/CsDkETHRRR1EcueeN346MJoqzymkkr7RFjMqGpZMzAib/hotspot.png",
+                "mime": "image/png",
+            }],
+            "metadata": {
+                "attributes": [
+                    { "value": "112vepK9Upx7juv4pCdwqzm7reBo6VYEQHKRNmU6PEaKX1ese5NE", "trait_type": "ecc_compact" },
+                    { "value": true, "trait_type": "rewardable" },
+                ],
+                "description": "A hotspot NFT on Helium",
+                "name": "gentle-mandarin-ferret",
+                "symbol": "HOTSPOT",
+            },
+        },
+        "authorities": [{ "address": "AngjrikiSwNAqed9GEZrXW9eyyptqiwkWtXgw5hkJ1jM", "scopes": ["full"] }],
+        "compression": {
+            "eligible": false,
+            "compressed": true,
+            "data_hash": "D4yd9ePcnVX2uKujrC4NSCFNVsNTUB1gKUq2bnouR8Ba",
+            "creator_hash": "GuBHQ5zhrHdkADX8ttpGvSYPQB2M6K5Zcj2qq5HogPgQ",
+            "asset_hash": "DE2bb82qfupE4Wcv28brKXCKztnEKC2XnwxAmN8LzqPe",
+            "tree": "E7JbdYSZLvVmqvw6XfEwNQCEAybTF6E1mnrHUXw8hXDV",
+            "seq": 29627,
+            "leaf_id": 29584,
+        },
+        "grouping": [{ "group_key": "collection", "group_value": "FC2daoG1bnBPHGBypxPLb8hU8dtQdx7cYYsU9RR4eYmf" }],
+        "royalty": {
+            "royalty_model": "creators",
+            "target": null,
+            "percent": 0.0,
+            "basis_points": 0,
+            "primary_sale_happened": true,
+            "locked": false,
+        },
+        "creators": [{ "address": "Fv5hf1Fg58htfC7YEXKNEfkpuogUUQDDTLgjGWxxv48H", "share": 100, "verified": true }],
+        "ownership": {
+            "frozen": false,
+            "delegated": false,
+            "delegate": null,
+            "ownership_model": "single",
+            "owner": "9gGP3HLonuAiE5Xqb87sEua6SY3uHmTPuobbxPsLUV4u",
+        },
+        "supply": { "print_max_supply": 0, "print_current_supply": 0, "edition_nonce": null },
+        "mutable": true,
+        "burnt": false,
+    }))
+    .unwrap()
+}
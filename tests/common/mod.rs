@@ -0,0 +1,43 @@
+//! Helpers shared by the integration test crates
+// Each test crate only uses some of the helpers
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use helius::retry::RetryPolicy;
+use helius::types::{Cluster, HeliusEndpoints};
+use helius::Helius;
+
+#[cfg(feature = "das")]
+pub mod merkle;
+
+/// A client that sends every request to the mock server at `url`
+pub fn helius_for(url: &str) -> Helius {
+    Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: url.to_string(),
+            rpc: url.to_string(),
+            ..Default::default()
+        }))
+        .build()
+        .unwrap()
+}
+
+/// Like `helius_for`, but retries idempotent requests without waiting between attempts
+pub fn retrying_helius_for(url: &str) -> Helius {
+    Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: url.to_string(),
+            rpc: url.to_string(),
+            ..Default::default()
+        }))
+        .retry_policy(
+            RetryPolicy::default()
+                .with_initial_backoff(Duration::from_millis(1))
+                .with_jitter(false),
+        )
+        .build()
+        .unwrap()
+}
@@ -0,0 +1,112 @@
+use crate::common::helius_for;
+use helius::batch::{BatchCall, BatchResponse, RpcBatch};
+use helius::client::Helius;
+use helius::error::HeliusError;
+use helius::types::*;
+
+use mockito::{self, Matcher, Server};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_batch_routes_responses_to_typed_results() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    // Responses are returned out of order to ensure they are matched by ID
+    let response = json!([
+        {
+            "jsonrpc": "2.0",
+            "id": "2",
+            "result": { "priorityFeeEstimate": 1500.0 }
+        },
+        {
+            "jsonrpc": "2.0",
+            "id": "1",
+            "error": { "code": -32602, "message": "Invalid params" }
+        },
+        {
+            "jsonrpc": "2.0",
+            "id": "0",
+            "result": { "total": 0, "limit": 10, "token_accounts": [] }
+        }
+    ]);
+
+    let mock = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(json!([
+            { "id": "0", "method": "getTokenAccounts" },
+            { "id": "1", "method": "getAsset" },
+            { "id": "2", "method": "getPriorityFeeEstimate" }
+        ])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(response.to_string())
+        .expect(1)
+        .create();
+
+    let helius: Helius = helius_for(&server.url());
+    let mut batch: RpcBatch = helius.rpc().batch();
+
+    let accounts: BatchCall<TokenAccountsList> = batch
+        .get_token_accounts(GetTokenAccounts {
+            owner: Some("owner".to_string()),
+            limit: Some(10),
+            ..Default::default()
+        })
+        .unwrap();
+    let asset: BatchCall<Option<Asset>> = batch
+        .get_asset(GetAsset {
+            id: "invalid".to_string(),
+            display_options: None,
+        })
+        .unwrap();
+    let fees: BatchCall<GetPriorityFeeEstimateResponse> = batch
+        .get_priority_fee_estimate(GetPriorityFeeEstimateRequest::default())
+        .unwrap();
+    assert_eq!(batch.len(), 3);
+
+    let response: BatchResponse = batch.send().await.unwrap();
+    mock.assert();
+
+    assert_eq!(response.get(&accounts).unwrap().limit, 10);
+    assert_eq!(response.get(&fees).unwrap().priority_fee_estimate, Some(1500.0));
+    assert!(matches!(
+        response.get(&asset),
+        Err(HeliusError::Rpc { code: -32602, .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_batch_rejected_as_a_whole() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"jsonrpc":"2.0","id":null,"error":{"code":-32600,"message":"Invalid request"}}"#)
+        .create();
+
+    let helius: Helius = helius_for(&server.url());
+    let mut batch: RpcBatch = helius.rpc().batch();
+    batch
+        .get_asset(GetAsset {
+            id: "asset".to_string(),
+            display_options: None,
+        })
+        .unwrap();
+
+    assert!(matches!(batch.send().await, Err(HeliusError::Rpc { code: -32600, .. })));
+}
+
+#[tokio::test]
+async fn test_empty_batch_is_not_sent() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    let mock = server.mock("POST", Matcher::Any).expect(0).create();
+
+    let helius: Helius = helius_for(&server.url());
+    let response: BatchResponse = helius.rpc().batch().send().await.unwrap();
+
+    assert!(response.is_empty());
+    mock.assert();
+}
@@ -0,0 +1,230 @@
+use crate::common::helius_for;
+use helius::error::{HeliusError, Result};
+use helius::pagination::PaginationOptions;
+use helius::types::*;
+use helius::Helius;
+
+use futures_util::StreamExt;
+use mockito::{self, Matcher, Server};
+use serde_json::{json, Value};
+
+const OWNER: &str = "86xCnPeV69n6t3DnyGvkKobf9FdN2H9oiVDdaMpo2MMY";
+const MASTER_MINT: &str = "Ey2Qb8kLctbchQsMnhZs5DjY32To2QtPuXNwWvk4NosL";
+
+fn asset(id: &str) -> Value {
+    json!({
+        "interface": "V1_NFT",
+        "id": id,
+        "ownership": {
+            "frozen": false,
+            "delegated": false,
+            "delegate": null,
+            "ownership_model": "single",
+            "owner": OWNER,
+        },
+        "mutable": true,
+        "burnt": false,
+        "mint_extensions": null,
+        "token_info": null,
+    })
+}
+
+fn asset_page(ids: &[&str], cursor: Option<&str>) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": "1",
+        "result": {
+            "total": ids.len(),
+            "limit": 2,
+            "cursor": cursor,
+            "items": ids.iter().map(|id| asset(id)).collect::<Vec<Value>>(),
+        },
+    })
+    .to_string()
+}
+
+fn editions_page(page: u32, mints: &[&str]) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": "1",
+        "result": {
+            "total": mints.len(),
+            "limit": 2,
+            "page": page,
+            "master_edition_address": "8SHfqzJYABeGfiG1apwiEYt6TvfGQiL1pdwEjvTKsyiZ",
+            "supply": 5,
+            "max_supply": 10,
+            "editions": mints
+                .iter()
+                .map(|mint| json!({ "mint": mint, "edition_address": mint, "edition": 1 }))
+                .collect::<Vec<Value>>(),
+        },
+    })
+    .to_string()
+}
+
+fn owner_request() -> GetAssetsByOwner {
+    GetAssetsByOwner {
+        owner_address: OWNER.to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_assets_stream_follows_cursors() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let pages = [
+        (Value::Null, asset_page(&["asset-1", "asset-2"], Some("cursor-1"))),
+        (json!("cursor-1"), asset_page(&["asset-3"], Some("cursor-2"))),
+        (json!("cursor-2"), asset_page(&[], None)),
+    ]
+    .into_iter()
+    .map(|(cursor, body)| {
+        server
+            .mock("POST", "/?api-key=fake_api_key")
+            .match_body(Matcher::PartialJson(json!({
+                "method": "getAssetsByOwner",
+                "params": { "limit": 2, "cursor": cursor },
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .expect(1)
+            .create()
+    })
+    .collect::<Vec<_>>();
+
+    let helius: Helius = helius_for(&url);
+    let assets: Vec<Result<Asset>> = helius
+        .rpc()
+        .get_assets_by_owner_stream(owner_request(), PaginationOptions::default().with_page_size(2))
+        .collect()
+        .await;
+
+    let ids: Vec<String> = assets.into_iter().map(|asset| asset.unwrap().id).collect();
+    assert_eq!(ids, vec!["asset-1", "asset-2", "asset-3"]);
+    pages.iter().for_each(|page| page.assert());
+}
+
+#[test]
+fn test_cursor_requests_omit_page() {
+    let by_owner: Value = serde_json::to_value(GetAssetsByOwner {
+        cursor: Some("cursor-1".to_string()),
+        ..owner_request()
+    })
+    .unwrap();
+    assert_eq!(by_owner["cursor"], "cursor-1");
+    assert!(by_owner.get("page").is_none());
+
+    let by_authority: Value = serde_json::to_value(GetAssetsByAuthority {
+        authority_address: OWNER.to_string(),
+        cursor: Some("cursor-1".to_string()),
+        ..Default::default()
+    })
+    .unwrap();
+    assert!(by_authority.get("page").is_none());
+
+    // Page numbers are still sent when paginating by page
+    let paged: Value = serde_json::to_value(GetAssetsByOwner {
+        page: 2,
+        ..owner_request()
+    })
+    .unwrap();
+    assert_eq!(paged["page"], 2);
+}
+
+#[tokio::test]
+async fn test_stream_falls_back_to_page_numbers() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let pages = [
+        (Value::Null, editions_page(1, &["mint-1", "mint-2"])),
+        (json!(2), editions_page(2, &["mint-3", "mint-4"])),
+        (json!(3), editions_page(3, &["mint-5"])),
+    ]
+    .into_iter()
+    .map(|(page, body)| {
+        server
+            .mock("POST", "/?api-key=fake_api_key")
+            .match_body(Matcher::PartialJson(json!({
+                "method": "getNftEditions",
+                "params": { "limit": 2, "page": page },
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .expect(1)
+            .create()
+    })
+    .collect::<Vec<_>>();
+
+    let helius: Helius = helius_for(&url);
+    let editions: Vec<Result<Edition>> = helius
+        .rpc()
+        .get_nft_editions_stream(
+            GetNftEditions {
+                mint: Some(MASTER_MINT.to_string()),
+                ..Default::default()
+            },
+            PaginationOptions::default().with_page_size(2),
+        )
+        .collect()
+        .await;
+
+    let mints: Vec<String> = editions.into_iter().map(|edition| edition.unwrap().mint).collect();
+    assert_eq!(mints, vec!["mint-1", "mint-2", "mint-3", "mint-4", "mint-5"]);
+    pages.iter().for_each(|page| page.assert());
+}
+
+#[tokio::test]
+async fn test_stream_stops_at_max_items() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    // The page size is capped to the items still wanted, and no further page is fetched
+    let mock = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(json!({ "params": { "limit": 1 } })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(asset_page(&["asset-1"], Some("cursor-1")))
+        .expect(1)
+        .create();
+
+    let helius: Helius = helius_for(&url);
+    let assets: Vec<Result<Asset>> = helius
+        .rpc()
+        .get_assets_by_owner_stream(owner_request(), PaginationOptions::default().with_max_items(1))
+        .collect()
+        .await;
+
+    assert_eq!(assets.len(), 1);
+    assert_eq!(assets[0].as_ref().unwrap().id, "asset-1");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_stream_ends_with_error() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(400)
+        .with_body(r#"{"error": "invalid owner"}"#)
+        .expect(1)
+        .create();
+
+    let helius: Helius = helius_for(&url);
+    let assets: Vec<Result<Asset>> = helius
+        .rpc()
+        .get_assets_by_owner_stream(owner_request(), PaginationOptions::default())
+        .collect()
+        .await;
+
+    assert_eq!(assets.len(), 1);
+    assert!(matches!(assets[0], Err(HeliusError::BadRequest { .. })));
+}
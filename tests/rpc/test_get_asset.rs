@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use helius::client::Helius;
-use helius::config::Config;
+use helius::config::{AuthMethod, Config};
 use helius::error::Result;
 use helius::rpc_client::RpcClient;
 use helius::types::*;
@@ -159,11 +159,14 @@ async fn test_get_asset_success() {
         .create();
 
     let config: Arc<Config> = Arc::new(Config {
-        api_key: "fake_api_key".to_string(),
+        api_key: "fake_api_key".into(),
+        auth: AuthMethod::QueryParam,
         cluster: Cluster::Devnet,
         endpoints: HeliusEndpoints {
             api: url.to_string(),
             rpc: url.to_string(),
+            websocket: None,
+            sender: None,
         },
     });
 
@@ -216,11 +219,14 @@ async fn test_get_asset_failure() {
         .create();
 
     let config: Arc<Config> = Arc::new(Config {
-        api_key: "fake_api_key".to_string(),
+        api_key: "fake_api_key".into(),
+        auth: AuthMethod::QueryParam,
         cluster: Cluster::Devnet,
         endpoints: HeliusEndpoints {
             api: url.to_string(),
             rpc: url.to_string(),
+            websocket: None,
+            sender: None,
         },
     });
 
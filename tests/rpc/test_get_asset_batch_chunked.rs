@@ -0,0 +1,158 @@
+use crate::common::helius_for;
+use helius::batch::{ChunkOptions, ChunkedAssetBatch, ChunkedAssetProofBatch};
+use helius::error::{HeliusError, Result};
+use helius::types::*;
+use helius::Helius;
+
+use mockito::{self, Matcher, Mock, Server};
+use serde_json::{json, Value};
+
+const OWNER: &str = "86xCnPeV69n6t3DnyGvkKobf9FdN2H9oiVDdaMpo2MMY";
+
+fn asset(id: &str) -> Value {
+    json!({
+        "interface": "V1_NFT",
+        "id": id,
+        "ownership": {
+            "frozen": false,
+            "delegated": false,
+            "delegate": null,
+            "ownership_model": "single",
+            "owner": OWNER,
+        },
+        "mutable": true,
+        "burnt": false,
+        "mint_extensions": null,
+        "token_info": null,
+    })
+}
+
+fn proof(id: &str) -> Value {
+    json!({
+        "root": format!("root-{id}"),
+        "proof": ["node-1", "node-2"],
+        "node_index": 16384,
+        "leaf": format!("leaf-{id}"),
+        "tree_id": "tree",
+    })
+}
+
+fn mock_batch(server: &mut Server, method: &str, ids: &[&str], result: Value) -> Mock {
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(
+            json!({ "method": method, "params": { "ids": ids } }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "id": "1", "result": result }).to_string())
+        .expect(1)
+        .create()
+}
+
+#[tokio::test]
+async fn test_get_asset_batch_chunked_keeps_input_order() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let mocks: Vec<Mock> = vec![
+        mock_batch(
+            &mut server,
+            "getAssetBatch",
+            &["asset-1", "asset-2"],
+            json!([asset("asset-1"), null]),
+        ),
+        mock_batch(
+            &mut server,
+            "getAssetBatch",
+            &["asset-3", "asset-4"],
+            json!([asset("asset-3"), asset("asset-4")]),
+        ),
+        // A short response still lines up with the IDs it was asked for
+        mock_batch(&mut server, "getAssetBatch", &["asset-5"], json!([])),
+    ];
+
+    let helius: Helius = helius_for(&url);
+    let request: GetAssetBatch = GetAssetBatch {
+        ids: (1..=5).map(|i| format!("asset-{i}")).collect(),
+        display_options: None,
+    };
+    let result: ChunkedAssetBatch = helius
+        .rpc()
+        .get_asset_batch_chunked(request, ChunkOptions::default().with_chunk_size(2).with_concurrency(3))
+        .await
+        .unwrap();
+
+    let ids: Vec<Option<&str>> = result
+        .assets
+        .iter()
+        .map(|asset| asset.as_ref().map(|asset| asset.id.as_str()))
+        .collect();
+    assert_eq!(ids, vec![Some("asset-1"), None, Some("asset-3"), Some("asset-4"), None]);
+    assert_eq!(result.missing, vec!["asset-2", "asset-5"]);
+    assert!(!result.is_complete());
+    mocks.iter().for_each(Mock::assert);
+}
+
+#[tokio::test]
+async fn test_get_asset_proof_batch_chunked_reports_missing_ids() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let mocks: Vec<Mock> = vec![
+        mock_batch(
+            &mut server,
+            "getAssetProofBatch",
+            &["asset-1", "asset-2"],
+            json!({ "asset-1": proof("asset-1"), "asset-2": null }),
+        ),
+        // Proofs the server leaves out entirely are reported as missing too
+        mock_batch(&mut server, "getAssetProofBatch", &["asset-3"], json!({})),
+    ];
+
+    let helius: Helius = helius_for(&url);
+    let request: GetAssetProofBatch = GetAssetProofBatch {
+        ids: vec![
+            "asset-1".to_string(),
+            "asset-2".to_string(),
+            "asset-1".to_string(),
+            "asset-3".to_string(),
+        ],
+    };
+    let result: ChunkedAssetProofBatch = helius
+        .rpc()
+        .get_asset_proof_batch_chunked(request, ChunkOptions::default().with_chunk_size(2))
+        .await
+        .unwrap();
+
+    assert_eq!(result.proofs.len(), 1);
+    assert_eq!(result.proofs["asset-1"].leaf, "leaf-asset-1");
+    assert_eq!(result.missing, vec!["asset-2", "asset-3"]);
+    mocks.iter().for_each(Mock::assert);
+}
+
+#[tokio::test]
+async fn test_get_asset_batch_chunked_fails_with_first_error() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    mock_batch(&mut server, "getAssetBatch", &["asset-1"], json!([asset("asset-1")]));
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(json!({ "params": { "ids": ["asset-2"] } })))
+        .with_status(400)
+        .with_body(r#"{"error": "invalid id"}"#)
+        .create();
+
+    let helius: Helius = helius_for(&url);
+    let request: GetAssetBatch = GetAssetBatch {
+        ids: vec!["asset-1".to_string(), "asset-2".to_string()],
+        display_options: None,
+    };
+    let result: Result<ChunkedAssetBatch> = helius
+        .rpc()
+        .get_asset_batch_chunked(request, ChunkOptions::default().with_chunk_size(1))
+        .await;
+
+    assert!(matches!(result, Err(HeliusError::BadRequest { .. })));
+}
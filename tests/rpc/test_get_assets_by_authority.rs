@@ -1,4 +1,4 @@
-use helius::config::Config;
+use helius::config::{AuthMethod, Config};
 use helius::error::Result;
 use helius::rpc_client::RpcClient;
 use helius::types::{
@@ -217,11 +217,14 @@ async fn test_get_assets_by_authority_success() {
         .create();
 
     let config: Arc<Config> = Arc::new(Config {
-        api_key: "fake_api_key".to_string(),
+        api_key: "fake_api_key".into(),
+        auth: AuthMethod::QueryParam,
         cluster: Cluster::Devnet,
         endpoints: HeliusEndpoints {
             api: url.to_string(),
             rpc: url.to_string(),
+            websocket: None,
+            sender: None,
         },
     });
 
@@ -264,11 +267,14 @@ async fn test_get_assets_by_authority_failure() {
         .create();
 
     let config: Arc<Config> = Arc::new(Config {
-        api_key: "fake_api_key".to_string(),
+        api_key: "fake_api_key".into(),
+        auth: AuthMethod::QueryParam,
         cluster: Cluster::Devnet,
         endpoints: HeliusEndpoints {
             api: url.to_string(),
             rpc: url.to_string(),
+            websocket: None,
+            sender: None,
         },
     });
 
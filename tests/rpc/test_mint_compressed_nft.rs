@@ -0,0 +1,179 @@
+use crate::common::{helius_for, retrying_helius_for};
+use helius::error::HeliusError;
+use helius::mint_api::{collection_authority_record_address, metadata_address, TOKEN_METADATA_PROGRAM_ID};
+use helius::types::*;
+use helius::Helius;
+
+use mockito::{self, Matcher, Server};
+use serde_json::{json, Value};
+use solana_sdk::{bs58, instruction::Instruction, pubkey::Pubkey};
+
+fn account_info(value: Value) -> String {
+    json!({ "jsonrpc": "2.0", "id": 1, "result": { "context": { "slot": 1 }, "value": value } }).to_string()
+}
+
+#[tokio::test]
+async fn test_mint_compressed_nft_success() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+    let collection: Pubkey = Pubkey::new_unique();
+
+    let mock = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(json!({
+            "method": "mintCompressedNft",
+            "params": {
+                "name": "Exodia the Forbidden One",
+                "owner": "DCQnfUH6mHA333mzkU22b4hMvyqcejUBociodq8bB5HF",
+                "collection": collection.to_string(),
+                "sellerFeeBasisPoints": 6900,
+            },
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": "1",
+                "result": {
+                    "signature": "4FRxjAvHLGCEUXJqkucG7SdKrrmAeqjfTQKmhMZWvqGvkPGvWeZXY8Vfj8oxXtGnf7q3n4n8XHVDsExkpCtAEdKj",
+                    "minted": true,
+                    "assetId": "JCfTS6dmJZY4NXhjMwHqayGGHUwxp59pzcYhZrYqMBce",
+                },
+            })
+            .to_string(),
+        )
+        .create();
+
+    let helius: Helius = helius_for(&url);
+    let request: MintCompressedNftRequest = MintCompressedNftRequest {
+        name: "Exodia the Forbidden One".to_string(),
+        symbol: "ETFO".to_string(),
+        owner: "DCQnfUH6mHA333mzkU22b4hMvyqcejUBociodq8bB5HF".to_string(),
+        description: "Exodia the Forbidden One is a powerful, legendary creature".to_string(),
+        collection: Some(collection.to_string()),
+        seller_fee_basis_points: Some(6900),
+        ..Default::default()
+    };
+    let response: MintResponse = helius.rpc().mint_compressed_nft(request).await.unwrap();
+
+    assert!(response.minted);
+    assert_eq!(
+        response.asset_id.as_deref(),
+        Some("JCfTS6dmJZY4NXhjMwHqayGGHUwxp59pzcYhZrYqMBce")
+    );
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_mint_compressed_nft_is_not_retried() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let mock = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(json!({ "method": "mintCompressedNft" })))
+        .with_status(503)
+        .expect(1)
+        .create();
+
+    let helius: Helius = retrying_helius_for(&url);
+    let request: MintCompressedNftRequest = MintCompressedNftRequest {
+        name: "Exodia the Forbidden One".to_string(),
+        symbol: "ETFO".to_string(),
+        owner: "DCQnfUH6mHA333mzkU22b4hMvyqcejUBociodq8bB5HF".to_string(),
+        description: "Exodia the Forbidden One is a powerful, legendary creature".to_string(),
+        ..Default::default()
+    };
+    let response: Result<MintResponse, HeliusError> = helius.rpc().mint_compressed_nft(request).await;
+
+    assert!(response.is_err());
+    mock.assert();
+}
+
+#[test]
+fn test_delegate_collection_to_mint_api() {
+    let helius: Helius = Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(Cluster::Devnet)
+        .build()
+        .unwrap();
+    let mint_api_authority: Pubkey = helius.config.mint_api_authority().unwrap().into();
+    let collection: Pubkey = Pubkey::new_unique();
+    let update_authority: Pubkey = Pubkey::new_unique();
+
+    let approve: Instruction = helius
+        .delegate_collection_to_mint_api(&collection, &update_authority, &update_authority)
+        .unwrap();
+    assert_eq!(approve.program_id, TOKEN_METADATA_PROGRAM_ID);
+    assert_eq!(approve.data, vec![23]);
+    assert_eq!(
+        approve.accounts[0].pubkey,
+        collection_authority_record_address(&collection, &mint_api_authority)
+    );
+    assert_eq!(approve.accounts[1].pubkey, mint_api_authority);
+    assert!(approve.accounts[2].is_signer && approve.accounts[3].is_signer);
+    assert_eq!(approve.accounts[4].pubkey, metadata_address(&collection));
+    assert_eq!(approve.accounts[5].pubkey, collection);
+
+    let revoke: Instruction = helius
+        .revoke_mint_api_collection_delegate(&collection, &update_authority)
+        .unwrap();
+    assert_eq!(revoke.data, vec![24]);
+    assert_eq!(revoke.accounts[0].pubkey, approve.accounts[0].pubkey);
+    assert_eq!(revoke.accounts[1].pubkey, mint_api_authority);
+    assert!(revoke.accounts[2].is_signer);
+
+    // The Mint API only runs on mainnet and devnet, so there's no authority to delegate to elsewhere
+    let localnet: Helius = helius_for("http://localhost:8899");
+    assert!(matches!(
+        localnet.delegate_collection_to_mint_api(&collection, &update_authority, &update_authority),
+        Err(HeliusError::InvalidInput(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_is_collection_delegated_to_mint_api() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let helius: Helius = Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(Cluster::Devnet)
+        .rpc_url(server.url())
+        .build()
+        .unwrap();
+
+    let collection: Pubkey = Pubkey::new_unique();
+    let delegated: Pubkey = Pubkey::new_unique();
+    let record = |collection: &Pubkey| {
+        collection_authority_record_address(collection, &helius.config.mint_api_authority().unwrap().into()).to_string()
+    };
+
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(
+            json!({ "method": "getAccountInfo", "params": [record(&collection)] }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(account_info(Value::Null))
+        .create();
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(
+            json!({ "method": "getAccountInfo", "params": [record(&delegated)] }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(account_info(json!({
+            "data": [bs58::encode([11, 0]).into_string(), "base58"],
+            "executable": false,
+            "lamports": 1_398_960,
+            "owner": TOKEN_METADATA_PROGRAM_ID.to_string(),
+            "rentEpoch": 0,
+            "space": 2,
+        })))
+        .create();
+
+    assert!(!helius.is_collection_delegated_to_mint_api(&collection).await.unwrap());
+    assert!(helius.is_collection_delegated_to_mint_api(&delegated).await.unwrap());
+}
@@ -0,0 +1,149 @@
+use crate::common::{helius_for, retrying_helius_for};
+use helius::client::Helius;
+use helius::error::{HeliusError, Result};
+use helius::types::*;
+
+use mockito::{self, Server};
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn test_json_rpc_error_is_returned_as_rpc_error() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    let body = json!({
+        "jsonrpc": "2.0",
+        "id": "helius-rust-sdk",
+        "error": {
+            "code": -32002,
+            "message": "Transaction simulation failed",
+            "data": { "logs": ["Program log: insufficient funds"] }
+        }
+    });
+
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body.to_string())
+        .create();
+
+    let helius: Helius = helius_for(&server.url());
+    let response: Result<Option<Asset>> = helius
+        .rpc()
+        .get_asset(GetAsset {
+            id: "asset".to_string(),
+            display_options: None,
+        })
+        .await;
+
+    let error: HeliusError = response.unwrap_err();
+    assert_eq!(error.rpc_code(), Some(-32002));
+    match error {
+        HeliusError::Rpc { code, message, data } => {
+            assert_eq!(code, -32002);
+            assert_eq!(message, "Transaction simulation failed");
+            assert_eq!(data.unwrap()["logs"][0], "Program log: insufficient funds");
+        }
+        other => panic!("Expected Rpc error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_json_rpc_error_without_data() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"jsonrpc":"2.0","id":null,"error":{"code":-32602,"message":"Invalid params"}}"#)
+        .create();
+
+    let helius: Helius = helius_for(&server.url());
+    let response: Result<Option<Asset>> = helius
+        .rpc()
+        .get_asset(GetAsset {
+            id: "asset".to_string(),
+            display_options: None,
+        })
+        .await;
+
+    assert!(matches!(
+        response,
+        Err(HeliusError::Rpc {
+            code: -32602,
+            data: None,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_rpc_response_models_result_and_error() {
+    let success: RpcResponse<Option<u64>> = serde_json::from_str(r#"{"jsonrpc":"2.0","id":1,"result":null}"#).unwrap();
+    assert_eq!(success.id, "1");
+    assert_eq!(success.payload, RpcPayload::Result(None));
+
+    let failure: RpcResponse<Option<u64>> =
+        serde_json::from_str(r#"{"jsonrpc":"2.0","id":"a","error":{"code":-32601,"message":"Method not found"}}"#)
+            .unwrap();
+    assert_eq!(
+        failure.payload,
+        RpcPayload::Error(RpcError {
+            code: -32601,
+            message: "Method not found".to_string(),
+            data: None,
+        })
+    );
+    assert_eq!(failure.into_result().unwrap_err().rpc_code(), Some(-32601));
+}
+
+#[tokio::test]
+async fn test_json_rpc_read_is_retried() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    let unavailable = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(503)
+        .expect(2)
+        .create();
+    let success = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "jsonrpc": "2.0", "id": "1", "result": null }).to_string())
+        .create();
+
+    let helius: Helius = retrying_helius_for(&server.url());
+    let response: Result<Option<Asset>> = helius
+        .rpc()
+        .get_asset(GetAsset {
+            id: "JE9mLqmTRZnUYoMJofSmZp8nZT4pzgARtAJS8crtgVWV".to_string(),
+            display_options: None,
+        })
+        .await;
+
+    assert!(response.unwrap().is_none());
+    unavailable.assert();
+    success.assert();
+}
+
+#[tokio::test]
+async fn test_json_rpc_write_is_not_retried() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    let mock = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(503)
+        .expect(1)
+        .create();
+
+    let helius: Helius = retrying_helius_for(&server.url());
+    let response: Result<Value> = helius
+        .rpc()
+        .post_rpc_request("sendTransaction", vec!["fake_transaction"])
+        .await;
+
+    assert!(response.is_err());
+    mock.assert();
+}
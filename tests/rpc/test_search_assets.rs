@@ -1,16 +1,20 @@
-use helius::config::Config;
-use helius::error::Result;
+use crate::common::helius_for;
+use helius::config::{AuthMethod, Config};
+use helius::error::{HeliusError, Result};
 use helius::rpc_client::RpcClient;
+use helius::search::SearchAssetsQuery;
 use helius::types::{
-    ApiResponse, Asset, AssetList, Attribute, Attributes, Authorities, Cluster, Compression, Content, Creator, File,
-    Group, HeliusEndpoints, Interface, Links, Metadata, Ownership, OwnershipModel, Royalty, RoyaltyModel, Scope,
-    SearchAssets, Supply,
+    ApiResponse, Asset, AssetList, AssetSortBy, Attribute, Attributes, Authorities, Cluster, Compression, Content,
+    Creator, File, Group, HeliusEndpoints, Interface, Links, Metadata, Ownership, OwnershipModel, Royalty,
+    RoyaltyModel, Scope, SearchAssets, SearchConditionType, Supply,
 };
 use helius::Helius;
 
-use mockito::{self, Server};
+use chrono::{TimeZone, Utc};
+use mockito::{self, Matcher, Server};
 use reqwest::Client;
-use serde_json::Value;
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
 use std::sync::Arc;
 
 #[tokio::test]
@@ -147,11 +151,14 @@ async fn test_search_assets_success() {
         .create();
 
     let config: Arc<Config> = Arc::new(Config {
-        api_key: "fake_api_key".to_string(),
+        api_key: "fake_api_key".into(),
+        auth: AuthMethod::QueryParam,
         cluster: Cluster::Devnet,
         endpoints: HeliusEndpoints {
             api: url.to_string(),
             rpc: url.to_string(),
+            websocket: None,
+            sender: None,
         },
     });
 
@@ -195,11 +202,14 @@ async fn test_search_assets_failure() {
         .create();
 
     let config: Arc<Config> = Arc::new(Config {
-        api_key: "fake_api_key".to_string(),
+        api_key: "fake_api_key".into(),
+        auth: AuthMethod::QueryParam,
         cluster: Cluster::Devnet,
         endpoints: HeliusEndpoints {
             api: url.to_string(),
             rpc: url.to_string(),
+            websocket: None,
+            sender: None,
         },
     });
 
@@ -224,3 +234,140 @@ async fn test_search_assets_failure() {
     let response: Result<AssetList> = helius.rpc().search_assets(request).await;
     assert!(response.is_err(), "Expected an error due to server failure");
 }
+
+#[tokio::test]
+async fn test_search_assets_query() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let owner: Pubkey = Pubkey::new_unique();
+    let collection: Pubkey = Pubkey::new_unique();
+    let excluded: Pubkey = Pubkey::new_unique();
+
+    let mock = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(json!({
+            "method": "searchAssets",
+            "params": {
+                "ownerAddress": owner.to_string(),
+                "grouping": ["collection", collection.to_string()],
+                "conditionType": "all",
+                "createdAt": { "after": "2025-01-01T00:00:00Z" },
+                "not": { "creators": [excluded.to_string()] },
+                "limit": 50,
+                "page": 2,
+            },
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "jsonrpc": "2.0", "id": "1", "result": { "total": 0, "limit": 50, "page": 2, "items": [] } })
+                .to_string(),
+        )
+        .create();
+
+    let helius: Helius = helius_for(&url);
+    let request: SearchAssets = SearchAssetsQuery::new()
+        .owner(owner)
+        .collection(collection)
+        .exclude_creator(excluded)
+        .created_after(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+        .condition_type(SearchConditionType::All)
+        .limit(50)
+        .page(2)
+        .build()
+        .unwrap();
+
+    // Unset filters and bounds aren't sent
+    let params: Value = serde_json::to_value(&request).unwrap();
+    assert_eq!(params["createdAt"], json!({ "after": "2025-01-01T00:00:00Z" }));
+    assert!(serde_json::to_value(SearchAssets::default())
+        .unwrap()
+        .get("createdAt")
+        .is_none());
+
+    let response: AssetList = helius.rpc().search_assets(request).await.unwrap();
+
+    assert_eq!(response.total, 0);
+    mock.assert();
+}
+
+#[test]
+fn test_search_assets_query_exclusions_are_base58() {
+    let owner: Pubkey = Pubkey::new_unique();
+    let creator: Pubkey = Pubkey::new_unique();
+    let authority: Pubkey = Pubkey::new_unique();
+    let collection: Pubkey = Pubkey::new_unique();
+
+    let request: SearchAssets = SearchAssetsQuery::new()
+        .exclude_owner(owner)
+        .exclude_creator(creator)
+        .exclude_authority(authority)
+        .exclude_collection(collection)
+        .build()
+        .unwrap();
+
+    let params: Value = serde_json::to_value(&request).unwrap();
+    assert_eq!(
+        params["not"],
+        json!({
+            "owners": [owner.to_string()],
+            "creators": [creator.to_string()],
+            "authorities": [authority.to_string()],
+            "collections": [collection.to_string()],
+        })
+    );
+}
+
+#[test]
+fn test_search_assets_query_validation() {
+    let invalid = |query: SearchAssetsQuery| matches!(query.build(), Err(HeliusError::InvalidInput(_)));
+    let owner: Pubkey = Pubkey::new_unique();
+
+    // Only one pagination mode per query
+    assert!(invalid(SearchAssetsQuery::new().owner(owner).page(1).cursor("cursor")));
+    assert!(invalid(
+        SearchAssetsQuery::new()
+            .owner(owner)
+            .page(1)
+            .after(Pubkey::new_unique())
+    ));
+    assert!(invalid(
+        SearchAssetsQuery::new()
+            .owner(owner)
+            .cursor("cursor")
+            .before(Pubkey::new_unique())
+    ));
+    assert!(invalid(SearchAssetsQuery::new().owner(owner).page(0)));
+    assert!(invalid(SearchAssetsQuery::new().owner(owner).limit(1001)));
+    assert!(invalid(
+        SearchAssetsQuery::new()
+            .owner(owner)
+            .cursor("cursor")
+            .sort_by(AssetSortBy::Created, None)
+    ));
+
+    // Condition types and negation need filters to apply to
+    assert!(invalid(
+        SearchAssetsQuery::new().condition_type(SearchConditionType::Any)
+    ));
+    assert!(invalid(SearchAssetsQuery::new().negate(true).limit(10)));
+
+    let day = |day: u32| Utc.with_ymd_and_hms(2025, 1, day, 0, 0, 0).unwrap();
+    assert!(invalid(
+        SearchAssetsQuery::new().created_after(day(2)).created_before(day(1))
+    ));
+
+    let request: SearchAssets = SearchAssetsQuery::new()
+        .creator_verified(owner, true)
+        .created_after(day(1))
+        .created_before(day(2))
+        .condition_type(SearchConditionType::Any)
+        .cursor("cursor")
+        .sort_by(AssetSortBy::Id, None)
+        .try_into()
+        .unwrap();
+    assert_eq!(request.creator_address, Some(owner.to_string()));
+    assert_eq!(request.creator_verified, Some(true));
+    assert_eq!(request.cursor.as_deref(), Some("cursor"));
+}
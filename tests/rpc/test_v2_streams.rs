@@ -0,0 +1,225 @@
+use crate::common::helius_for;
+use helius::error::{HeliusError, Result};
+use helius::pagination::{AccountPage, PaginationOptions};
+use helius::types::*;
+use helius::Helius;
+
+use futures_util::StreamExt;
+use mockito::{self, Matcher, Server};
+use serde_json::{json, Value};
+
+const PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const OWNER: &str = "86xCnPeV69n6t3DnyGvkKobf9FdN2H9oiVDdaMpo2MMY";
+
+fn account(pubkey: &str) -> Value {
+    json!({
+        "pubkey": pubkey,
+        "account": {
+            "lamports": 2_039_280,
+            "owner": PROGRAM_ID,
+            "data": ["", "base64"],
+            "executable": false,
+            "rentEpoch": 0,
+            "space": 0,
+        },
+    })
+}
+
+fn program_accounts_page(pubkeys: &[&str], pagination_key: Option<&str>) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": "1",
+        "result": {
+            "accounts": pubkeys.iter().map(|pubkey| account(pubkey)).collect::<Vec<Value>>(),
+            "paginationKey": pagination_key,
+            "totalResults": 5,
+        },
+    })
+    .to_string()
+}
+
+fn token_accounts_page(pubkeys: &[&str], pagination_key: Option<&str>) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": "1",
+        "result": {
+            "context": { "slot": 1_000, "apiVersion": "2.2.0" },
+            "value": {
+                "accounts": pubkeys.iter().map(|pubkey| account(pubkey)).collect::<Vec<Value>>(),
+                "paginationKey": pagination_key,
+                "count": pubkeys.len(),
+            },
+        },
+    })
+    .to_string()
+}
+
+fn pubkeys<T>(pages: &[Result<AccountPage<T>>], pubkey: fn(&T) -> &str) -> Vec<String> {
+    pages
+        .iter()
+        .flat_map(|page| page.as_ref().unwrap().accounts.iter().map(pubkey))
+        .map(str::to_string)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_program_accounts_stream_resumes_from_checkpoint() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let second_page = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::Regex(r#""paginationKey":"key-1""#.to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(program_accounts_page(&["account-3", "account-4"], Some("key-2")))
+        .expect(1)
+        .create();
+    let last_page = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::Regex(r#""paginationKey":"key-2""#.to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(program_accounts_page(&["account-5"], None))
+        .expect(1)
+        .create();
+
+    let helius: Helius = helius_for(&url);
+    let config: GetProgramAccountsV2Config = GetProgramAccountsV2Config {
+        pagination_key: Some("key-1".to_string()),
+        ..Default::default()
+    };
+    let pages: Vec<Result<AccountPage<GpaAccount>>> = helius
+        .rpc()
+        .get_program_accounts_v2_stream(PROGRAM_ID.to_string(), config, PaginationOptions::default())
+        .collect()
+        .await;
+
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0].as_ref().unwrap().pagination_key.as_deref(), Some("key-2"));
+    assert_eq!(pages[1].as_ref().unwrap().pagination_key, None);
+    assert_eq!(pages[1].as_ref().unwrap().total_results, Some(5));
+    assert_eq!(
+        pubkeys(&pages, |account| &account.pubkey),
+        vec!["account-3", "account-4", "account-5"]
+    );
+    second_page.assert();
+    last_page.assert();
+}
+
+#[tokio::test]
+async fn test_program_accounts_stream_requests_only_remaining_items() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let first_page = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::Regex(r#""limit":2"#.to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(program_accounts_page(&["account-1", "account-2"], Some("key-1")))
+        .expect(1)
+        .create();
+    let capped_page = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(r#""limit":1"#.to_string()),
+            Matcher::Regex(r#""paginationKey":"key-1""#.to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(program_accounts_page(&["account-3"], Some("key-2")))
+        .expect(1)
+        .create();
+
+    let helius: Helius = helius_for(&url);
+    let pages: Vec<Result<AccountPage<GpaAccount>>> = helius
+        .rpc()
+        .get_program_accounts_v2_stream(
+            PROGRAM_ID.to_string(),
+            GetProgramAccountsV2Config::default(),
+            PaginationOptions::default().with_page_size(2).with_max_items(3),
+        )
+        .collect()
+        .await;
+
+    assert_eq!(
+        pubkeys(&pages, |account| &account.pubkey),
+        vec!["account-1", "account-2", "account-3"]
+    );
+    // The checkpoint of the last page still resumes where the stream stopped
+    assert_eq!(pages[1].as_ref().unwrap().pagination_key.as_deref(), Some("key-2"));
+    first_page.assert();
+    capped_page.assert();
+}
+
+#[tokio::test]
+async fn test_token_accounts_stream_prefetches_pages() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let first_page = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(json!({ "method": "getTokenAccountsByOwnerV2" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(token_accounts_page(&["token-1", "token-2"], Some("key-1")))
+        .expect(1)
+        .create();
+    let last_page = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::Regex(r#""paginationKey":"key-1""#.to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(token_accounts_page(&["token-3"], None))
+        .expect(1)
+        .create();
+
+    let helius: Helius = helius_for(&url);
+    let pages: Vec<Result<AccountPage<TokenAccountRecord>>> = helius
+        .rpc()
+        .get_token_accounts_by_owner_v2_stream(
+            OWNER.to_string(),
+            TokenAccountsOwnerFilter::Program {
+                program_id: PROGRAM_ID.to_string(),
+            },
+            GetTokenAccountsByOwnerV2Config::default(),
+            PaginationOptions::default().with_prefetch(1),
+        )
+        .collect()
+        .await;
+
+    assert_eq!(
+        pubkeys(&pages, |account| &account.pubkey),
+        vec!["token-1", "token-2", "token-3"]
+    );
+    first_page.assert();
+    last_page.assert();
+}
+
+#[tokio::test]
+async fn test_prefetched_stream_ends_with_error() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(400)
+        .with_body(r#"{"error": "invalid program"}"#)
+        .expect(1)
+        .create();
+
+    let helius: Helius = helius_for(&url);
+    let pages: Vec<Result<AccountPage<GpaAccount>>> = helius
+        .rpc()
+        .get_program_accounts_v2_stream(
+            PROGRAM_ID.to_string(),
+            GetProgramAccountsV2Config::default(),
+            PaginationOptions::default().with_prefetch(2),
+        )
+        .collect()
+        .await;
+
+    assert_eq!(pages.len(), 1);
+    assert!(matches!(pages[0], Err(HeliusError::BadRequest { .. })));
+}
@@ -0,0 +1,185 @@
+#![cfg(feature = "rpc-v2")]
+
+mod common;
+
+use std::collections::HashMap;
+
+use common::helius_for;
+use helius::account_sync::{ProgramAccountSync, SyncReport};
+use helius::error::Result;
+use helius::types::*;
+use helius::Helius;
+
+use mockito::{self, Matcher, Mock, Server, ServerGuard};
+use serde_json::{json, Value};
+
+const PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+fn account(pubkey: &str, lamports: u64) -> Value {
+    json!({
+        "pubkey": pubkey,
+        "account": {
+            "lamports": lamports,
+            "owner": PROGRAM_ID,
+            "data": ["", "base64"],
+            "executable": false,
+            "rentEpoch": 0,
+            "space": 0,
+        },
+    })
+}
+
+fn page(slot: u64, accounts: Vec<Value>, pagination_key: Option<&str>) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": "1",
+        "result": {
+            "context": { "slot": slot },
+            "value": { "accounts": accounts, "paginationKey": pagination_key },
+        },
+    })
+    .to_string()
+}
+
+fn mock_accounts(server: &mut ServerGuard, body: Matcher, slot: u64, accounts: Vec<Value>) -> Mock {
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::PartialJson(json!({ "method": "getProgramAccountsV2" })),
+            Matcher::Regex(r#""withContext":true"#.to_string()),
+            body,
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(slot, accounts, None))
+        .expect(1)
+        .create()
+}
+
+fn mock_page(server: &mut ServerGuard, mut config: Value, body: String) -> Mock {
+    config["withContext"] = json!(true);
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(json!({
+            "method": "getProgramAccountsV2",
+            "params": [PROGRAM_ID, config],
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .expect(1)
+        .create()
+}
+
+#[tokio::test]
+async fn test_sync_snapshots_then_fetches_changes() {
+    let mut server: ServerGuard = Server::new_async().await;
+    let helius: Helius = helius_for(&server.url());
+
+    let snapshot = mock_accounts(
+        &mut server,
+        Matcher::Any,
+        100,
+        vec![account("account-1", 1_000), account("account-2", 2_000)],
+    );
+
+    let mut sync = ProgramAccountSync::new(
+        helius.rpc().as_ref(),
+        PROGRAM_ID,
+        GetProgramAccountsV2Config::default(),
+        HashMap::<String, AccountInfo>::new(),
+    );
+    let report: SyncReport = sync.poll().await.unwrap();
+    assert_eq!(
+        report,
+        SyncReport {
+            slot: 100,
+            upserted: 2,
+            full_snapshot: true,
+        }
+    );
+    assert_eq!(sync.slot(), Some(100));
+    snapshot.assert();
+    snapshot.remove();
+
+    let changes = mock_accounts(
+        &mut server,
+        Matcher::Regex(r#""changedSinceSlot":100"#.to_string()),
+        120,
+        vec![account("account-2", 5_000)],
+    );
+
+    let report: SyncReport = sync.poll().await.unwrap();
+    assert_eq!(
+        report,
+        SyncReport {
+            slot: 120,
+            upserted: 1,
+            full_snapshot: false,
+        }
+    );
+    changes.assert();
+
+    let store: HashMap<String, AccountInfo> = sync.into_store();
+    assert_eq!(store.len(), 2);
+    assert_eq!(store["account-1"].lamports, 1_000);
+    assert_eq!(store["account-2"].lamports, 5_000);
+}
+
+#[tokio::test]
+async fn test_failed_cycle_keeps_previous_slot() {
+    let mut server: ServerGuard = Server::new_async().await;
+    let helius: Helius = helius_for(&server.url());
+
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(json!({ "method": "getProgramAccountsV2" })))
+        .with_status(400)
+        .with_body(r#"{"error": "invalid filter"}"#)
+        .create();
+
+    let mut sync = ProgramAccountSync::new(
+        helius.rpc().as_ref(),
+        PROGRAM_ID,
+        GetProgramAccountsV2Config::default(),
+        HashMap::<String, AccountInfo>::new(),
+    )
+    .with_slot(100);
+
+    let report: Result<SyncReport> = sync.poll().await;
+    assert!(report.is_err());
+    assert_eq!(sync.slot(), Some(100));
+    assert!(sync.store().is_empty());
+}
+
+#[tokio::test]
+async fn test_sync_records_the_oldest_page_slot() {
+    let mut server: ServerGuard = Server::new_async().await;
+    let helius: Helius = helius_for(&server.url());
+
+    // The second page is served by a node that's behind the first, so changes after slot 95 must be fetched again.
+    // Its mock is created first, since its params also match the first page's mock
+    let second = mock_page(
+        &mut server,
+        json!({ "paginationKey": "key-1" }),
+        page(95, vec![account("account-2", 2_000)], None),
+    );
+    let first = mock_page(
+        &mut server,
+        json!({}),
+        page(100, vec![account("account-1", 1_000)], Some("key-1")),
+    );
+
+    let mut sync = ProgramAccountSync::new(
+        helius.rpc().as_ref(),
+        PROGRAM_ID,
+        GetProgramAccountsV2Config::default(),
+        HashMap::<String, AccountInfo>::new(),
+    );
+    let report: SyncReport = sync.snapshot().await.unwrap();
+    assert_eq!(report.slot, 95);
+    assert_eq!(report.upserted, 2);
+    assert_eq!(sync.slot(), Some(95));
+    first.assert();
+    second.assert();
+}
@@ -0,0 +1,114 @@
+use helius::config::{AuthMethod, Config, API_KEY_HEADER};
+use helius::error::Result;
+use helius::secret::Secret;
+use helius::types::{AssetProof, Cluster, GetAssetProof, HeliusEndpoints};
+use helius::Helius;
+
+use mockito::{self, Matcher, Server};
+use serde_json::json;
+
+fn asset_proof_response() -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": "1",
+        "result": {
+            "root": "root",
+            "proof": [],
+            "node_index": 1,
+            "leaf": "leaf",
+            "tree_id": "tree"
+        }
+    })
+    .to_string()
+}
+
+#[test]
+fn test_secret_is_redacted_from_debug_output() {
+    let secret: Secret = Secret::from("super-secret-key");
+    assert_eq!(format!("{:?}", secret), "Secret(REDACTED)");
+    assert_eq!(secret.expose(), "super-secret-key");
+
+    let config: Config = Config::new("super-secret-key", Cluster::Devnet).unwrap();
+    assert!(!format!("{:?}", config).contains("super-secret-key"));
+}
+
+#[tokio::test]
+async fn test_header_auth_keeps_api_key_out_of_url() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let mock = server
+        .mock("POST", "/")
+        .match_query(Matcher::Missing)
+        .match_header(API_KEY_HEADER, "fake_api_key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(asset_proof_response())
+        .create();
+
+    let helius: Helius = Helius::builder()
+        .api_key("fake_api_key")
+        .auth_method(AuthMethod::Header)
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: url.clone(),
+            rpc: url,
+            ..Default::default()
+        }))
+        .build()
+        .unwrap();
+
+    let response: Result<Option<AssetProof>> = helius
+        .rpc()
+        .get_asset_proof(GetAssetProof {
+            id: "asset".to_string(),
+        })
+        .await;
+    assert!(response.is_ok());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_network_errors_redact_api_key() {
+    let helius: Helius = Helius::builder()
+        .api_key("super-secret-key")
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: "http://127.0.0.1:1/".to_string(),
+            rpc: "http://127.0.0.1:1".to_string(),
+            ..Default::default()
+        }))
+        .build()
+        .unwrap();
+
+    let error: String = helius
+        .rpc()
+        .get_asset_proof(GetAssetProof {
+            id: "asset".to_string(),
+        })
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(!error.contains("super-secret-key"));
+    assert!(error.contains("REDACTED"), "{}", error);
+}
+
+#[cfg(feature = "staking")]
+#[tokio::test]
+async fn test_stake_account_errors_redact_api_key() {
+    let helius: Helius = Helius::builder()
+        .api_key("super-secret-key")
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: "http://127.0.0.1:1/".to_string(),
+            rpc: "http://127.0.0.1:1".to_string(),
+            ..Default::default()
+        }))
+        .build()
+        .unwrap();
+
+    let error: String = helius
+        .get_stake_accounts(solana_sdk::pubkey::Pubkey::new_unique())
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(!error.contains("super-secret-key"));
+    assert!(error.contains("REDACTED"), "{}", error);
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "blocking")]
+
+use helius::blocking::Helius;
+use helius::error::{HeliusError, Result};
+use helius::types::*;
+use helius::HeliusBuilder;
+
+use mockito::{Server, ServerGuard};
+use serde_json::json;
+
+fn helius_for(server: &ServerGuard) -> Helius {
+    Helius::from_builder(
+        HeliusBuilder::new()
+            .api_key("fake_api_key")
+            .cluster(Cluster::Custom(HeliusEndpoints {
+                api: format!("{}/", server.url()),
+                rpc: server.url(),
+                ..Default::default()
+            })),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_blocking_get_asset_batch() {
+    let mut server: ServerGuard = Server::new();
+
+    let mock = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(mockito::Matcher::PartialJson(json!({"method": "getAssetBatch"})))
+        .with_status(200)
+        .with_header("Content-Type", "application/json")
+        .with_body(json!({"jsonrpc": "2.0", "id": "1", "result": [null]}).to_string())
+        .create();
+
+    let helius: Helius = helius_for(&server);
+    let assets: Vec<Option<Asset>> = helius
+        .rpc()
+        .get_asset_batch(GetAssetBatch {
+            ids: vec!["F9Lw3ki3hJ7PF9HQXsBzoY8GyE6sPoEZZdXJBsTTD2rk".to_string()],
+            display_options: None,
+        })
+        .unwrap();
+
+    assert_eq!(assets.len(), 1);
+    assert!(assets[0].is_none());
+    mock.assert();
+}
+
+#[test]
+fn test_blocking_get_webhook_by_id() {
+    let mut server: ServerGuard = Server::new();
+
+    let webhook: Webhook = Webhook {
+        webhook_url: "https://webhook.site/0e8250a1-ceec-4757-ad69-cc6473085bfc".to_string(),
+        transaction_types: vec![TransactionType::Any],
+        webhook_type: WebhookType::Enhanced,
+        webhook_id: "0e8250a1-ceec-4757-ad69".to_string(),
+        ..Default::default()
+    };
+    let mock = server
+        .mock("GET", "/v0/webhooks/0e8250a1-ceec-4757-ad69?api-key=fake_api_key")
+        .with_status(200)
+        .with_header("Content-Type", "application/json")
+        .with_body(serde_json::to_string(&webhook).unwrap())
+        .create();
+
+    let helius: Helius = helius_for(&server);
+    let response: Webhook = helius.get_webhook_by_id("0e8250a1-ceec-4757-ad69").unwrap();
+
+    assert_eq!(response.webhook_id, "0e8250a1-ceec-4757-ad69");
+    mock.assert();
+}
+
+#[test]
+fn test_blocking_surfaces_errors() {
+    let mut server: ServerGuard = Server::new();
+
+    server
+        .mock("POST", "/v0/transactions?api-key=fake_api_key")
+        .with_status(400)
+        .with_body(r#"{"error": "invalid transaction"}"#)
+        .create();
+
+    let helius: Helius = helius_for(&server);
+    let response: Result<Vec<EnhancedTransaction>> = helius.parse_transactions(ParseTransactionsRequest {
+        transactions: vec!["not-a-signature".to_string()],
+    });
+
+    assert!(matches!(response, Err(HeliusError::BadRequest { .. })));
+}
@@ -0,0 +1,256 @@
+#![cfg(feature = "das")]
+
+mod common;
+
+use common::helius_for;
+use common::merkle::{encode, fixture, helium_hotspot, Fixture, DEPTH, LEAF_INDEX};
+use helius::bubblegum::{
+    tree_config_address, voucher_address, BubblegumBuilder, MetadataArgs, TreeInfo, BUBBLEGUM_PROGRAM_ID,
+};
+use helius::error::{HeliusError, Result};
+use helius::Helius;
+
+use mockito::{self, Matcher, Server};
+use serde_json::json;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+const BUFFER_SIZE: u32 = 8;
+const CANOPY_DEPTH: u32 = 1;
+
+const TREE_CONFIG_DISCRIMINATOR: [u8; 8] = [122, 245, 175, 248, 171, 34, 0, 207];
+
+/// Lays out a concurrent Merkle tree account: the header, the tree itself, and a canopy of `canopy_depth` levels
+fn tree_account(depth: u32, buffer_size: u32, canopy_depth: u32) -> Vec<u8> {
+    let mut data: Vec<u8> = vec![1, 0];
+    data.extend_from_slice(&buffer_size.to_le_bytes());
+    data.extend_from_slice(&depth.to_le_bytes());
+    data.resize(56, 0);
+
+    let path_size: usize = 40 + 32 * depth as usize;
+    let canopy_nodes: usize = (1 << (canopy_depth + 1)) - 2;
+    data.resize(56 + 24 + (buffer_size as usize + 1) * path_size + 32 * canopy_nodes, 0);
+    data
+}
+
+fn tree_config_account(creator: &Pubkey, delegate: &Pubkey) -> Vec<u8> {
+    let mut data: Vec<u8> = TREE_CONFIG_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(creator.as_ref());
+    data.extend_from_slice(delegate.as_ref());
+    data.extend_from_slice(&(1u64 << DEPTH).to_le_bytes());
+    data.extend_from_slice(&6u64.to_le_bytes());
+    data.extend_from_slice(&[0, 0]);
+    data
+}
+
+fn tree_info(tree: Pubkey) -> TreeInfo {
+    let authority: Pubkey = Pubkey::new_unique();
+    TreeInfo::from_accounts(
+        tree,
+        &tree_account(DEPTH, BUFFER_SIZE, CANOPY_DEPTH),
+        &tree_config_account(&authority, &authority),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_tree_info_from_accounts() {
+    let tree: Pubkey = Pubkey::new_unique();
+    let creator: Pubkey = Pubkey::new_unique();
+    let delegate: Pubkey = Pubkey::new_unique();
+    let config: Vec<u8> = tree_config_account(&creator, &delegate);
+
+    for canopy_depth in 0..=DEPTH {
+        let info: TreeInfo =
+            TreeInfo::from_accounts(tree, &tree_account(DEPTH, BUFFER_SIZE, canopy_depth), &config).unwrap();
+        assert_eq!(info.max_depth, DEPTH);
+        assert_eq!(info.max_buffer_size, BUFFER_SIZE);
+        assert_eq!(info.canopy_depth, canopy_depth);
+        assert_eq!(info.tree_creator, creator);
+        assert_eq!(info.tree_delegate, delegate);
+    }
+
+    // A canopy that isn't a whole number of levels means the account isn't laid out as expected
+    let mut truncated: Vec<u8> = tree_account(DEPTH, BUFFER_SIZE, 1);
+    truncated.truncate(truncated.len() - 32);
+    assert!(matches!(
+        TreeInfo::from_accounts(tree, &truncated, &config),
+        Err(HeliusError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        TreeInfo::from_accounts(tree, &tree_account(DEPTH, BUFFER_SIZE, 1), &config[8..]),
+        Err(HeliusError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn test_metadata_hashes_match_mainnet() {
+    // The hashes Bubblegum stored on-chain for a mainnet cNFT, as recorded by the indexer
+    let metadata: MetadataArgs = MetadataArgs::from_asset(&helium_hotspot()).unwrap();
+    assert_eq!(
+        encode(&metadata.data_hash().unwrap()),
+        "D4yd9ePcnVX2uKujrC4NSCFNVsNTUB1gKUq2bnouR8Ba"
+    );
+    assert_eq!(
+        encode(&metadata.creator_hash()),
+        "GuBHQ5zhrHdkADX8ttpGvSYPQB2M6K5Zcj2qq5HogPgQ"
+    );
+    assert!(metadata.collection.unwrap().verified);
+}
+
+#[test]
+fn test_transfer_and_burn_instructions() {
+    let delegate: Pubkey = Pubkey::new_unique();
+    let Fixture { asset, proof, tree } = fixture(Some(delegate));
+    let builder: BubblegumBuilder = BubblegumBuilder::new(&asset, &proof, tree_info(tree)).unwrap();
+    let owner: Pubkey = builder.leaf_owner();
+    let new_owner: Pubkey = Pubkey::new_unique();
+
+    // The canopy caches the top level of the tree, so only the two lower proof nodes are passed
+    assert_eq!(builder.proof().len(), (DEPTH - CANOPY_DEPTH) as usize);
+
+    let transfer: Instruction = builder.transfer(&delegate, &new_owner).unwrap();
+    assert_eq!(transfer.program_id, BUBBLEGUM_PROGRAM_ID);
+    assert_eq!(transfer.accounts.len(), 8 + 2);
+    assert_eq!(transfer.accounts[0].pubkey, tree_config_address(&tree));
+    assert_eq!(
+        (transfer.accounts[1].pubkey, transfer.accounts[1].is_signer),
+        (owner, false)
+    );
+    assert_eq!(
+        (transfer.accounts[2].pubkey, transfer.accounts[2].is_signer),
+        (delegate, true)
+    );
+    assert_eq!(transfer.accounts[3].pubkey, new_owner);
+    assert!(transfer.accounts[4].is_writable);
+    assert_eq!(transfer.accounts[8].pubkey.to_string(), proof.proof[0]);
+    assert_eq!(transfer.accounts[9].pubkey.to_string(), proof.proof[1]);
+
+    let data: &[u8] = &transfer.data;
+    assert_eq!(data.len(), 8 + 32 * 3 + 8 + 4);
+    assert_eq!(&data[..8], &[163, 52, 200, 231, 140, 3, 69, 186]);
+    assert_eq!(encode(&data[8..40]), proof.root);
+    assert_eq!(encode(&data[40..72]), asset.compression.as_ref().unwrap().data_hash);
+    assert_eq!(
+        u64::from_le_bytes(data[104..112].try_into().unwrap()),
+        LEAF_INDEX as u64
+    );
+    assert_eq!(
+        u32::from_le_bytes(data[112..116].try_into().unwrap()),
+        LEAF_INDEX as u32
+    );
+
+    let burn: Instruction = builder.burn(&owner).unwrap();
+    assert_eq!(burn.accounts.len(), 7 + 2);
+    assert!(burn.accounts[1].is_signer && !burn.accounts[2].is_signer);
+
+    let redeem: Instruction = builder.redeem();
+    assert_eq!(redeem.accounts[4].pubkey, voucher_address(&tree, LEAF_INDEX as u64));
+
+    // Only the owner or delegate can authorize a transfer
+    let result: Result<Instruction> = builder.transfer(&new_owner, &new_owner);
+    assert!(matches!(result, Err(HeliusError::InvalidInput(_))));
+}
+
+#[test]
+fn test_metadata_instructions() {
+    let Fixture { asset, proof, tree } = fixture(None);
+    let builder: BubblegumBuilder = BubblegumBuilder::new(&asset, &proof, tree_info(tree)).unwrap();
+    let metadata: MetadataArgs = MetadataArgs::from_asset(&asset).unwrap();
+    let payer: Pubkey = Pubkey::new_unique();
+    let creator: Pubkey = metadata.creators[1].address;
+    // DAS returns the supply fields in snake_case
+    assert_eq!(metadata.edition_nonce, Some(254));
+
+    let verify: Instruction = builder.verify_creator(&payer, &creator, &metadata).unwrap();
+    assert_eq!(verify.accounts.len(), 9 + 2);
+    assert!(verify.accounts[5].is_signer);
+    assert_eq!(&verify.data[116..], metadata.serialize().unwrap().as_slice());
+
+    let collection_mint: Pubkey = Pubkey::new_unique();
+    let collection: Instruction = builder
+        .set_and_verify_collection(&payer, &payer, None, &collection_mint, &metadata)
+        .unwrap();
+    assert_eq!(collection.accounts.len(), 16 + 2);
+    // Bubblegum requires the tree delegate's signature unless it's the collection's update authority
+    assert_eq!(collection.accounts[5].pubkey, builder.tree().tree_delegate);
+    assert!(collection.accounts[5].is_signer);
+    assert_eq!(collection.accounts[7].pubkey, BUBBLEGUM_PROGRAM_ID);
+    assert_eq!(&collection.data[collection.data.len() - 32..], collection_mint.as_ref());
+
+    let decompress: Instruction = builder.decompress(&metadata).unwrap();
+    assert_eq!(decompress.accounts.len(), 13);
+    assert_eq!(decompress.accounts[0].pubkey, voucher_address(&tree, LEAF_INDEX as u64));
+    assert_eq!(decompress.accounts[3].pubkey, builder.asset_id());
+
+    // Metadata that doesn't hash to the leaf would be rejected on-chain
+    let mut renamed: MetadataArgs = metadata.clone();
+    renamed.name = "Renamed".to_string();
+    assert!(matches!(
+        builder.verify_creator(&payer, &creator, &renamed),
+        Err(HeliusError::InvalidInput(_))
+    ));
+
+    // The proof must be a full path through the tree the info describes
+    let shallow: TreeInfo = TreeInfo {
+        max_depth: DEPTH + 1,
+        ..tree_info(tree)
+    };
+    assert!(matches!(
+        BubblegumBuilder::new(&asset, &proof, shallow),
+        Err(HeliusError::InvalidInput(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_bubblegum_builder_fetches_tree_info() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let Fixture { asset, proof, tree } = fixture(None);
+    let creator: Pubkey = Pubkey::new_unique();
+    let account = |owner: &str, data: Vec<u8>| {
+        json!({
+            "data": [encode(&data), "base58"],
+            "executable": false,
+            "lamports": 1_000_000,
+            "owner": owner,
+            "rentEpoch": 0,
+            "space": data.len(),
+        })
+    };
+
+    let mock = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(json!({
+            "method": "getMultipleAccounts",
+            "params": [[tree.to_string(), tree_config_address(&tree).to_string()]],
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "context": { "slot": 1 },
+                    "value": [
+                        account(
+                            "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK",
+                            tree_account(DEPTH, BUFFER_SIZE, CANOPY_DEPTH),
+                        ),
+                        account(&BUBBLEGUM_PROGRAM_ID.to_string(), tree_config_account(&creator, &creator)),
+                    ],
+                },
+            })
+            .to_string(),
+        )
+        .create();
+
+    let helius: Helius = helius_for(&url);
+    let builder: BubblegumBuilder = helius.bubblegum_builder(&asset, &proof).await.unwrap();
+
+    assert_eq!(builder.tree().canopy_depth, CANOPY_DEPTH);
+    assert_eq!(builder.tree().tree_creator, creator);
+    assert_eq!(builder.proof().len(), (DEPTH - CANOPY_DEPTH) as usize);
+    mock.assert();
+}
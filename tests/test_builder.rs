@@ -0,0 +1,220 @@
+use std::time::Duration;
+
+use helius::builder::WebsocketOptions;
+use helius::error::{HeliusError, Result};
+use helius::types::{AssetProof, Cluster, GetAssetProof, HeliusEndpoints};
+use helius::Helius;
+use mockito::{self, Server};
+use serde_json::json;
+use solana_commitment_config::CommitmentConfig;
+
+#[test]
+fn test_builder_defaults_to_mainnet() {
+    let helius: Helius = Helius::builder().api_key("valid-api-key").build().unwrap();
+
+    assert_eq!(helius.config.cluster, Cluster::MainnetBeta);
+    assert_eq!(helius.config.endpoints.rpc, "https://mainnet.helius-rpc.com/");
+    assert!(helius.async_rpc_client.is_none());
+    assert!(helius.ws_client.is_none());
+}
+
+#[test]
+fn test_builder_with_all_http_settings() {
+    let result: Result<Helius> = Helius::builder()
+        .api_key("valid-api-key")
+        .cluster(Cluster::Devnet)
+        .commitment(CommitmentConfig::confirmed())
+        .with_async_solana()
+        .timeout(Duration::from_secs(30))
+        .connect_timeout(Duration::from_secs(5))
+        .api_url("http://localhost:8080/")
+        .rpc_url("http://localhost:8899/")
+        .build();
+    assert!(result.is_ok());
+
+    let helius: Helius = result.unwrap();
+    assert_eq!(helius.config.endpoints.api, "http://localhost:8080/");
+    assert_eq!(helius.config.endpoints.rpc, "http://localhost:8899/");
+    assert!(helius.async_rpc_client.is_some());
+    assert_eq!(helius.connection().commitment(), CommitmentConfig::confirmed());
+}
+
+#[test]
+fn test_builder_with_reqwest_client() {
+    let result: Result<Helius> = Helius::builder()
+        .api_key("valid-api-key")
+        .cluster(Cluster::Devnet)
+        .http_client(reqwest::Client::new())
+        .build();
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_builder_missing_api_key() {
+    let result: Result<Helius> = Helius::builder().cluster(Cluster::Devnet).build();
+    assert!(matches!(result, Err(HeliusError::InvalidInput(_))));
+}
+
+#[test]
+fn test_builder_invalid_endpoint_override() {
+    let result: Result<Helius> = Helius::builder().api_key("valid-api-key").rpc_url("not a url").build();
+    assert!(matches!(result, Err(HeliusError::InvalidInput(_))));
+}
+
+#[test]
+fn test_builder_rejects_timeouts_with_provided_client() {
+    let result: Result<Helius> = Helius::builder()
+        .api_key("valid-api-key")
+        .http_client(reqwest::Client::new())
+        .timeout(Duration::from_secs(30))
+        .build();
+    assert!(matches!(result, Err(HeliusError::InvalidInput(_))));
+}
+
+#[test]
+fn test_builder_rejects_zero_timeout() {
+    let result: Result<Helius> = Helius::builder()
+        .api_key("valid-api-key")
+        .timeout(Duration::ZERO)
+        .build();
+    assert!(matches!(result, Err(HeliusError::InvalidInput(_))));
+}
+
+#[test]
+fn test_builder_validates_websocket_settings() {
+    let staked = Helius::builder()
+        .api_key("valid-api-key")
+        .cluster(Cluster::StakedMainnetBeta)
+        .with_websocket();
+    assert!(staked.validate().is_err());
+
+    let zero_ping = Helius::builder().api_key("valid-api-key").websocket(WebsocketOptions {
+        ping_interval_secs: Some(0),
+        pong_timeout_secs: None,
+    });
+    assert!(matches!(zero_ping.validate(), Err(HeliusError::InvalidInput(_))));
+}
+
+#[test]
+fn test_builder_build_rejects_websocket() {
+    let result: Result<Helius> = Helius::builder().api_key("valid-api-key").with_websocket().build();
+    assert!(matches!(result, Err(HeliusError::InvalidInput(_))));
+}
+
+#[test]
+fn test_builder_custom_cluster_with_overrides() {
+    let endpoints: HeliusEndpoints = HeliusEndpoints {
+        api: "https://api.example.com/".to_string(),
+        rpc: "https://rpc.example.com/".to_string(),
+        websocket: None,
+        sender: None,
+    };
+
+    let helius: Helius = Helius::builder()
+        .api_key("valid-api-key")
+        .cluster(Cluster::Custom(endpoints))
+        .websocket_url("wss://ws.example.com/")
+        .sender_url("https://sender.example.com")
+        .build()
+        .unwrap();
+
+    assert_eq!(helius.config.endpoints.api, "https://api.example.com/");
+    assert_eq!(helius.config.endpoints.rpc, "https://rpc.example.com/");
+    assert_eq!(
+        helius.config.endpoints.websocket.as_deref(),
+        Some("wss://ws.example.com/")
+    );
+    assert_eq!(
+        helius.config.endpoints.sender.as_deref(),
+        Some("https://sender.example.com")
+    );
+}
+
+#[test]
+fn test_builder_validates_custom_cluster() {
+    let invalid = Helius::builder()
+        .api_key("valid-api-key")
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: "not a url".to_string(),
+            rpc: "https://rpc.example.com/".to_string(),
+            ..Default::default()
+        }));
+    assert!(matches!(invalid.validate(), Err(HeliusError::InvalidInput(_))));
+
+    let without_websocket = Helius::builder()
+        .api_key("valid-api-key")
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: "https://api.example.com/".to_string(),
+            rpc: "https://rpc.example.com/".to_string(),
+            ..Default::default()
+        }))
+        .with_websocket();
+    assert!(without_websocket.validate().is_err());
+
+    let localnet = Helius::builder()
+        .api_key("valid-api-key")
+        .cluster(Cluster::Localnet)
+        .with_websocket();
+    assert!(localnet.validate().is_ok());
+}
+
+#[tokio::test]
+async fn test_custom_cluster_routes_rpc_requests() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": "1",
+                "result": {
+                    "root": "root",
+                    "proof": [],
+                    "node_index": 1,
+                    "leaf": "leaf",
+                    "tree_id": "tree"
+                }
+            })
+            .to_string(),
+        )
+        .create();
+
+    let helius: Helius = Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: url.clone(),
+            rpc: url,
+            ..Default::default()
+        }))
+        .build()
+        .unwrap();
+
+    let response: Result<Option<AssetProof>> = helius
+        .rpc()
+        .get_asset_proof(GetAssetProof {
+            id: "asset".to_string(),
+        })
+        .await;
+    assert_eq!(response.unwrap().unwrap().tree_id, "tree");
+}
+
+#[tokio::test]
+async fn test_custom_sender_endpoint_is_used() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    let ping = server.mock("GET", "/ping").with_status(200).create();
+
+    let helius: Helius = Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(Cluster::Localnet)
+        .sender_url(server.url())
+        .build()
+        .unwrap();
+
+    helius.warm_sender_connection("").await.unwrap();
+    ping.assert();
+}
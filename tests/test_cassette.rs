@@ -0,0 +1,143 @@
+#![cfg(feature = "cassette")]
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use helius::cassette::{Cassette, CassetteMode};
+use helius::error::{HeliusError, Result};
+use helius::types::*;
+use helius::Helius;
+
+use mockito::{self, Server};
+use serde_json::json;
+
+const ASSET_ID: &str = "JE9mLqmTRZnUYoMJofSmZp8nZT4pzgARtAJS8crtgVWV";
+
+fn cassette_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("helius-cassette-{}-{name}.json", std::process::id()))
+}
+
+fn helius_for(url: &str, api_key: &str, cassette: Arc<Cassette>) -> Helius {
+    Helius::builder()
+        .api_key(api_key)
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: format!("{url}/"),
+            rpc: url.to_string(),
+            ..Default::default()
+        }))
+        .cassette(cassette)
+        .build()
+        .unwrap()
+}
+
+fn get_asset() -> GetAsset {
+    GetAsset {
+        id: ASSET_ID.to_string(),
+        display_options: None,
+    }
+}
+
+#[tokio::test]
+async fn test_record_then_replay_without_network() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+    let path: PathBuf = cassette_path("record-then-replay");
+
+    let mock = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": "1",
+                "result": {
+                    "interface": "MplCoreAsset",
+                    "id": ASSET_ID,
+                    "ownership": {
+                        "frozen": false,
+                        "delegated": false,
+                        "delegate": null,
+                        "ownership_model": "single",
+                        "owner": "9RzG2RpJjUs3CJdpdmK7VfbGFhFawgpCa5u8G4ztx9sP",
+                    },
+                    "mutable": true,
+                    "burnt": false,
+                },
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let recorder: Arc<Cassette> = Arc::new(Cassette::record(&path));
+    let recorded: Option<Asset> = helius_for(&url, "fake_api_key", recorder.clone())
+        .rpc()
+        .get_asset(get_asset())
+        .await
+        .unwrap();
+    assert_eq!(recorded.as_ref().unwrap().id, ASSET_ID);
+    assert_eq!(recorder.interactions().len(), 1);
+
+    let contents: String = std::fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains("fake_api_key"));
+    assert!(contents.contains("api-key=REDACTED"));
+
+    // The replay uses a different API key and never reaches the server
+    let player: Arc<Cassette> = Arc::new(Cassette::replay(&path).unwrap());
+    assert_eq!(player.mode(), CassetteMode::Replay);
+    let replayed: Option<Asset> = helius_for(&url, "another_api_key", player)
+        .rpc()
+        .get_asset(get_asset())
+        .await
+        .unwrap();
+    assert_eq!(replayed.unwrap().id, ASSET_ID);
+
+    mock.assert();
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn test_replay_checked_in_fixture() {
+    let cassette: Arc<Cassette> = Arc::new(Cassette::replay("tests/cassettes/get_asset.json").unwrap());
+    let helius: Helius = Helius::builder()
+        .api_key("any_api_key")
+        .cluster(Cluster::MainnetBeta)
+        .cassette(cassette)
+        .build()
+        .unwrap();
+
+    let asset: Asset = helius.rpc().get_asset(get_asset()).await.unwrap().unwrap();
+    assert_eq!(asset.interface, Interface::MplCoreAsset);
+    assert_eq!(asset.ownership.owner, "9RzG2RpJjUs3CJdpdmK7VfbGFhFawgpCa5u8G4ztx9sP");
+
+    // Each interaction is replayed at most once
+    let replayed_again: Result<Option<Asset>> = helius.rpc().get_asset(get_asset()).await;
+    assert!(matches!(replayed_again, Err(HeliusError::Cassette(_))));
+}
+
+#[tokio::test]
+async fn test_replay_fails_for_unrecorded_request() {
+    let cassette: Arc<Cassette> = Arc::new(Cassette::replay("tests/cassettes/get_asset.json").unwrap());
+    let helius: Helius = Helius::builder()
+        .api_key("any_api_key")
+        .cluster(Cluster::MainnetBeta)
+        .cassette(cassette)
+        .build()
+        .unwrap();
+
+    let response: Result<Option<Asset>> = helius
+        .rpc()
+        .get_asset(GetAsset {
+            id: "unrecorded".to_string(),
+            display_options: None,
+        })
+        .await;
+    assert!(matches!(response, Err(HeliusError::Cassette(_))));
+}
+
+#[test]
+fn test_replay_missing_file() {
+    let cassette: Result<Cassette> = Cassette::replay(cassette_path("missing"));
+    assert!(matches!(cassette, Err(HeliusError::Cassette(_))));
+}
@@ -1,7 +1,10 @@
 use helius::client::Helius;
 use helius::error::Result;
-use helius::types::Cluster;
+use helius::types::{Cluster, HeliusEndpoints};
+use mockito::{self, Matcher, Server};
+use serde_json::json;
 use solana_commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
 
 #[test]
 fn test_creating_new_client_success() {
@@ -47,3 +50,43 @@ fn test_creating_new_async_client_with_commitment_success() {
     let result: Result<Helius> = Helius::new_with_async_solana_and_commitment(api_key, cluster, commitment);
     assert!(result.is_ok());
 }
+
+#[test]
+fn test_async_connection_available_without_async_solana() {
+    let helius: Helius = Helius::new("valid-api-key", Cluster::Devnet).unwrap();
+
+    assert!(helius.async_rpc_client.is_none());
+    assert!(helius.async_connection().is_ok());
+}
+
+#[tokio::test]
+async fn test_async_methods_use_nonblocking_client() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let rent = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(Matcher::PartialJson(
+            json!({"method": "getMinimumBalanceForRentExemption"}),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"jsonrpc": "2.0", "id": 1, "result": 2282880}).to_string())
+        .create();
+
+    let helius: Helius = Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: url.clone(),
+            rpc: url,
+            ..Default::default()
+        }))
+        .build()
+        .unwrap();
+
+    // The blocking client would panic here, since it cannot block on the single-threaded test runtime
+    let (instructions, _stake_account) = helius.get_stake_instructions(Pubkey::new_unique(), 1.0).await.unwrap();
+
+    assert_eq!(instructions.len(), 3);
+    rent.assert();
+}
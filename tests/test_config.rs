@@ -1,6 +1,6 @@
 use helius::config::Config;
 use helius::error::{HeliusError, Result};
-use helius::types::Cluster;
+use helius::types::{Cluster, HeliusEndpoints};
 use helius::Helius;
 
 #[test]
@@ -41,3 +41,26 @@ fn test_create_async_client() {
     assert!(client.async_rpc_client.is_some());
     assert!(client.ws_client.is_none());
 }
+
+#[test]
+fn test_config_new_with_localnet() {
+    let config: Config = Config::new("valid-api-key", Cluster::Localnet).unwrap();
+    assert_eq!(config.endpoints.api, "http://127.0.0.1:8899/");
+    assert_eq!(config.endpoints.rpc, "http://127.0.0.1:8899/");
+    assert_eq!(config.endpoints.websocket.as_deref(), Some("ws://127.0.0.1:8900/"));
+    assert!(config.endpoints.sender.is_none());
+}
+
+#[test]
+fn test_config_new_with_custom_endpoints() {
+    let endpoints: HeliusEndpoints = HeliusEndpoints {
+        api: "https://api.example.com/".to_string(),
+        rpc: "https://rpc.example.com/".to_string(),
+        websocket: Some("wss://ws.example.com/".to_string()),
+        sender: Some("https://sender.example.com".to_string()),
+    };
+
+    let config: Config = Config::new("valid-api-key", Cluster::Custom(endpoints.clone())).unwrap();
+    assert_eq!(config.endpoints, endpoints);
+    assert_eq!(config.cluster, Cluster::Custom(endpoints));
+}
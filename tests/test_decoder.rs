@@ -0,0 +1,356 @@
+#![cfg(feature = "account-decoder")]
+
+use base64::{engine::general_purpose::STANDARD as B64, Engine};
+use helius::decoder::spl_token_2022_interface::extension::{
+    mint_close_authority::MintCloseAuthority, BaseStateWithExtensions, BaseStateWithExtensionsMut, ExtensionType,
+    StateWithExtensionsMut,
+};
+use helius::decoder::spl_token_2022_interface::state::{Account, AccountState, Mint};
+use helius::decoder::{
+    AccountDecoder, DecodedAccount, DecoderRegistry, SplMint, SplTokenAccount, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
+};
+use helius::error::{HeliusError, Result};
+use helius::types::{AccountInfo, GpaAccount, TokenAccountRecord};
+
+use serde_json::{json, Value};
+use solana_nonce::state::{Data as NonceData, DurableNonce, State as NonceState};
+use solana_nonce::versions::Versions;
+use solana_sdk::{bs58, hash::Hash, program_option::COption, program_pack::Pack, pubkey::Pubkey};
+use solana_stake_interface::state::{Authorized, Delegation, Lockup, Meta, Stake, StakeStateV2};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+fn account_info(owner: &Pubkey, data: Value) -> AccountInfo {
+    AccountInfo {
+        lamports: 2_039_280,
+        owner: owner.to_string(),
+        data,
+        executable: false,
+        rent_epoch: 0,
+        space: None,
+    }
+}
+
+fn base64(data: &[u8]) -> Value {
+    json!([B64.encode(data), "base64"])
+}
+
+#[test]
+fn test_decode_spl_token_account() {
+    let mint: Pubkey = Pubkey::new_unique();
+    let owner: Pubkey = Pubkey::new_unique();
+    let mut data: Vec<u8> = vec![0; Account::LEN];
+    Account {
+        mint,
+        owner,
+        amount: 42,
+        state: AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+
+    let record: TokenAccountRecord = TokenAccountRecord {
+        pubkey: Pubkey::new_unique().to_string(),
+        account: account_info(&TOKEN_PROGRAM_ID, base64(&data)),
+    };
+    let token_account: SplTokenAccount = record.decode().unwrap();
+    assert_eq!(token_account.account().mint, mint);
+    assert_eq!(token_account.account().owner, owner);
+    assert_eq!(token_account.account().amount, 42);
+    assert!(!token_account.is_token_2022());
+    assert!(token_account.extension_types().unwrap().is_empty());
+
+    // The registry tells token accounts and mints apart by their layout
+    let decoded: DecodedAccount = DecoderRegistry::default().decode(&record.account).unwrap();
+    assert!(matches!(decoded, DecodedAccount::TokenAccount(account) if account.account().amount == 42));
+
+    // A mint decoder rejects token account data
+    assert!(matches!(record.decode::<SplMint>(), Err(HeliusError::AccountDecode(_))));
+}
+
+#[test]
+fn test_decode_token_2022_mint_with_extensions() {
+    let mint_pubkey: Pubkey = Pubkey::new_unique();
+    let authority: Pubkey = Pubkey::new_unique();
+    let metadata: TokenMetadata = TokenMetadata {
+        mint: mint_pubkey,
+        name: "Helius".to_string(),
+        symbol: "HEL".to_string(),
+        uri: "https://helius.dev".to_string(),
+        ..Default::default()
+    };
+
+    let mut data: Vec<u8> = vec![0; 512];
+    let mut state: StateWithExtensionsMut<Mint> =
+        StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    state
+        .init_extension::<MintCloseAuthority>(true)
+        .unwrap()
+        .close_authority = Some(authority).try_into().unwrap();
+    state.init_variable_len_extension(&metadata, true).unwrap();
+    state.base = Mint {
+        mint_authority: COption::Some(authority),
+        supply: 1_000_000,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+
+    // Compressed data is decompressed before decoding
+    let compressed: Vec<u8> = zstd::encode_all(data.as_slice(), 0).unwrap();
+    let account: GpaAccount = GpaAccount {
+        pubkey: mint_pubkey.to_string(),
+        account: account_info(&TOKEN_2022_PROGRAM_ID, json!([B64.encode(compressed), "base64+zstd"])),
+    };
+
+    let decoded: DecodedAccount = DecoderRegistry::default().decode(&account.account).unwrap();
+    let DecodedAccount::Mint(mint) = decoded else {
+        panic!("expected a mint, got {decoded:?}");
+    };
+    assert!(mint.is_token_2022());
+    assert_eq!(mint.mint().supply, 1_000_000);
+    assert_eq!(mint.mint().decimals, 6);
+    assert_eq!(
+        mint.extension_types().unwrap(),
+        vec![ExtensionType::MintCloseAuthority, ExtensionType::TokenMetadata]
+    );
+    let close_authority: &MintCloseAuthority = mint.state.get_extension::<MintCloseAuthority>().unwrap();
+    assert_eq!(Option::<Pubkey>::from(close_authority.close_authority), Some(authority));
+    assert_eq!(mint.token_metadata().unwrap(), Some(metadata));
+    assert_eq!(account.decode::<SplMint>().unwrap(), mint);
+}
+
+#[test]
+fn test_decode_stake_and_nonce_accounts() {
+    let staker: Pubkey = Pubkey::new_unique();
+    let stake: StakeStateV2 = StakeStateV2::Initialized(Meta {
+        rent_exempt_reserve: 2_282_880,
+        authorized: Authorized {
+            staker,
+            withdrawer: staker,
+        },
+        lockup: Lockup::default(),
+    });
+    let mut stake_data: Vec<u8> = bincode::serialize(&stake).unwrap();
+    stake_data.resize(StakeStateV2::size_of(), 0);
+    let stake_account: AccountInfo = account_info(
+        &solana_stake_interface::program::ID,
+        json!([bs58::encode(&stake_data).into_string(), "base58"]),
+    );
+    assert_eq!(stake_account.decode::<StakeStateV2>().unwrap(), stake);
+
+    let nonce: NonceState =
+        NonceState::new_initialized(&staker, DurableNonce::from_blockhash(&Hash::new_unique()), 5_000);
+    let nonce_data: Vec<u8> = bincode::serialize(&Versions::new(nonce.clone())).unwrap();
+    // Binary data without an encoding tag is base58
+    let nonce_account: AccountInfo = account_info(
+        &Pubkey::default(),
+        Value::String(bs58::encode(&nonce_data).into_string()),
+    );
+    let decoded: DecodedAccount = DecoderRegistry::default().decode(&nonce_account).unwrap();
+    assert!(matches!(decoded, DecodedAccount::Nonce(state) if state == nonce));
+
+    // Decoders check the owning program
+    assert!(matches!(
+        nonce_account.decode::<StakeStateV2>(),
+        Err(HeliusError::AccountDecode(_))
+    ));
+}
+
+#[test]
+fn test_decode_json_parsed_accounts() {
+    let mint: Pubkey = Pubkey::new_unique();
+    let owner: Pubkey = Pubkey::new_unique();
+    let delegate: Pubkey = Pubkey::new_unique();
+    let token_account: AccountInfo = account_info(
+        &TOKEN_2022_PROGRAM_ID,
+        json!({
+            "program": "spl-token-2022",
+            "parsed": {
+                "type": "account",
+                "info": {
+                    "mint": mint.to_string(),
+                    "owner": owner.to_string(),
+                    "tokenAmount": { "amount": "1500", "decimals": 2, "uiAmount": 15.0, "uiAmountString": "15" },
+                    "delegate": delegate.to_string(),
+                    "delegatedAmount": { "amount": "500", "decimals": 2, "uiAmount": 5.0, "uiAmountString": "5" },
+                    "state": "frozen",
+                    "isNative": false,
+                },
+            },
+            "space": 165,
+        }),
+    );
+    let decoded: SplTokenAccount = token_account.decode().unwrap();
+    assert!(decoded.is_token_2022());
+    assert_eq!(
+        *decoded.account(),
+        Account {
+            mint,
+            owner,
+            amount: 1500,
+            delegate: COption::Some(delegate),
+            state: AccountState::Frozen,
+            is_native: COption::None,
+            delegated_amount: 500,
+            close_authority: COption::None,
+        }
+    );
+    assert!(matches!(
+        DecoderRegistry::default().decode(&token_account).unwrap(),
+        DecodedAccount::TokenAccount(account) if account == decoded
+    ));
+    assert!(matches!(
+        token_account.decode::<SplMint>(),
+        Err(HeliusError::AccountDecode(_))
+    ));
+
+    let mint_account: AccountInfo = account_info(
+        &TOKEN_PROGRAM_ID,
+        json!({
+            "program": "spl-token",
+            "parsed": {
+                "type": "mint",
+                "info": {
+                    "mintAuthority": owner.to_string(),
+                    "supply": "1000000000",
+                    "decimals": 6,
+                    "isInitialized": true,
+                    "freezeAuthority": null,
+                },
+            },
+            "space": 82,
+        }),
+    );
+    let decoded: SplMint = mint_account.decode().unwrap();
+    assert_eq!(
+        *decoded.mint(),
+        Mint {
+            mint_authority: COption::Some(owner),
+            supply: 1_000_000_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+    );
+
+    // Token-2022 extensions are only decoded from binary encodings
+    let mut with_extensions: AccountInfo = mint_account.clone();
+    with_extensions.owner = TOKEN_2022_PROGRAM_ID.to_string();
+    with_extensions.data["parsed"]["info"]["extensions"] =
+        json!([{ "extension": "mintCloseAuthority", "state": { "closeAuthority": owner.to_string() } }]);
+    assert!(matches!(
+        with_extensions.decode::<SplMint>(),
+        Err(HeliusError::AccountDecode(_))
+    ));
+
+    let voter: Pubkey = Pubkey::new_unique();
+    let stake_account: AccountInfo = account_info(
+        &solana_stake_interface::program::ID,
+        json!({
+            "program": "stake",
+            "parsed": {
+                "type": "delegated",
+                "info": {
+                    "meta": {
+                        "rentExemptReserve": "2282880",
+                        "authorized": { "staker": owner.to_string(), "withdrawer": owner.to_string() },
+                        "lockup": { "unixTimestamp": 0, "epoch": 0, "custodian": Pubkey::default().to_string() },
+                    },
+                    "stake": {
+                        "delegation": {
+                            "voter": voter.to_string(),
+                            "stake": "1000000000",
+                            "activationEpoch": "500",
+                            "deactivationEpoch": "18446744073709551615",
+                            "warmupCooldownRate": 0.25,
+                        },
+                        "creditsObserved": 42,
+                    },
+                },
+            },
+            "space": 200,
+        }),
+    );
+    let StakeStateV2::Stake(meta, stake, _) = stake_account.decode::<StakeStateV2>().unwrap() else {
+        panic!("Expected a delegated stake account");
+    };
+    assert_eq!(
+        meta,
+        Meta {
+            rent_exempt_reserve: 2_282_880,
+            authorized: Authorized {
+                staker: owner,
+                withdrawer: owner,
+            },
+            lockup: Lockup::default(),
+        }
+    );
+    assert_eq!(
+        stake,
+        Stake {
+            delegation: Delegation::new(&voter, 1_000_000_000, 500),
+            credits_observed: 42,
+        }
+    );
+
+    let durable_nonce: DurableNonce = DurableNonce::from_blockhash(&Hash::new_unique());
+    let nonce_account: AccountInfo = account_info(
+        &Pubkey::default(),
+        json!({
+            "program": "nonce",
+            "parsed": {
+                "type": "initialized",
+                "info": {
+                    "authority": owner.to_string(),
+                    "blockhash": durable_nonce.as_hash().to_string(),
+                    "feeCalculator": { "lamportsPerSignature": "5000" },
+                },
+            },
+            "space": 80,
+        }),
+    );
+    assert!(matches!(
+        DecoderRegistry::default().decode(&nonce_account).unwrap(),
+        DecodedAccount::Nonce(NonceState::Initialized(data)) if data == NonceData::new(owner, durable_nonce, 5_000)
+    ));
+}
+
+#[derive(Debug, PartialEq)]
+struct Counter {
+    count: u64,
+}
+
+impl AccountDecoder for Counter {
+    fn decode_account(_owner: &Pubkey, data: &[u8]) -> Result<Self> {
+        let bytes: [u8; 8] = data
+            .get(8..16)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| HeliusError::AccountDecode("Counter account is too short".to_string()))?;
+        Ok(Counter {
+            count: u64::from_le_bytes(bytes),
+        })
+    }
+}
+
+#[test]
+fn test_registry_custom_decoders() {
+    let program_id: Pubkey = Pubkey::new_unique();
+    let mut data: Vec<u8> = vec![0; 8];
+    data.extend_from_slice(&7u64.to_le_bytes());
+    let account: AccountInfo = account_info(&program_id, base64(&data));
+
+    let mut registry: DecoderRegistry = DecoderRegistry::default();
+    assert!(matches!(registry.decode(&account), Err(HeliusError::AccountDecode(_))));
+
+    registry.register::<Counter>(program_id);
+    assert!(registry.contains(&program_id));
+    let decoded: DecodedAccount = registry.decode(&account).unwrap();
+    assert_eq!(decoded.as_custom::<Counter>(), Some(&Counter { count: 7 }));
+    assert_eq!(decoded.into_custom::<Counter>(), Some(Counter { count: 7 }));
+
+    // jsonParsed data has no raw bytes to decode
+    let parsed: AccountInfo = account_info(&program_id, json!({ "parsed": {}, "program": "counter", "space": 16 }));
+    assert!(matches!(parsed.data_bytes(), Err(HeliusError::AccountDecode(_))));
+}
@@ -0,0 +1,294 @@
+#![cfg(feature = "testing")]
+
+use std::time::Duration;
+
+use helius::error::{HeliusError, Result};
+use helius::services::fake::FakeDas;
+use helius::testing::{FakeHeliusServer, BLOCK_HEIGHT};
+use helius::types::*;
+use helius::Helius;
+
+use serde_json::{json, Value};
+use solana_account_decoder::{UiAccount, UiAccountData, UiAccountEncoding};
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature};
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
+use tokio::time::timeout;
+use tokio_stream::StreamExt;
+
+const OWNER: &str = "86xCnPeV69n6t3DnyGvkKobf9FdN2H9oiVDdaMpo2MMY";
+const PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+fn asset(id: &str, owner: &str) -> Asset {
+    serde_json::from_value(json!({
+        "interface": "V1_NFT",
+        "id": id,
+        "ownership": {
+            "frozen": false,
+            "delegated": false,
+            "delegate": null,
+            "ownership_model": "single",
+            "owner": owner,
+        },
+        "mutable": true,
+        "burnt": false,
+        "mint_extensions": null,
+        "token_info": null,
+    }))
+    .unwrap()
+}
+
+fn program_account(pubkey: &str) -> GpaAccount {
+    GpaAccount {
+        pubkey: pubkey.to_string(),
+        account: AccountInfo {
+            lamports: 2_039_280,
+            owner: PROGRAM_ID.to_string(),
+            data: json!(["", "base64"]),
+            executable: false,
+            rent_epoch: 0,
+            space: Some(0),
+        },
+    }
+}
+
+fn transaction_notification(signature: &str, account_keys: &[&str]) -> TransactionNotification {
+    serde_json::from_value(json!({
+        "transaction": {
+            "transaction": {
+                "signatures": [signature],
+                "message": {
+                    "header": {
+                        "numRequiredSignatures": 1,
+                        "numReadonlySignedAccounts": 0,
+                        "numReadonlyUnsignedAccounts": 0,
+                    },
+                    "accountKeys": account_keys,
+                    "recentBlockhash": Hash::default().to_string(),
+                    "instructions": [],
+                },
+            },
+            "meta": null,
+        },
+        "signature": signature,
+        "slot": 1_000,
+    }))
+    .unwrap()
+}
+
+fn helius_for(server: &FakeHeliusServer) -> Helius {
+    Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(server.cluster())
+        .with_async_solana()
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_das_methods_are_served_from_fixtures() {
+    let server: FakeHeliusServer = FakeHeliusServer::builder()
+        .das(FakeDas::new().with_asset(asset("asset-1", OWNER)))
+        .start()
+        .await
+        .unwrap();
+    let helius: Helius = helius_for(&server);
+
+    let assets: AssetList = helius
+        .rpc()
+        .get_assets_by_owner(GetAssetsByOwner {
+            owner_address: OWNER.to_string(),
+            page: 1,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(assets.items.len(), 1);
+    assert_eq!(assets.items[0].id, "asset-1");
+
+    let unsupported: Result<Value> = helius.rpc().post_rpc_request("getHealth", json!([])).await;
+    assert!(matches!(unsupported, Err(HeliusError::Rpc { code: -32601, .. })));
+}
+
+#[tokio::test]
+async fn test_webhook_crud_keeps_state() {
+    let server: FakeHeliusServer = FakeHeliusServer::start().await.unwrap();
+    let helius: Helius = helius_for(&server);
+
+    let webhook: Webhook = helius
+        .create_webhook(CreateWebhookRequest {
+            webhook_url: "https://webhook.site/0e8250a1-ceec-4757-ad69-cc6473085bfc".to_string(),
+            transaction_types: vec![TransactionType::Any],
+            account_addresses: vec![OWNER.to_string()],
+            webhook_type: WebhookType::Enhanced,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let edited: Webhook = helius
+        .append_addresses_to_webhook(&webhook.webhook_id, &[PROGRAM_ID.to_string()])
+        .await
+        .unwrap();
+    assert_eq!(edited.account_addresses, vec![OWNER, PROGRAM_ID]);
+    assert_eq!(server.webhooks()[0].account_addresses, vec![OWNER, PROGRAM_ID]);
+
+    helius.delete_webhook(&webhook.webhook_id).await.unwrap();
+    assert!(helius.get_all_webhooks().await.unwrap().is_empty());
+    let deleted: Result<Webhook> = helius.get_webhook_by_id(&webhook.webhook_id).await;
+    assert!(matches!(deleted, Err(HeliusError::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn test_program_accounts_v2_paginates() {
+    let pubkeys: Vec<String> = (0..5).map(|_| Pubkey::new_unique().to_string()).collect();
+    let server: FakeHeliusServer = pubkeys
+        .iter()
+        .fold(FakeHeliusServer::builder(), |builder, pubkey| {
+            builder.with_program_account(PROGRAM_ID, program_account(pubkey))
+        })
+        .start()
+        .await
+        .unwrap();
+    let helius: Helius = helius_for(&server);
+
+    let config = GetProgramAccountsV2Config {
+        limit: Some(2),
+        ..Default::default()
+    };
+    let first_page: GetProgramAccountsV2Response = helius
+        .rpc()
+        .get_program_accounts_v2(PROGRAM_ID.to_string(), config.clone())
+        .await
+        .unwrap();
+    assert_eq!(first_page.accounts.len(), 2);
+    assert_eq!(first_page.pagination_key.as_deref(), Some(pubkeys[1].as_str()));
+    assert_eq!(first_page.total_results, Some(5));
+
+    let accounts: Vec<GpaAccount> = helius
+        .rpc()
+        .get_all_program_accounts(PROGRAM_ID.to_string(), config)
+        .await
+        .unwrap();
+    let fetched: Vec<String> = accounts.into_iter().map(|account| account.pubkey).collect();
+    assert_eq!(fetched, pubkeys);
+}
+
+#[tokio::test]
+async fn test_priority_fee_estimate_uses_configured_levels() {
+    let server: FakeHeliusServer = FakeHeliusServer::start().await.unwrap();
+    let helius: Helius = helius_for(&server);
+
+    let estimate: GetPriorityFeeEstimateResponse = helius
+        .rpc()
+        .get_priority_fee_estimate(GetPriorityFeeEstimateRequest {
+            account_keys: Some(vec![PROGRAM_ID.to_string()]),
+            options: Some(GetPriorityFeeEstimateOptions {
+                priority_level: Some(PriorityLevel::High),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(estimate.priority_fee_estimate, Some(100_000.0));
+
+    let levels: GetPriorityFeeEstimateResponse = helius
+        .rpc()
+        .get_priority_fee_estimate(GetPriorityFeeEstimateRequest {
+            options: Some(GetPriorityFeeEstimateOptions {
+                include_all_priority_fee_levels: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(levels.priority_fee_levels.unwrap().medium, 10_000.0);
+}
+
+#[tokio::test]
+async fn test_websocket_subscriptions_receive_pushes() {
+    let server: FakeHeliusServer = FakeHeliusServer::builder()
+        .api_key("fake_api_key")
+        .start()
+        .await
+        .unwrap();
+    let helius: Helius = Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(server.cluster())
+        .with_websocket()
+        .connect()
+        .await
+        .unwrap();
+    let ws = helius.ws().unwrap();
+
+    let watched: Pubkey = Pubkey::new_unique();
+    let (mut transactions, _unsubscribe) = ws
+        .transaction_subscribe(RpcTransactionsConfig {
+            filter: TransactionSubscribeFilter::standard(&watched),
+            options: TransactionSubscribeOptions::default(),
+        })
+        .await
+        .unwrap();
+    let (mut accounts, _unsubscribe) = ws.account_subscribe(&watched, None).await.unwrap();
+
+    let unrelated: String = Signature::new_unique().to_string();
+    let matching: String = Signature::new_unique().to_string();
+    server
+        .push_transaction(&transaction_notification(&unrelated, &[OWNER]))
+        .unwrap();
+    server
+        .push_transaction(&transaction_notification(&matching, &[OWNER, &watched.to_string()]))
+        .unwrap();
+
+    let notification: TransactionNotification = timeout(Duration::from_secs(5), transactions.next())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(notification.signature, matching);
+
+    let account: UiAccount = UiAccount {
+        lamports: 42,
+        data: UiAccountData::Binary(String::new(), UiAccountEncoding::Base64),
+        owner: PROGRAM_ID.to_string(),
+        executable: false,
+        rent_epoch: 0,
+        space: Some(0),
+    };
+    server.push_account(&watched, &account, 1_000).unwrap();
+
+    let update = timeout(Duration::from_secs(5), accounts.next()).await.unwrap().unwrap();
+    assert_eq!(update.value.lamports, 42);
+}
+
+#[tokio::test]
+async fn test_sender_records_and_confirms_submissions() {
+    let server: FakeHeliusServer = FakeHeliusServer::start().await.unwrap();
+    let helius: Helius = helius_for(&server);
+
+    helius.warm_sender_connection("Default").await.unwrap();
+
+    let payer: Keypair = Keypair::new();
+    let transaction: Transaction =
+        Transaction::new_signed_with_payer(&[], Some(&payer.pubkey()), &[&payer], Hash::default());
+    let signature: Signature = helius
+        .send_and_confirm_via_sender(
+            &transaction,
+            BLOCK_HEIGHT,
+            SenderSendOptions {
+                swqos_only: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let submissions = server.sender_submissions();
+    assert_eq!(submissions.len(), 1);
+    assert_eq!(submissions[0].signature, signature);
+    assert_eq!(signature, transaction.signatures[0]);
+    assert!(submissions[0].swqos_only);
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "das")]
+
+mod common;
+
+use common::merkle::{encode, fixture, helium_hotspot, Fixture};
+use helius::error::{HeliusError, Result};
+use helius::merkle::{compute_leaf_hash, compute_root, verify_asset_proof, verify_merkle_proof};
+use helius::types::{Asset, AssetProof, Compression};
+
+use solana_sdk::{bs58, pubkey::Pubkey};
+use std::str::FromStr;
+
+#[test]
+fn test_verify_asset_proof() {
+    let Fixture { asset, proof, .. } = fixture(None);
+    verify_merkle_proof(&proof).unwrap();
+    verify_asset_proof(&asset, &proof).unwrap();
+
+    let Fixture { asset, proof, .. } = fixture(Some(Pubkey::new_unique()));
+    verify_asset_proof(&asset, &proof).unwrap();
+}
+
+#[test]
+fn test_stale_ownership_is_rejected() {
+    let Fixture { mut asset, proof, .. } = fixture(None);
+    // The asset was transferred after the proof was indexed
+    asset.ownership.owner = Pubkey::new_unique().to_string();
+
+    let result: Result<()> = verify_asset_proof(&asset, &proof);
+    assert!(matches!(result, Err(HeliusError::InvalidProof(_))));
+}
+
+#[test]
+fn test_inconsistent_proofs_are_rejected() {
+    let Fixture { asset, proof, .. } = fixture(None);
+
+    let mut tampered: AssetProof = proof;
+    tampered.proof[1] = encode(&[7; 32]);
+    assert!(matches!(
+        verify_merkle_proof(&tampered),
+        Err(HeliusError::InvalidProof(_))
+    ));
+    assert!(matches!(
+        verify_asset_proof(&asset, &tampered),
+        Err(HeliusError::InvalidProof(_))
+    ));
+
+    let other_tree: AssetProof = AssetProof {
+        tree_id: Pubkey::new_unique().to_string(),
+        ..tampered
+    };
+    assert!(matches!(
+        verify_asset_proof(&asset, &other_tree),
+        Err(HeliusError::InvalidProof(_))
+    ));
+
+    // A node index outside the leaves of the tree can't be placed
+    let Fixture { proof, .. } = fixture(None);
+    let not_a_leaf: AssetProof = AssetProof { node_index: 3, ..proof };
+    assert!(matches!(
+        verify_merkle_proof(&not_a_leaf),
+        Err(HeliusError::InvalidInput(_))
+    ));
+}
+
+fn decode(hash: &str) -> [u8; 32] {
+    bs58::decode(hash).into_vec().unwrap().try_into().unwrap()
+}
+
+#[test]
+fn test_recorded_mainnet_hashes() {
+    // The leaf hash of a mainnet cNFT, computed from its recorded `getAsset` response, is the one the indexer recorded
+    let asset: Asset = helium_hotspot();
+    let compression: &Compression = asset.compression.as_ref().unwrap();
+    let owner: Pubkey = Pubkey::from_str(&asset.ownership.owner).unwrap();
+    let leaf: [u8; 32] = compute_leaf_hash(
+        &Pubkey::from_str(&asset.id).unwrap(),
+        &owner,
+        &owner,
+        compression.leaf_id as u64,
+        &decode(&compression.data_hash),
+        &decode(&compression.creator_hash),
+    );
+    assert_eq!(encode(&leaf), "DE2bb82qfupE4Wcv28brKXCKztnEKC2XnwxAmN8LzqPe");
+
+    // A recorded mainnet `getAssetProof` response (for Bu1DEKeawy7txbnCEJE4BU3BKLXaNAKCYcHR4XhndGss) hashes up to its
+    // recorded root
+    let proof: AssetProof = AssetProof {
+        root: "FcyXjiB62Jd7pidqvJGaqxoY9CQxDn65tFM69iW8vaji".to_string(),
+        proof: [
+            "EmJXiXEAhEN3FfNQtBa5hwR8LC5kHvdLsaGCoERosZjK",
+            "7NEfhcNPAwbw3L87fjsPqTz2fQdd1CjoLE138SD58FDQ",
+            "6dM3VyeQoYkRFZ74G53EwvUPbQC6LsMZge6c7S1Ds4ks",
+            "A9AACJ5m7UtaVz4HxzhDxGjYaY88rc2XPoFvnoTvgYBj",
+            "2VG5cKeBZdqozwhHGGzs13b9tzy9TXt9kPfN8MzSJ1Sm",
+            "3E1uFze4pi6BnTZXMsQbeh3jQCeDi966Zax9aMbYgg2D",
+            "EZWcjuxCvSj2megG1zXKfXkUF2MKdSEaYfKFGeYSoPrQ",
+            "HSbJ8quT4vuXFgf5FnjzeUuFfAtLKsq6W1Frj8y1qrif",
+            "GJMLzL4F4hY9yFHY1EY6XRmW4wpuNGeBZTiv7vM2mYra",
+            "FYPtEiqmRx6JprHQvWeEWEuVp3WA7DPRCE4VbhFRVuAj",
+            "6MJKrpnK1GbYsnEzwMRWStNGkTjAZF23NhzTQSQVXsD3",
+            "HjnrJn5vBUUzpCxzjjM9ZnCPuXei2cXKJjX468B9yWD7",
+            "4YCF1CSyTXm1Yi9W9JeYevawupkomdgy2dLxEBHL9euq",
+            "E3oMtCuPEauftdZLX8EZ8YX7BbFzpBCVRYEiLxwPJLY2",
+        ]
+        .map(String::from)
+        .to_vec(),
+        node_index: 16384,
+        leaf: "6YdZXw49M97mfFTwgQb6kxM2c6eqZkHSaW9XhhoZXtzv".to_string(),
+        tree_id: "2kuTFCcjbV22wvUmtmgsFR7cas7eZUzAu96jzJUvUcb7".to_string(),
+    };
+    verify_merkle_proof(&proof).unwrap();
+    let nodes: Vec<[u8; 32]> = proof.proof.iter().map(|node| decode(node)).collect();
+    assert_eq!(encode(&compute_root(decode(&proof.leaf), &nodes, 0)), proof.root);
+    assert_ne!(encode(&compute_root(decode(&proof.leaf), &nodes, 1)), proof.root);
+}
@@ -0,0 +1,143 @@
+use helius::error::{HeliusError, Result};
+use helius::metrics::MetricsSink;
+use helius::retry::RetryPolicy;
+use helius::types::*;
+use helius::Helius;
+
+use mockito::{self, Server};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Default)]
+struct RecordingSink {
+    operations: Mutex<Vec<(String, Option<&'static str>)>>,
+    retries: Mutex<Vec<String>>,
+}
+
+impl MetricsSink for RecordingSink {
+    fn record_operation(&self, operation: &str, _elapsed: Duration, error: Option<&HeliusError>) {
+        self.operations
+            .lock()
+            .unwrap()
+            .push((operation.to_string(), error.map(HeliusError::kind)));
+    }
+
+    fn record_retry(&self, operation: &str) {
+        self.retries.lock().unwrap().push(operation.to_string());
+    }
+}
+
+fn helius_for(url: &str, metrics: Arc<RecordingSink>) -> Helius {
+    Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: url.to_string(),
+            rpc: url.to_string(),
+            ..Default::default()
+        }))
+        .retry_policy(
+            RetryPolicy::default()
+                .with_initial_backoff(Duration::from_millis(1))
+                .with_jitter(false),
+        )
+        .metrics(metrics)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_metrics_record_operations_and_retries() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(503)
+        .expect(1)
+        .create();
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"jsonrpc": "2.0", "id": "1", "result": null}).to_string())
+        .create();
+
+    let metrics: Arc<RecordingSink> = Arc::new(RecordingSink::default());
+    let helius: Helius = helius_for(&server.url(), metrics.clone());
+
+    let response: Result<Option<Asset>> = helius
+        .rpc()
+        .get_asset(GetAsset {
+            id: "asset".to_string(),
+            display_options: None,
+        })
+        .await;
+
+    assert!(response.unwrap().is_none());
+    assert_eq!(*metrics.retries.lock().unwrap(), vec!["getAsset"]);
+    assert_eq!(
+        *metrics.operations.lock().unwrap(),
+        vec![("getAsset".to_string(), None)]
+    );
+}
+
+#[tokio::test]
+async fn test_metrics_record_error_kind() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(401)
+        .with_body(r#"{"error": "invalid api key"}"#)
+        .create();
+
+    let metrics: Arc<RecordingSink> = Arc::new(RecordingSink::default());
+    let helius: Helius = helius_for(&server.url(), metrics.clone());
+
+    let response: Result<Option<Asset>> = helius
+        .rpc()
+        .get_asset(GetAsset {
+            id: "asset".to_string(),
+            display_options: None,
+        })
+        .await;
+
+    assert!(response.is_err());
+    assert!(metrics.retries.lock().unwrap().is_empty());
+    assert_eq!(
+        *metrics.operations.lock().unwrap(),
+        vec![("getAsset".to_string(), Some("Unauthorized"))]
+    );
+}
+
+#[cfg(feature = "prometheus")]
+#[test]
+fn test_prometheus_metrics_render() {
+    use helius::metrics::PrometheusMetrics;
+
+    let metrics: PrometheusMetrics = PrometheusMetrics::with_buckets(vec![0.1, 1.0]);
+    metrics.record_operation("getAsset", Duration::from_millis(50), None);
+    metrics.record_operation("getAsset", Duration::from_millis(500), None);
+    metrics.record_operation(
+        "getAsset",
+        Duration::from_secs(2),
+        Some(&HeliusError::NotFound {
+            text: "missing".to_string(),
+        }),
+    );
+    metrics.record_retry("getAsset");
+    metrics.record_notification("transaction");
+
+    let output: String = metrics.render();
+    for line in [
+        r#"helius_operation_duration_seconds_bucket{operation="getAsset",le="0.1"} 1"#,
+        r#"helius_operation_duration_seconds_bucket{operation="getAsset",le="1"} 2"#,
+        r#"helius_operation_duration_seconds_bucket{operation="getAsset",le="+Inf"} 3"#,
+        r#"helius_operation_duration_seconds_count{operation="getAsset"} 3"#,
+        r#"helius_operation_errors_total{operation="getAsset",error="NotFound"} 1"#,
+        r#"helius_operation_retries_total{operation="getAsset"} 1"#,
+        r#"helius_websocket_notifications_total{subscription="transaction"} 1"#,
+    ] {
+        assert!(output.lines().any(|l| l == line), "missing `{line}` in:\n{output}");
+    }
+}
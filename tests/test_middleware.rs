@@ -0,0 +1,210 @@
+use helius::error::{HeliusError, Result};
+use helius::middleware::Middleware;
+use helius::request_handler::{RequestContext, RequestHandler};
+use helius::types::Cluster;
+use helius::Helius;
+
+use mockito::{self, Server};
+use reqwest::header::HeaderValue;
+use reqwest::{Client, Method, Request, Response, Url};
+use serde::Deserialize;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Deserialize, Debug, Default)]
+struct MockResponse {
+    message: String,
+}
+
+struct AddHeader;
+
+impl Middleware for AddHeader {
+    fn before_request(&self, _context: &RequestContext, request: &mut Request) -> Result<()> {
+        request
+            .headers_mut()
+            .insert("x-trace-id", HeaderValue::from_static("trace-123"));
+        Ok(())
+    }
+}
+
+struct Rewrite(Url);
+
+impl Middleware for Rewrite {
+    fn before_request(&self, _context: &RequestContext, request: &mut Request) -> Result<()> {
+        *request.url_mut() = self.0.clone();
+        Ok(())
+    }
+}
+
+struct Reject;
+
+impl Middleware for Reject {
+    fn before_request(&self, _context: &RequestContext, _request: &mut Request) -> Result<()> {
+        Err(HeliusError::InvalidInput("rejected by middleware".to_string()))
+    }
+}
+
+struct Recorder {
+    name: &'static str,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for Recorder {
+    fn before_request(&self, context: &RequestContext, _request: &mut Request) -> Result<()> {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("before {} {}", self.name, context.operation));
+        Ok(())
+    }
+
+    fn after_response(
+        &self,
+        context: &RequestContext,
+        outcome: std::result::Result<&Response, &HeliusError>,
+        _elapsed: Duration,
+    ) {
+        let status: u16 = outcome.map(|response| response.status().as_u16()).unwrap_or(0);
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("after {} {} {}", self.name, context.operation, status));
+    }
+}
+
+#[tokio::test]
+async fn test_middleware_adds_header() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    let mock = server
+        .mock("GET", "/")
+        .match_header("x-trace-id", "trace-123")
+        .with_status(200)
+        .with_body(r#"{"message": "success"}"#)
+        .create();
+
+    let handler: RequestHandler = RequestHandler::new(Arc::new(Client::new()))
+        .unwrap()
+        .with_middleware(Arc::new(AddHeader));
+
+    let response: MockResponse = handler
+        .send::<(), MockResponse>(Method::GET, server.url().parse().unwrap(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(response.message, "success");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_middleware_rewrites_url() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    let mock = server
+        .mock("GET", "/proxy")
+        .with_status(200)
+        .with_body(r#"{"message": "proxied"}"#)
+        .create();
+
+    let proxy: Url = format!("{}/proxy", server.url()).parse().unwrap();
+    let handler: RequestHandler = RequestHandler::new(Arc::new(Client::new()))
+        .unwrap()
+        .with_middleware(Arc::new(Rewrite(proxy)));
+
+    let response: MockResponse = handler
+        .send::<(), MockResponse>(Method::GET, "http://127.0.0.1:1/".parse().unwrap(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(response.message, "proxied");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_middleware_hook_order() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    server
+        .mock("GET", "/")
+        .with_status(200)
+        .with_body(r#"{"message": "success"}"#)
+        .create();
+
+    let events: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let handler: RequestHandler = RequestHandler::new(Arc::new(Client::new()))
+        .unwrap()
+        .with_middleware(Arc::new(Recorder {
+            name: "first",
+            events: events.clone(),
+        }))
+        .with_middleware(Arc::new(Recorder {
+            name: "second",
+            events: events.clone(),
+        }));
+
+    let context: RequestContext = RequestContext::rpc("getHealth");
+    handler
+        .send_with_context::<(), MockResponse>(&context, Method::GET, server.url().parse().unwrap(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            "before first getHealth",
+            "before second getHealth",
+            "after second getHealth 200",
+            "after first getHealth 200",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_middleware_error_aborts_request() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    let mock = server.mock("GET", "/").expect(0).create();
+
+    let handler: RequestHandler = RequestHandler::new(Arc::new(Client::new()))
+        .unwrap()
+        .with_middleware(Arc::new(Reject));
+
+    let response: Result<MockResponse> = handler
+        .send::<(), MockResponse>(Method::GET, server.url().parse().unwrap(), None)
+        .await;
+
+    assert!(matches!(response, Err(HeliusError::InvalidInput(_))));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_sender_requests_pass_through_middleware() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+
+    let ping = server
+        .mock("GET", "/ping")
+        .match_header("x-trace-id", "trace-123")
+        .with_status(200)
+        .create();
+
+    let events: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let helius: Helius = Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(Cluster::Localnet)
+        .sender_url(server.url())
+        .middleware(AddHeader)
+        .middleware(Recorder {
+            name: "recorder",
+            events: events.clone(),
+        })
+        .build()
+        .unwrap();
+
+    helius.warm_sender_connection("").await.unwrap();
+
+    ping.assert();
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec!["before recorder senderPing", "after recorder senderPing 200"]
+    );
+}
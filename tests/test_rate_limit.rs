@@ -0,0 +1,135 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use helius::error::{HeliusError, Result};
+use helius::rate_limit::{RateLimiter, RateLimits};
+use helius::request_handler::EndpointFamily;
+use helius::types::{AssetProof, Cluster, GetAssetProof, HeliusEndpoints};
+use helius::Helius;
+
+use mockito::{self, Server};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_rate_limiter_waits_for_capacity() {
+    let rate_limiter: RateLimiter = RateLimiter::new(RateLimits::default().with_das(Some(20))).unwrap();
+
+    let start: Instant = Instant::now();
+    for _ in 0..30 {
+        rate_limiter.acquire(EndpointFamily::Das).await;
+    }
+
+    // The first 20 requests use the initial burst, and the remaining 10 are spaced 50ms apart
+    assert!(start.elapsed() >= Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn test_rate_limiter_buckets_are_independent() {
+    let rate_limiter: RateLimiter = RateLimiter::new(RateLimits::default().with_das(Some(1))).unwrap();
+    rate_limiter.acquire(EndpointFamily::Das).await;
+
+    let start: Instant = Instant::now();
+    for _ in 0..100 {
+        rate_limiter.acquire(EndpointFamily::Rpc).await;
+        rate_limiter.acquire(EndpointFamily::EnhancedApi).await;
+    }
+
+    assert!(start.elapsed() < Duration::from_millis(100));
+}
+
+#[test]
+fn test_rate_limits_reject_zero() {
+    let limits: RateLimits = RateLimits::free().with_rpc(Some(0));
+    assert!(matches!(RateLimiter::new(limits), Err(HeliusError::InvalidInput(_))));
+
+    let result: Result<Helius> = Helius::builder().api_key("valid-api-key").rate_limits(limits).build();
+    assert!(matches!(result, Err(HeliusError::InvalidInput(_))));
+}
+
+#[test]
+fn test_endpoint_family_for_rpc_method() {
+    assert_eq!(EndpointFamily::for_rpc_method("getAsset"), EndpointFamily::Das);
+    assert_eq!(EndpointFamily::for_rpc_method("searchAssets"), EndpointFamily::Das);
+    assert_eq!(
+        EndpointFamily::for_rpc_method("getProgramAccountsV2"),
+        EndpointFamily::Rpc
+    );
+    assert_eq!(
+        EndpointFamily::for_rpc_method("getPriorityFeeEstimate"),
+        EndpointFamily::Rpc
+    );
+}
+
+#[test]
+fn test_rate_limiter_is_shared_across_clones() {
+    let helius: Helius = Helius::builder()
+        .api_key("valid-api-key")
+        .rate_limits(RateLimits::developer())
+        .build()
+        .unwrap();
+    let clone: Helius = helius.clone();
+
+    let limiter: &Arc<RateLimiter> = helius.rpc_client.handler.rate_limiter().unwrap();
+    assert!(Arc::ptr_eq(limiter, clone.rpc_client.handler.rate_limiter().unwrap()));
+    assert_eq!(limiter.limits(), &RateLimits::developer());
+
+    let shared: Arc<RateLimiter> = Arc::new(RateLimiter::new(RateLimits::free()).unwrap());
+    let first: Helius = Helius::builder()
+        .api_key("valid-api-key")
+        .rate_limiter(shared.clone())
+        .build()
+        .unwrap();
+    assert!(Arc::ptr_eq(&shared, first.rpc_client.handler.rate_limiter().unwrap()));
+}
+
+#[tokio::test]
+async fn test_requests_are_throttled() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let mock = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": "1",
+                "result": {
+                    "root": "root",
+                    "proof": [],
+                    "node_index": 1,
+                    "leaf": "leaf",
+                    "tree_id": "tree"
+                }
+            })
+            .to_string(),
+        )
+        .expect(7)
+        .create();
+
+    let helius: Helius = Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: url.clone(),
+            rpc: url,
+            ..Default::default()
+        }))
+        .rate_limits(RateLimits::default().with_das(Some(5)))
+        .build()
+        .unwrap();
+
+    let start: Instant = Instant::now();
+    for _ in 0..7 {
+        let response: Result<Option<AssetProof>> = helius
+            .rpc()
+            .get_asset_proof(GetAssetProof {
+                id: "asset".to_string(),
+            })
+            .await;
+        assert!(response.is_ok());
+    }
+
+    assert!(start.elapsed() >= Duration::from_millis(350));
+    mock.assert();
+}
@@ -1,10 +1,12 @@
 use helius::error::{HeliusError, Result};
-use helius::request_handler::RequestHandler;
+use helius::request_handler::{RequestContext, RequestHandler};
+use helius::retry::RetryPolicy;
 
 use mockito::{self, Server};
 use reqwest::{Client, Method};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Serialize, Deserialize, Debug, Default)]
 struct MockResponse {
@@ -103,3 +105,184 @@ async fn test_bad_request_with_json_rpc_error() {
 
     server.reset();
 }
+
+fn fast_retry_policy() -> RetryPolicy {
+    RetryPolicy::default()
+        .with_initial_backoff(Duration::from_millis(1))
+        .with_jitter(false)
+}
+
+#[tokio::test]
+async fn test_retries_idempotent_request_until_success() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let unavailable = server.mock("GET", "/").with_status(503).expect(2).create();
+    let success = server
+        .mock("GET", "/")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message": "success"}"#)
+        .create();
+
+    let client: Arc<Client> = Arc::new(Client::new());
+    let handler: RequestHandler = RequestHandler::new(client)
+        .unwrap()
+        .with_retry_policy(fast_retry_policy());
+
+    let response: Result<MockResponse> = handler
+        .send::<(), MockResponse>(Method::GET, url.parse().unwrap(), None)
+        .await;
+
+    assert_eq!(response.unwrap().message, "success");
+    unavailable.assert();
+    success.assert();
+}
+
+#[tokio::test]
+async fn test_does_not_retry_non_idempotent_request() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let mock = server.mock("POST", "/").with_status(500).expect(1).create();
+
+    let client: Arc<Client> = Arc::new(Client::new());
+    let handler: RequestHandler = RequestHandler::new(client)
+        .unwrap()
+        .with_retry_policy(fast_retry_policy());
+
+    let response: Result<MockResponse> = handler
+        .send::<(), MockResponse>(Method::POST, url.parse().unwrap(), None)
+        .await;
+
+    assert!(matches!(response, Err(HeliusError::InternalError { .. })));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_retries_request_marked_idempotent() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let mock = server.mock("POST", "/").with_status(429).expect(4).create();
+
+    let client: Arc<Client> = Arc::new(Client::new());
+    let handler: RequestHandler = RequestHandler::new(client)
+        .unwrap()
+        .with_retry_policy(fast_retry_policy());
+
+    let response: Result<MockResponse> = handler
+        .send_idempotent::<(), MockResponse>(Method::POST, url.parse().unwrap(), None)
+        .await;
+
+    assert!(matches!(response, Err(HeliusError::RateLimitExceeded { .. })));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_does_not_retry_client_errors() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let mock = server.mock("GET", "/").with_status(400).expect(1).create();
+
+    let client: Arc<Client> = Arc::new(Client::new());
+    let handler: RequestHandler = RequestHandler::new(client)
+        .unwrap()
+        .with_retry_policy(fast_retry_policy());
+
+    let response: Result<MockResponse> = handler
+        .send::<(), MockResponse>(Method::GET, url.parse().unwrap(), None)
+        .await;
+
+    assert!(matches!(response, Err(HeliusError::BadRequest { .. })));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_retry_after_beyond_max_elapsed_stops_retrying() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let mock = server
+        .mock("GET", "/")
+        .with_status(429)
+        .with_header("retry-after", "60")
+        .expect(1)
+        .create();
+
+    let client: Arc<Client> = Arc::new(Client::new());
+    let handler: RequestHandler = RequestHandler::new(client)
+        .unwrap()
+        .with_retry_policy(fast_retry_policy().with_max_elapsed(Some(Duration::from_secs(1))));
+
+    let response: Result<MockResponse> = handler
+        .send::<(), MockResponse>(Method::GET, url.parse().unwrap(), None)
+        .await;
+
+    assert!(matches!(response, Err(HeliusError::RateLimitExceeded { .. })));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_retry_after_is_capped_at_max_backoff() {
+    let mut server: Server = Server::new_with_opts_async(mockito::ServerOpts::default()).await;
+    let url: String = server.url();
+
+    let rate_limited = server
+        .mock("GET", "/")
+        .with_status(429)
+        .with_header("retry-after", "3600")
+        .expect(1)
+        .create();
+    let success = server
+        .mock("GET", "/")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message": "success"}"#)
+        .create();
+
+    let client: Arc<Client> = Arc::new(Client::new());
+    let handler: RequestHandler = RequestHandler::new(client)
+        .unwrap()
+        .with_retry_policy(fast_retry_policy().with_max_backoff(Duration::from_millis(10)));
+
+    let response: Result<MockResponse> = tokio::time::timeout(
+        Duration::from_secs(5),
+        handler.send::<(), MockResponse>(Method::GET, url.parse().unwrap(), None),
+    )
+    .await
+    .expect("Retry-After should be capped at max_backoff");
+
+    assert_eq!(response.unwrap().message, "success");
+    rate_limited.assert();
+    success.assert();
+}
+
+#[test]
+fn test_retry_policy_backoff_is_capped() {
+    let policy: RetryPolicy = RetryPolicy::default()
+        .with_initial_backoff(Duration::from_millis(100))
+        .with_max_backoff(Duration::from_millis(250))
+        .with_jitter(false);
+
+    assert_eq!(policy.backoff(0), Duration::from_millis(100));
+    assert_eq!(policy.backoff(1), Duration::from_millis(200));
+    assert_eq!(policy.backoff(2), Duration::from_millis(250));
+    assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(250));
+
+    let jittered: Duration = policy.clone().with_jitter(true).backoff(1);
+    assert!(jittered >= Duration::from_millis(100) && jittered <= Duration::from_millis(200));
+}
+
+#[test]
+fn test_rpc_context_is_idempotent_only_for_read_only_methods() {
+    assert!(RequestContext::rpc("getAsset").idempotent);
+    assert!(RequestContext::rpc("searchAssets").idempotent);
+    assert!(RequestContext::rpc("getProgramAccountsV2").idempotent);
+    assert!(RequestContext::rpc("getPriorityFeeEstimate").idempotent);
+    assert!(RequestContext::rpc("getSlot").idempotent);
+
+    assert!(!RequestContext::rpc("mintCompressedNft").idempotent);
+    assert!(!RequestContext::rpc("sendTransaction").idempotent);
+}
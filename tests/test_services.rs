@@ -0,0 +1,223 @@
+#![cfg(all(
+    feature = "das",
+    feature = "webhooks",
+    feature = "enhanced-transactions",
+    feature = "smart-transactions"
+))]
+
+use std::sync::Arc;
+
+use helius::error::{HeliusError, Result};
+use helius::services::fake::{FakeDas, FakeEnhancedTransactions, FakeTransactionSender, FakeWebhooks};
+use helius::services::{DasApi, EnhancedTransactionsApi, TransactionSender, WebhookApi};
+use helius::types::*;
+use helius::Helius;
+
+use mockito::{Server, ServerGuard};
+use serde_json::json;
+use solana_sdk::instruction::Instruction as SolanaInstruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature};
+use solana_sdk::signer::Signer;
+
+const OWNER: &str = "86xCnPeV69n6t3DnyGvkKobf9FdN2H9oiVDdaMpo2MMY";
+const OTHER_OWNER: &str = "2iK5FbRZcJHAfhUNYmYdKTzSLnZE9NGAECurPoxDA3o7";
+
+fn asset(id: &str, owner: &str) -> Asset {
+    serde_json::from_value(json!({
+        "interface": "V1_NFT",
+        "id": id,
+        "ownership": {
+            "frozen": false,
+            "delegated": false,
+            "delegate": null,
+            "ownership_model": "single",
+            "owner": owner,
+        },
+        "mutable": true,
+        "burnt": false,
+        "mint_extensions": null,
+        "token_info": null,
+    }))
+    .unwrap()
+}
+
+fn transaction(signature: &str, fee_payer: &str) -> EnhancedTransaction {
+    EnhancedTransaction {
+        account_data: vec![],
+        description: String::new(),
+        transaction_type: TransactionType::Transfer,
+        source: Source::SystemProgram,
+        fee: 5000,
+        fee_payer: fee_payer.to_string(),
+        signature: signature.to_string(),
+        slot: 148277128,
+        native_transfers: None,
+        token_transfers: None,
+        transaction_error: None,
+        instructions: vec![],
+        events: TransactionEvent::default(),
+        timestamp: 1656442333,
+    }
+}
+
+async fn owned_ids<D: DasApi>(das: &D, owner: &str, page: u32, limit: i32) -> Result<Vec<String>> {
+    let assets: AssetList = das
+        .get_assets_by_owner(GetAssetsByOwner {
+            owner_address: owner.to_string(),
+            page,
+            limit: Some(limit),
+            ..Default::default()
+        })
+        .await?;
+    Ok(assets.items.into_iter().map(|asset| asset.id).collect())
+}
+
+#[tokio::test]
+async fn test_fake_das_filters_and_paginates() {
+    let das: FakeDas = FakeDas::new()
+        .with_asset(asset("asset-1", OWNER))
+        .with_asset(asset("asset-2", OTHER_OWNER))
+        .with_asset(asset("asset-3", OWNER))
+        .with_asset(asset("asset-4", OWNER));
+
+    assert_eq!(owned_ids(&das, OWNER, 1, 2).await.unwrap(), vec!["asset-1", "asset-3"]);
+    assert_eq!(owned_ids(&das, OWNER, 2, 2).await.unwrap(), vec!["asset-4"]);
+    assert!(owned_ids(&das, OWNER, 3, 2).await.unwrap().is_empty());
+
+    let missing: Option<Asset> = das
+        .get_asset(GetAsset {
+            id: "asset-5".to_string(),
+            display_options: None,
+        })
+        .await
+        .unwrap();
+    assert!(missing.is_none());
+}
+
+#[tokio::test]
+async fn test_das_api_for_helius() {
+    let mut server: ServerGuard = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/?api-key=fake_api_key")
+        .match_body(mockito::Matcher::PartialJson(json!({"method": "getAssetsByOwner"})))
+        .with_status(200)
+        .with_header("Content-Type", "application/json")
+        .with_body(
+            json!({
+                "jsonrpc": "2.0",
+                "id": "1",
+                "result": {"total": 1, "limit": 10, "page": 1, "items": [asset("asset-1", OWNER)]},
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let helius: Helius = Helius::builder()
+        .api_key("fake_api_key")
+        .cluster(Cluster::Custom(HeliusEndpoints {
+            api: format!("{}/", server.url()),
+            rpc: server.url(),
+            ..Default::default()
+        }))
+        .build()
+        .unwrap();
+
+    assert_eq!(owned_ids(&helius, OWNER, 1, 10).await.unwrap(), vec!["asset-1"]);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_fake_webhooks_lifecycle() {
+    let webhooks: FakeWebhooks = FakeWebhooks::new();
+
+    let created: Webhook = webhooks
+        .create_webhook(CreateWebhookRequest {
+            webhook_url: "https://webhook.site/0e8250a1-ceec-4757-ad69-cc6473085bfc".to_string(),
+            transaction_types: vec![TransactionType::Any],
+            account_addresses: vec![OWNER.to_string()],
+            webhook_type: WebhookType::Enhanced,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let appended: Webhook = webhooks
+        .append_addresses_to_webhook(&created.webhook_id, &[OTHER_OWNER.to_string()])
+        .await
+        .unwrap();
+    assert_eq!(appended.account_addresses, vec![OWNER, OTHER_OWNER]);
+
+    let removed: Webhook = webhooks
+        .remove_addresses_from_webhook(&created.webhook_id, &[OWNER.to_string()])
+        .await
+        .unwrap();
+    assert_eq!(removed.account_addresses, vec![OTHER_OWNER]);
+    assert_eq!(webhooks.get_all_webhooks().await.unwrap().len(), 1);
+
+    webhooks.delete_webhook(&created.webhook_id).await.unwrap();
+    let response: Result<Webhook> = webhooks.get_webhook_by_id(&created.webhook_id).await;
+    assert!(matches!(response, Err(HeliusError::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn test_fake_enhanced_transactions_history() {
+    let transactions: FakeEnhancedTransactions = FakeEnhancedTransactions::new()
+        .with_transaction(transaction("signature-3", OWNER))
+        .with_transaction(transaction("signature-2", OTHER_OWNER))
+        .with_transaction(transaction("signature-1", OWNER))
+        .with_transaction(transaction("signature-0", OWNER));
+
+    let history: Vec<EnhancedTransaction> = transactions
+        .parsed_transaction_history(ParsedTransactionHistoryRequest {
+            address: OWNER.to_string(),
+            before: Some("signature-3".to_string()),
+            until: None,
+            commitment: None,
+            source: None,
+            transaction_type: None,
+            limit: Some(1),
+        })
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].signature, "signature-1");
+
+    let parsed: Vec<EnhancedTransaction> = transactions
+        .parse_transactions(ParseTransactionsRequest {
+            transactions: vec!["signature-2".to_string(), "unknown".to_string()],
+        })
+        .await
+        .unwrap();
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].fee_payer, OTHER_OWNER);
+}
+
+#[tokio::test]
+async fn test_fake_transaction_sender_records_sends() {
+    let sender: FakeTransactionSender = FakeTransactionSender::new();
+    let payer: Arc<dyn Signer> = Arc::new(Keypair::new());
+    let instruction: SolanaInstruction = SolanaInstruction::new_with_bytes(Pubkey::new_unique(), &[1], vec![]);
+
+    sender.push_outcome(Err(HeliusError::Timeout {
+        code: reqwest::StatusCode::REQUEST_TIMEOUT,
+        text: "Transaction failed to confirm".to_string(),
+    }));
+
+    let config = || SmartTransactionConfig::new(vec![instruction.clone()], vec![payer.clone()], Timeout::default());
+    let first: Result<Signature> = sender.send_smart_transaction(config()).await;
+    assert!(matches!(first, Err(HeliusError::Timeout { .. })));
+    assert!(sender.send_smart_transaction(config()).await.is_ok());
+
+    let unsigned: Result<Signature> = sender
+        .send_smart_transaction(SmartTransactionConfig::new(vec![], vec![], Timeout::default()))
+        .await;
+    assert!(matches!(unsigned, Err(HeliusError::InvalidInput(_))));
+
+    let sent: Vec<_> = sender.sent();
+    assert_eq!(sent.len(), 2);
+    assert_eq!(sent[0].fee_payer, payer.pubkey());
+    assert_eq!(sent[1].instructions, vec![instruction]);
+}
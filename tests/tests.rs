@@ -1,3 +1,5 @@
+mod common;
+
 mod utils {
     mod test_deserialize_str_to_number;
     mod test_is_valid_solana_address;
@@ -5,8 +7,11 @@ mod utils {
 }
 
 mod rpc {
+    mod test_batch;
+    mod test_das_streams;
     mod test_get_asset;
     mod test_get_asset_batch;
+    mod test_get_asset_batch_chunked;
     mod test_get_asset_proof;
     mod test_get_asset_proof_batch;
     mod test_get_assets_by_authority;
@@ -18,7 +23,10 @@ mod rpc {
     mod test_get_signatures_for_asset;
     mod test_get_token_accounts;
     mod test_get_transactions_for_address;
+    mod test_mint_compressed_nft;
+    mod test_rpc_errors;
     mod test_search_assets;
+    mod test_v2_streams;
 }
 mod webhook {
     mod test_create_webhook;